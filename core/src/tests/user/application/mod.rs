@@ -0,0 +1,7 @@
+pub mod test_errors;
+pub mod tests_user_factory;
+pub mod tests_user_service;
+pub mod tests_user_builder;
+pub mod tests_password_reset;
+pub mod tests_password_history;
+pub mod tests_sessions;
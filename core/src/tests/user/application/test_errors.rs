@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use sea_orm::DbErr;
+
+    use crate::user::application::UserApplicationError;
+    use crate::user::domain::repositories::RepositoryError;
+    use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+    #[test]
+    fn test_domain_error_into_application_error() {
+        let domain: UserDomainError = (CategoryError::Username, TypeError::Empty).into();
+        let err: UserApplicationError = domain.clone().into();
+
+        assert_eq!(err.code(), "user.username.empty");
+        assert_eq!(err.as_domain(), Some(&domain));
+    }
+
+    #[test]
+    fn test_db_error_is_preserved_through_layers() {
+        let repo: RepositoryError = DbErr::Custom("connection reset".into()).into();
+        assert_eq!(repo.code(), "user.repository.infrastructure");
+
+        let err: UserApplicationError = repo.into();
+        assert_eq!(err.code(), "user.repository.infrastructure");
+
+        let db_err = err
+            .source()
+            .and_then(|repo| repo.source())
+            .and_then(|source| source.downcast_ref::<DbErr>());
+
+        assert!(matches!(db_err, Some(DbErr::Custom(msg)) if msg == "connection reset"));
+        println!("🧱 Error de infraestructura: {}", err);
+    }
+
+    #[test]
+    fn test_record_not_found_maps_to_not_found() {
+        let repo: RepositoryError = DbErr::RecordNotFound("roles".into()).into();
+        assert_eq!(repo.code(), "user.repository.not_found");
+        assert!(UserApplicationError::from(repo).as_domain().is_none());
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use std::sync::Arc;
+
+    use crate::user::application::*;
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::repositories::UserAggregateRepository;
+    use crate::user::domain::services::PasswordHistoryPolicy;
+    use crate::user::domain::vo::UserId;
+    use crate::user::infrastructure::services_impl::InMemoryPasswordResetNotifier;
+    use crate::tests::user::fixtures::{register, service, PlainHasher, ServiceFixture};
+
+    #[async_std::test]
+    async fn test_password_history_per_tenant_on_sqlite() {
+        let ServiceFixture { uow, aggregates, service, .. } = service().await;
+        let notifier = InMemoryPasswordResetNotifier::new();
+
+        // 🔹 Cada tenant configura su servicio: uno recuerda 3 contraseñas y exige un día entre cambios
+        let strict = service
+            .with_password_history_policy(PasswordHistoryPolicy { remember: 3, min_age: Duration::days(1) })
+            .with_password_reset_notifier(Arc::new(notifier.clone()));
+        let relaxed = UserService::new(uow, Arc::new(PlainHasher))
+            .with_password_history_policy(PasswordHistoryPolicy { remember: 3, min_age: Duration::zero() });
+
+        let id = strict.register_user(register("rotacion@example.co")).await.unwrap();
+        let change = |current: &str, new: &str| ChangePassword {
+            user_id: id,
+            current_password: current.into(),
+            new_password: new.into(),
+        };
+
+        // 🔹 La contraseña del alta se puede cambiar ya; la siguiente, no hasta mañana
+        strict.change_password(change("s3cret-Passw0rd", "Rotada-Clave-1")).await.unwrap();
+        let err = strict.change_password(change("Rotada-Clave-1", "Rotada-Clave-2")).await.unwrap_err();
+        println!("⏳ {}", err.code());
+        assert_eq!(err.code(), "user.password.rate_limited");
+
+        relaxed.change_password(change("Rotada-Clave-1", "Rotada-Clave-2")).await.unwrap();
+
+        // 🔹 El historial sobrevive a la persistencia
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap().password.unwrap();
+        assert_eq!(stored.password_history, ["plain$Rotada-Clave-1", "plain$s3cret-Passw0rd"]);
+        assert!(stored.password_changed_at.is_some());
+
+        // 🔹 Ni la actual ni las dos anteriores; la cuarta ya se olvidó
+        for reused in ["Rotada-Clave-2", "Rotada-Clave-1", "s3cret-Passw0rd"] {
+            let err = relaxed.change_password(change("Rotada-Clave-2", reused)).await.unwrap_err();
+            assert_eq!(err.code(), "user.password.reused");
+        }
+        relaxed.change_password(change("Rotada-Clave-2", "Rotada-Clave-3")).await.unwrap();
+        relaxed.change_password(change("Rotada-Clave-3", "Rotada-Clave-4")).await.unwrap();
+        relaxed.change_password(change("Rotada-Clave-4", "s3cret-Passw0rd")).await.unwrap();
+
+        // 🔹 El restablecimiento respeta el historial, pero no la antigüedad mínima
+        strict.request_password_reset(RequestPasswordReset { email: "rotacion@example.co".into() }).await.unwrap();
+        let token = notifier.sent().pop().unwrap().token;
+        let reset = |new_password: &str| ResetPassword {
+            email: "rotacion@example.co".into(),
+            token: token.clone(),
+            new_password: new_password.into(),
+        };
+        let err = strict.reset_password(reset("Rotada-Clave-4")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.reused");
+        assert_eq!(strict.reset_password(reset("Restablecida-5")).await.unwrap(), id);
+    }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::user::application::*;
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::entities::UserSession;
+    use crate::user::domain::repositories::UserAggregateRepository;
+    use crate::user::domain::services::PasswordResetNotifier;
+    use crate::user::domain::vo::{Email, UserId};
+    use crate::user::infrastructure::services_impl::InMemoryPasswordResetNotifier;
+    use crate::tests::user::fixtures::{register, service, PlainHasher, ServiceFixture};
+
+    /// Notificador caído: ninguna entrega llega al usuario.
+    struct UnreachableNotifier;
+
+    #[async_trait::async_trait]
+    impl PasswordResetNotifier for UnreachableNotifier {
+        async fn send_reset_token(&self, _: &Email, _: &str, _: chrono::DateTime<Utc>) -> anyhow::Result<()> {
+            anyhow::bail!("smtp unreachable")
+        }
+    }
+
+    #[async_std::test]
+    async fn test_password_reset_flow_on_sqlite() {
+        let ServiceFixture { publisher, aggregates, service, .. } = service().await;
+        let notifier = InMemoryPasswordResetNotifier::new();
+        let service = service.with_password_reset_notifier(Arc::new(notifier.clone()));
+        let id = service.register_user(register("olvido@example.co")).await.unwrap();
+
+        // 🔹 Dos sesiones abiertas en distintos terminales
+        let mut aggregate = aggregates.load(&UserId::from_uuid(id), LoadOptions::all()).await.unwrap().unwrap();
+        for _ in 0..2 {
+            let session = UserSession::new(Uuid::new_v4(), id, None, Utc::now() + Duration::days(1), None, None, None).unwrap();
+            aggregate.sessions.push(session);
+        }
+        aggregates.save(&aggregate).await.unwrap();
+
+        // 🔹 Un email desconocido no revela nada ni emite eventos
+        let before = publisher.published().len();
+        service.request_password_reset(RequestPasswordReset { email: "nadie@example.co".into() }).await.unwrap();
+        assert_eq!(publisher.published().len(), before);
+        assert!(notifier.sent().is_empty());
+
+        // 🔹 Solicitud válida; la repetición inmediata se ignora en silencio
+        let request = RequestPasswordReset { email: "olvido@example.co".into() };
+        service.request_password_reset(request.clone()).await.unwrap();
+        service.request_password_reset(request).await.unwrap();
+        let tokens: Vec<String> = notifier.sent().into_iter().map(|sent| sent.token).collect();
+        println!("📧 {} token(s) enviados", tokens.len());
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(notifier.sent()[0].email.as_ref(), "olvido@example.co");
+
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        let password = stored.password.unwrap();
+        assert!(password.reset_requested_at.is_some());
+        assert_ne!(password.reset_token.as_deref(), Some(tokens[0].as_str()));
+
+        // 🔹 Token incorrecto
+        let reset = |token: &str, new_password: &str| ResetPassword {
+            email: "olvido@example.co".into(),
+            token: token.into(),
+            new_password: new_password.into(),
+        };
+        let err = service.reset_password(reset("0000", "Nueva-Clave-1")).await.unwrap_err();
+        assert_eq!(err.code(), "user.reset_token.mismatch");
+
+        // 🔹 Restablecimiento correcto: sesiones cerradas y la nueva contraseña sirve
+        assert_eq!(service.reset_password(reset(&tokens[0], "Nueva-Clave-1")).await.unwrap(), id);
+
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::all()).await.unwrap().unwrap();
+        assert_eq!(stored.sessions.len(), 2);
+        assert!(stored.sessions.iter().all(|s| !s.is_active && s.access_token_version == 2));
+        assert!(stored.password.unwrap().reset_token.is_none());
+
+        let login = |password: &str| AuthenticateUser {
+            email: "olvido@example.co".into(),
+            password: password.into(),
+            ..Default::default()
+        };
+        assert_eq!(service.authenticate(login("Nueva-Clave-1")).await.unwrap(), id);
+        assert_eq!(service.authenticate(login("s3cret-Passw0rd")).await.unwrap_err().code(), "user.password.mismatch");
+
+        let err = service.reset_password(reset(&tokens[0], "Otra-Clave-2")).await.unwrap_err();
+        println!("♻️ {}", err.code());
+        assert_eq!(err.code(), "user.reset_token.missing");
+
+        let published: Vec<&str> = publisher.published().iter().map(|e| e.event_name()).collect();
+        assert!(published.ends_with(&["user_password_reset_requested", "user_password_changed"]));
+    }
+
+    #[async_std::test]
+    async fn test_failed_delivery_discards_the_request() {
+        let ServiceFixture { uow, publisher, aggregates, service: bare } = service().await;
+        let id = bare.register_user(register("sin.correo@example.co")).await.unwrap();
+        let request = RequestPasswordReset { email: "sin.correo@example.co".into() };
+
+        // 🔹 Sin notificador no hay forma de entregar el token
+        let err = bare.request_password_reset(request.clone()).await.unwrap_err();
+        assert_eq!(err.code(), "user.reset_token.not_supported");
+
+        // 🔹 Si la entrega falla, no queda token ni empieza el intervalo
+        let down = UserService::new(uow.clone(), Arc::new(PlainHasher)).with_password_reset_notifier(Arc::new(UnreachableNotifier));
+        let before = publisher.published().len();
+        let err = down.request_password_reset(request.clone()).await.unwrap_err();
+        println!("📭 {}", err.code());
+        assert_eq!(err.code(), "user.reset_token.invalid_state");
+        assert_eq!(publisher.published().len(), before);
+
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        let password = stored.password.unwrap();
+        assert!(password.reset_requested_at.is_none());
+        assert!(password.reset_token.is_none());
+
+        // 🔹 Con el notificador de vuelta la solicitud se puede repetir al momento
+        let notifier = InMemoryPasswordResetNotifier::new();
+        let up = UserService::new(uow, Arc::new(PlainHasher)).with_password_reset_notifier(Arc::new(notifier.clone()));
+        up.request_password_reset(request).await.unwrap();
+        assert_eq!(notifier.sent().len(), 1);
+    }
+    #[async_std::test]
+    async fn test_deleting_the_account_voids_pending_tokens() {
+        let ServiceFixture { aggregates, service, .. } = service().await;
+        let notifier = InMemoryPasswordResetNotifier::new();
+        let service = service.with_password_reset_notifier(Arc::new(notifier.clone()));
+        let id = service.register_user(register("baja@example.co")).await.unwrap();
+
+        service.request_password_reset(RequestPasswordReset { email: "baja@example.co".into() }).await.unwrap();
+        let token = notifier.sent().pop().unwrap().token;
+        service.delete_user(DeleteUser { user_id: id }).await.unwrap();
+
+        // 🔹 El borrado descarta el token pendiente
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        assert!(stored.password.unwrap().reset_token.is_none());
+
+        let err = service
+            .reset_password(ResetPassword { email: "baja@example.co".into(), token, new_password: "Nueva-Clave-1".into() })
+            .await
+            .unwrap_err();
+        println!("🗑️ {}", err.code());
+        assert_eq!(err.code(), "user.status.invalid_status");
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+
+    use crate::user::application::*;
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::repositories::UserAggregateRepository;
+    use crate::user::domain::services::Clock;
+    use crate::user::domain::vo::UserId;
+    use crate::user::infrastructure::services_impl::{JwtAccessTokenCodec, ManualClock};
+    use crate::tests::user::fixtures::{register, service, PlainHasher, ServiceFixture};
+
+    #[async_std::test]
+    async fn test_login_tokens_and_logout_on_sqlite() {
+        let ServiceFixture { uow, aggregates, service, .. } = service().await;
+        let clock = ManualClock::new(Utc::now());
+        let service = service
+            .with_access_tokens(Arc::new(JwtAccessTokenCodec::hs256(&[7u8; 32]).unwrap()))
+            .with_clock(Arc::new(clock.clone()));
+
+        let id = service.register_user(register("terminal@example.co")).await.unwrap();
+        let login = |terminal: &str| AuthenticateUser {
+            email: "terminal@example.co".into(),
+            password: "s3cret-Passw0rd".into(),
+            ip_address: Some("10.0.0.1".into()),
+            user_agent: Some(terminal.into()),
+        };
+
+        // 🔹 El inicio de sesión abre una sesión persistida y emite su token
+        let caja = service.login(login("caja-1")).await.unwrap();
+        println!("🎫 {:?}", caja.access_token);
+        assert_eq!(caja.user_id, id);
+        assert_eq!(caja.access_token.expires_at, clock.now() + Duration::minutes(15));
+        let claims = service.validate_access_token(&caja.access_token.token).await.unwrap();
+        assert_eq!((claims.sub, claims.sid), (id, caja.session_id));
+
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::all()).await.unwrap().unwrap();
+        let [session] = stored.sessions.as_slice() else { panic!("Se esperaba una sesión, hay {:?}", stored.sessions) };
+        assert_eq!(session.user_agent.as_deref(), Some("caja-1"));
+
+        // 🔹 Pasado su TTL el token caduca, aunque la sesión siga abierta
+        clock.advance(Duration::minutes(16));
+        let err = service.validate_access_token(&caja.access_token.token).await.unwrap_err();
+        println!("⌛ {}", err.code());
+        assert_eq!(err.code(), "user.session.expired");
+
+        // 🔹 Cerrar una sesión revoca sus tokens y deja las demás intactas
+        let caja = service.login(login("caja-1")).await.unwrap();
+        let tablet = service.login(login("tablet")).await.unwrap();
+        let movil = service.login(login("movil")).await.unwrap();
+        assert_eq!(service.logout(&caja.access_token.token).await.unwrap(), id);
+        let err = service.validate_access_token(&caja.access_token.token).await.unwrap_err();
+        println!("🚪 {}", err.code());
+        assert_eq!(err.code(), "user.session.revoked");
+        service.validate_access_token(&tablet.access_token.token).await.unwrap();
+
+        // 🔹 Cerrar todas: la primera sesión (sin token vigente) también cuenta
+        assert_eq!(service.logout_everywhere(&movil.access_token.token).await.unwrap(), 3);
+        for token in [&tablet, &movil].map(|issued| &issued.access_token.token) {
+            assert_eq!(service.validate_access_token(token).await.unwrap_err().code(), "user.session.revoked");
+        }
+
+        // 🔹 Suspender la cuenta invalida tokens vivos e impide abrir sesión
+        let tpv = service.login(login("tpv")).await.unwrap();
+        service.activate_user(ActivateUser { user_id: id }).await.unwrap();
+        service.suspend_user(SuspendUser { user_id: id }).await.unwrap();
+        assert_eq!(service.validate_access_token(&tpv.access_token.token).await.unwrap_err().code(), "user.session.revoked");
+        assert!(service.login(login("tpv")).await.is_err());
+
+        // 🔹 Tokens falsos o firmados con otro secreto
+        let other = UserService::new(uow.clone(), Arc::new(PlainHasher))
+            .with_access_tokens(Arc::new(JwtAccessTokenCodec::hs256(&[8u8; 32]).unwrap()));
+        let err = other.validate_access_token(&tpv.access_token.token).await.unwrap_err();
+        assert_eq!(err.code(), "user.session.invalid_format");
+
+        // 🔹 Sin codec configurado no hay sesiones
+        let bare = UserService::new(uow, Arc::new(PlainHasher));
+        assert_eq!(bare.login(login("caja-1")).await.unwrap_err().code(), "user.session.not_supported");
+    }
+    #[async_std::test]
+    async fn test_suspended_users_cannot_log_in() {
+        let ServiceFixture { aggregates, service, .. } = service().await;
+        let service = service.with_access_tokens(Arc::new(JwtAccessTokenCodec::hs256(&[7u8; 32]).unwrap()));
+        let id = service.register_user(register("suspendida@example.co")).await.unwrap();
+        service.activate_user(ActivateUser { user_id: id }).await.unwrap();
+        service.suspend_user(SuspendUser { user_id: id }).await.unwrap();
+        let login = |password: &str| AuthenticateUser {
+            email: "suspendida@example.co".into(),
+            password: password.into(),
+            ..Default::default()
+        };
+
+        // 🔹 El estado sólo se revela con la contraseña correcta
+        for (password, code) in [("s3cret-Passw0rd", "user.status.invalid_status"), ("0000", "user.password.mismatch")] {
+            let err = service.login(login(password)).await.unwrap_err();
+            println!("⛔ {password} → {}", err.code());
+            assert_eq!(err.code(), code);
+        }
+
+        // 🔹 No se cuenta como intento fallido ni se abre sesión
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::all()).await.unwrap().unwrap();
+        assert_eq!(stored.password.unwrap().failed_attempts, 0);
+        assert!(stored.sessions.is_empty());
+
+        // 🔹 Lo mismo con la cuenta borrada
+        service.delete_user(DeleteUser { user_id: id }).await.unwrap();
+        assert_eq!(service.authenticate(login("s3cret-Passw0rd")).await.unwrap_err().code(), "user.status.invalid_status");
+        assert_eq!(service.authenticate(login("0000")).await.unwrap_err().code(), "user.password.mismatch");
+    }
+}
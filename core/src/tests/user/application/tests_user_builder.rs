@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::user::application::UserBuilder;
+    use crate::user::domain::vo::{Locale, UserStatus};
+
+    fn event_names(user: &crate::user::domain::entities::User) -> Vec<&'static str> {
+        user.pending_events().iter().map(|e| e.event_name()).collect()
+    }
+
+    #[test]
+    fn test_builder_reports_every_invalid_field_at_once() {
+        let errors = UserBuilder::new()
+            .email("bad@domain..com")
+            .username("x")
+            .phone("12345")
+            .external_id("")
+            .status("archived")
+            .build_new()
+            .unwrap_err();
+
+        println!("🧾 {errors}");
+        assert_eq!(errors.fields().collect::<Vec<_>>(), ["email", "username", "phone", "external_id", "status"]);
+        assert_eq!(errors.get("email").unwrap().code(), "user.email.invalid_format");
+        assert_eq!(errors.get("phone").unwrap().code(), "user.phone.invalid_format");
+        assert!(errors.get("username").unwrap().code().starts_with("user.username."));
+        assert!(errors.get("id").is_none());
+
+        // Un objeto por campo, listo para el formulario
+        let json = errors.to_json(&Locale::default());
+        println!("📦 {json}");
+        assert_eq!(json["email"]["code"], "user.email.invalid_format");
+        assert!(json["phone"]["message"].as_str().is_some_and(|m| !m.is_empty()));
+
+        let missing = UserBuilder::new().validate().unwrap_err();
+        assert_eq!(missing.get("email").unwrap().code(), "user.email.missing");
+        assert_eq!(missing.len(), 1);
+    }
+
+    #[test]
+    fn test_build_new_emits_the_same_events_as_the_aggregate() {
+        let user = UserBuilder::new()
+            .email("nueva.alta@example.co")
+            .username("nueva_alta")
+            .phone("+57 3001234567")
+            .external_id("EXTERNAL-BUILDER-01")
+            .email_verified(true)
+            .phone_verified(true)
+            .status("active")
+            .build_new()
+            .unwrap();
+
+        let events = event_names(&user);
+        println!("📣 {events:?}");
+        assert_eq!(
+            events,
+            [
+                "user_registered",
+                "user_username_assigned",
+                "user_phone_assigned",
+                "user_external_id_linked",
+                "user_email_verified",
+                "user_phone_verified",
+                "user_activated",
+            ]
+        );
+        assert_eq!(user.status(), &UserStatus::Active);
+        assert_eq!(user.persisted_version(), 0);
+        assert!(user.email_verified() && user.phone_verified());
+    }
+
+    #[test]
+    fn test_build_new_rejects_states_unreachable_from_signup() {
+        let errors = UserBuilder::new().email("suspendida@example.co").status("suspended").build_new().unwrap_err();
+        println!("⛔ {errors}");
+        assert_eq!(errors.get("status").unwrap().code(), "user.status.invalid_transition");
+
+        let errors = UserBuilder::new().email("sin.telefono@example.co").phone_verified(true).build_new().unwrap_err();
+        assert_eq!(errors.get("phone").unwrap().code(), "user.phone.missing");
+
+        // El id se ignora en un alta nueva: siempre se genera uno
+        let id = Uuid::new_v4();
+        let user = UserBuilder::new().id(id).email("id.propio@example.co").build_new().unwrap();
+        assert_ne!(user.id().as_uuid(), id);
+    }
+
+    #[test]
+    fn test_build_restored_keeps_state_without_events() {
+        let id = Uuid::new_v4();
+        let created = Utc::now() - Duration::days(30);
+
+        let user = UserBuilder::new()
+            .id(id)
+            .email("restaurada@example.co")
+            .username("restaurada")
+            .phone("+57 3001234567")
+            .phone_verified(true)
+            .status("suspended")
+            .created_at(created)
+            .version(7)
+            .build_restored()
+            .unwrap();
+
+        println!("♻️ {} v{}", user.status().as_str(), user.version());
+        assert!(user.pending_events().is_empty());
+        assert_eq!(user.id().as_uuid(), id);
+        assert_eq!(user.status(), &UserStatus::Suspended);
+        assert_eq!(user.version(), 7);
+        assert_eq!(user.created_at().value(), created);
+        assert_eq!(user.updated_at().value(), created);
+
+        let deleted = UserBuilder::new().id(id).version(2).email("borrada@example.co").status("deleted").build_restored().unwrap();
+        assert!(deleted.deleted_at().is_some());
+    }
+
+    #[test]
+    fn test_build_restored_requires_an_id_a_version_and_a_consistent_state() {
+        let errors = UserBuilder::new().email("bad@domain..com").build_restored().unwrap_err();
+        assert_eq!(errors.fields().collect::<Vec<_>>(), ["id", "email", "version"]);
+        assert_eq!(errors.get("id").unwrap().code(), "user.id.missing");
+        assert_eq!(errors.get("version").unwrap().code(), "user.version.missing");
+
+        // 🔹 Versión 0: sería un alta nueva, no un usuario persistido
+        let errors = UserBuilder::new().id(Uuid::new_v4()).email("sin.version@example.co").version(0).build_restored().unwrap_err();
+        assert_eq!(errors.fields().collect::<Vec<_>>(), ["version"]);
+
+        let errors = UserBuilder::new()
+            .id(Uuid::new_v4())
+            .version(1)
+            .email("inconsistente@example.co")
+            .phone_verified(true)
+            .build_restored()
+            .unwrap_err();
+        println!("⚠️ {errors}");
+        assert_eq!(errors.get("phone").unwrap().code(), "user.phone.missing");
+
+        let errors = UserBuilder::new()
+            .id(Uuid::new_v4())
+            .version(1)
+            .email("viva@example.co")
+            .deleted_at(Utc::now())
+            .build_restored()
+            .unwrap_err();
+        assert_eq!(errors.get("status").unwrap().code(), "user.status.invalid_status");
+    }
+}
@@ -1,14 +1,14 @@
 #[cfg(test)]
-mod tests_user_factory {
+mod tests {
     use uuid::Uuid;
     use crate::user::application::UserFactory;
-    use crate::user::domain::ValidationError;
     use std::result::Result::{Ok, Err};
 
     #[test]
     fn test_user_creation_valid() {
         let result = UserFactory::create(
             Uuid::new_v4(),
+            1,
             "alice@example.com",
             Some("Alice_mouse"),
             Some("+123456789"),
@@ -23,6 +23,7 @@ mod tests_user_factory {
     fn test_user_creation_invalid_email() {
         let result = UserFactory::create(
             Uuid::new_v4(),
+            1,
             "bad@domain..com", // correo inválido
             None,
             None,
@@ -34,7 +35,7 @@ mod tests_user_factory {
             Ok(result) => println!("Usuario creado (no debería pasar): {:?}", result),
             Err(e) => {
                 println!("Error esperado: {}", e);
-                assert_eq!(e, ValidationError::InvalidEmail);
+                assert_eq!(e.code(), "user.email.invalid_format");
             }
         }
     }
@@ -43,9 +44,10 @@ mod tests_user_factory {
     fn test_user_creation_invalid_phone() {
         let result = UserFactory::create(
             Uuid::new_v4(),
+            1,
             "bob@example.com",
-            Some("12345"), // inválido porque le falta el "+"
             None,
+            Some("12345"), // inválido: falta el código de país separado
             None,
             None,
         );
@@ -53,6 +55,6 @@ mod tests_user_factory {
         println!("Result: {:?}", result);
         assert!(result.is_err(), "Debería fallar con teléfono inválido");
         let err = result.unwrap_err();
-        assert_eq!(err, ValidationError::InvalidPhone);
+        assert_eq!(err.code(), "user.phone.invalid_format");
     }
 }
@@ -0,0 +1,345 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::user::application::*;
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::repositories::{RepositoryError, UserAggregateRepository};
+    use crate::user::domain::services::{LockoutPolicy, PasswordHasher};
+    use crate::user::domain::validations::UserDomainError;
+    use crate::user::domain::vo::*;
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::memory::{
+        InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory, InMemoryUserAggregateRepository,
+    };
+    use crate::user::infrastructure::services_impl::InMemoryLoginThrottle;
+    use crate::tests::user::fixtures::{register, seed_role, PlainHasher};
+
+    /// `PlainHasher` que cuenta cuántas veces se le pide cada operación.
+    #[derive(Default)]
+    struct CountingHasher {
+        hashes: AtomicUsize,
+        verifies: AtomicUsize,
+    }
+
+    impl PasswordHasher for CountingHasher {
+        fn hash(&self, password: &str) -> Result<String, UserDomainError> {
+            self.hashes.fetch_add(1, Ordering::SeqCst);
+            PlainHasher.hash(password)
+        }
+
+        fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError> {
+            self.verifies.fetch_add(1, Ordering::SeqCst);
+            PlainHasher.verify(password, hash)
+        }
+    }
+
+    struct Fixture {
+        aggregates: Arc<InMemoryUserAggregateRepository>,
+        publisher: InMemoryDomainEventPublisher,
+        service: UserService,
+    }
+
+    async fn fixture() -> Fixture {
+        let store = InMemoryStore::new();
+        let roles = Arc::new(InMemoryRoleRepository::new(store.clone()));
+        let aggregates = Arc::new(InMemoryUserAggregateRepository::new(store.clone()));
+        let publisher = InMemoryDomainEventPublisher::new();
+
+        for name in ["customer", "admin"] {
+            seed_role(roles.as_ref(), name).await;
+        }
+
+        let uow = InMemoryUnitOfWorkFactory::new(store, Arc::new(publisher.clone()));
+        let service = UserService::new(Arc::new(uow), Arc::new(PlainHasher));
+        Fixture { aggregates, publisher, service }
+    }
+
+    async fn registered(fx: &Fixture, email: &str) -> Uuid {
+        fx.service.register_user(register(email)).await.unwrap()
+    }
+
+    fn command(json: serde_json::Value) -> UserCommand {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[async_std::test]
+    async fn test_serialized_commands_drive_the_user_lifecycle() {
+        let fx = fixture().await;
+
+        let id = fx
+            .service
+            .handle(command(serde_json::json!({
+                "type": "register_user",
+                "email": "ciclo.vida@example.co",
+                "username": "ciclo_vida",
+                "password": "s3cret-Passw0rd",
+                "consents": ["terms_of_service", "privacy_policy"],
+                "first_name": null, "last_name": null, "display_name": "Ana Pérez",
+                "locale": "es-CO", "timezone": "America/Bogota",
+                "ip_address": "10.0.0.1", "user_agent": "tauri"
+            })))
+            .await
+            .unwrap();
+
+        for json in [
+            serde_json::json!({ "type": "verify_email", "user_id": id }),
+            serde_json::json!({ "type": "assign_phone", "user_id": id, "phone": "+57 3001234567" }),
+            serde_json::json!({ "type": "verify_phone", "user_id": id }),
+            serde_json::json!({ "type": "activate_user", "user_id": id }),
+            serde_json::json!({ "type": "suspend_user", "user_id": id }),
+            serde_json::json!({ "type": "delete_user", "user_id": id }),
+        ] {
+            assert_eq!(fx.service.handle(command(json)).await.unwrap(), id);
+        }
+
+        let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        println!("🔄 Estado final: {} (v{})", stored.user.status().as_str(), stored.user.version());
+        assert_eq!(stored.user.status(), &UserStatus::Deleted);
+        assert!(stored.user.email_verified() && stored.user.phone_verified());
+        assert_eq!(stored.profile.as_ref().and_then(|p| p.display_name.as_deref()), Some("Ana Pérez"));
+
+        let published: Vec<&str> = fx.publisher.published().iter().map(|e| e.event_name()).collect();
+        println!("📣 {published:?}");
+        assert_eq!(published.first(), Some(&"user_registered"));
+        assert_eq!(published.len() as u64, stored.user.version());
+    }
+
+    #[async_std::test]
+    async fn test_failed_commands_write_nothing() {
+        let fx = fixture().await;
+        let id = registered(&fx, "sin.cambios@example.co").await;
+        let before = fx.publisher.published().len();
+
+        // Suspender un usuario pendiente es una transición inválida
+        let err = fx.service.suspend_user(SuspendUser { user_id: id }).await.unwrap_err();
+        println!("⛔ {}", err.code());
+        assert_eq!(err.code(), "user.status.invalid_transition");
+
+        let err = fx.service.change_email(ChangeEmail { user_id: id, new_email: "no-es-email".into() }).await.unwrap_err();
+        assert!(err.code().starts_with("user.email."));
+
+        let err = fx.service.verify_email(VerifyEmail { user_id: Uuid::new_v4() }).await.unwrap_err();
+        assert!(matches!(err, UserApplicationError::Repository(RepositoryError::NotFound { entity: "user", .. })));
+
+        let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        assert_eq!(stored.user.version(), 1);
+        assert_eq!(fx.publisher.published().len(), before);
+    }
+
+    #[async_std::test]
+    async fn test_register_and_change_email_surface_typed_errors() {
+        let fx = fixture().await;
+        registered(&fx, "ocupado@example.co").await;
+        let other = registered(&fx, "libre@example.co").await;
+
+        let err = fx.service.register_user(register("ocupado@example.co")).await.unwrap_err();
+        assert!(matches!(err, UserApplicationError::Registration(_)));
+        assert_eq!(err.code(), "user.registration.email_taken");
+
+        let mut bad_consent = register("consentimiento@example.co");
+        bad_consent.consents.push("newsletter".into());
+        let err = fx.service.register_user(bad_consent).await.unwrap_err();
+        println!("📝 {}", err.code());
+        assert_eq!(err.code(), "user.consent_type.not_supported");
+
+        // El índice único protege el cambio de email hacia uno ocupado
+        let err = fx
+            .service
+            .change_email(ChangeEmail { user_id: other, new_email: "ocupado@example.co".into() })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, UserApplicationError::Repository(RepositoryError::Conflict { field: "email", .. })));
+    }
+
+    #[async_std::test]
+    async fn test_change_email_keeps_suspended_and_deleted_users_locked() {
+        let fx = fixture().await;
+        let suspended = registered(&fx, "suspendido@example.co").await;
+        fx.service.activate_user(ActivateUser { user_id: suspended }).await.unwrap();
+        fx.service.suspend_user(SuspendUser { user_id: suspended }).await.unwrap();
+        let deleted = registered(&fx, "borrado@example.co").await;
+        fx.service.delete_user(DeleteUser { user_id: deleted }).await.unwrap();
+
+        // 🔹 Cambiar el email no devuelve la cuenta a `pending`
+        for (id, status) in [(suspended, UserStatus::Suspended), (deleted, UserStatus::Deleted)] {
+            let err = fx
+                .service
+                .change_email(ChangeEmail { user_id: id, new_email: "nuevo@example.co".into() })
+                .await
+                .unwrap_err();
+            println!("🔒 {}", err.code());
+            assert_eq!(err.code(), "user.email.invalid_status");
+
+            let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+            assert_eq!(stored.user.status(), &status);
+        }
+    }
+
+    #[async_std::test]
+    async fn test_assign_phone_keeps_the_country_code_as_written() {
+        let fx = fixture().await;
+        let id = registered(&fx, "telefono@example.co").await;
+
+        // 🔹 El separador decide el código: `+5 1234567` no se lee como +51
+        for (raw, expected) in [("+57 3001234567", (57, 3001234567)), ("+5 1234567", (5, 1234567)), ("+1-2025550147", (1, 2025550147))] {
+            fx.service.assign_phone(AssignPhone { user_id: id, phone: raw.into() }).await.unwrap();
+            let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+            let phone = stored.user.phone().unwrap();
+            println!("📱 '{raw}' → {phone}");
+            assert_eq!((phone.country_code(), phone.number()), expected);
+        }
+
+        // 🔹 Sin separador el reparto sería una suposición
+        let err = fx.service.assign_phone(AssignPhone { user_id: id, phone: "+573001234567".into() }).await.unwrap_err();
+        assert_eq!(err.code(), "user.phone.invalid_format");
+    }
+
+    #[async_std::test]
+    async fn test_assign_and_revoke_roles() {
+        let fx = fixture().await;
+        let id = registered(&fx, "roles@example.co").await;
+        let assign = AssignRole { user_id: id, role: "admin".into(), granted_by: None, expires_at: None };
+
+        fx.service.assign_role(assign.clone()).await.unwrap();
+        let err = fx.service.assign_role(assign.clone()).await.unwrap_err();
+        println!("👥 {}", err.code());
+        assert_eq!(err.code(), "user.user_role.unchanged");
+
+        fx.service.revoke_role(RevokeRole { user_id: id, role: "admin".into() }).await.unwrap();
+        let err = fx.service.revoke_role(RevokeRole { user_id: id, role: "admin".into() }).await.unwrap_err();
+        assert_eq!(err.code(), "user.user_role.missing");
+
+        // Reasignar reactiva la fila revocada en lugar de duplicarla
+        fx.service.assign_role(assign).await.unwrap();
+        let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        assert_eq!(stored.roles.len(), 2);
+        assert!(stored.roles.iter().all(|role| role.is_valid()));
+
+        let err = fx
+            .service
+            .assign_role(AssignRole { user_id: id, role: "auditor".into(), granted_by: None, expires_at: None })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, UserApplicationError::Repository(RepositoryError::NotFound { entity: "role", .. })));
+    }
+
+    #[async_std::test]
+    async fn test_change_password_requires_the_current_one() {
+        let fx = fixture().await;
+        let id = registered(&fx, "clave@example.co").await;
+
+        let err = fx
+            .service
+            .change_password(ChangePassword { user_id: id, current_password: "otra".into(), new_password: "Nueva-Frase-1".into() })
+            .await
+            .unwrap_err();
+        println!("🔑 {}", err.code());
+        assert_eq!(err.code(), "user.password.mismatch");
+
+        fx.service
+            .change_password(ChangePassword {
+                user_id: id,
+                current_password: "s3cret-Passw0rd".into(),
+                new_password: "Nueva-Frase-1".into(),
+            })
+            .await
+            .unwrap();
+
+        let stored = fx.aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        let hash = stored.password.unwrap().password_hash;
+        assert!(PlainHasher.verify("Nueva-Frase-1", &hash).unwrap());
+        assert!(!PlainHasher.verify("s3cret-Passw0rd", &hash).unwrap());
+    }
+
+    #[async_std::test]
+    async fn test_failed_logins_lock_the_account_and_the_ip() {
+        let Fixture { aggregates, publisher, service } = fixture().await;
+        let service = service
+            .with_lockout_policy(LockoutPolicy {
+                max_failed_attempts: 3,
+                max_failed_attempts_per_ip: 4,
+                ..LockoutPolicy::default()
+            })
+            .with_login_throttle(Arc::new(InMemoryLoginThrottle::new()));
+        let id = service.register_user(register("cajero@example.co")).await.unwrap();
+        let login = |email: &str, password: &str, ip: &str| AuthenticateUser {
+            email: email.into(),
+            password: password.into(),
+            ip_address: Some(ip.into()),
+            ..Default::default()
+        };
+
+        // 🔹 Tres fallos bloquean la cuenta; el último ya lo indica
+        let codes: Vec<String> = failed_codes(&service, login("cajero@example.co", "0000", "10.0.0.1"), 3).await;
+        println!("🔑 {codes:?}");
+        assert_eq!(codes, ["user.password.mismatch", "user.password.mismatch", "user.password.locked"]);
+
+        // 🔹 Con la cuenta bloqueada ni la contraseña correcta entra, desde ninguna IP
+        let err = service.authenticate(login("cajero@example.co", "s3cret-Passw0rd", "10.0.0.2")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.locked");
+        assert!(err.as_domain().unwrap().params().contains_key("until"));
+
+        let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+        let password = stored.password.unwrap();
+        assert_eq!(password.failed_attempts, 3);
+        assert!(password.locked_until.is_some());
+
+        // 🔹 Desbloqueo manual como comando serializado
+        service.handle(command(serde_json::json!({ "type": "unlock_user", "user_id": id }))).await.unwrap();
+        assert_eq!(service.authenticate(login("cajero@example.co", "s3cret-Passw0rd", "10.0.0.2")).await.unwrap(), id);
+
+        let published: Vec<&str> = publisher.published().iter().map(|e| e.event_name()).collect();
+        println!("📣 {published:?}");
+        assert!(published.ends_with(&["user_locked_out", "user_unlocked"]));
+
+        // 🔹 La IP 10.0.0.1 ya lleva 3 fallos; uno más, aunque sea sobre otra cuenta, la bloquea
+        let err = service.authenticate(login("nadie@example.co", "0000", "10.0.0.1")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.mismatch");
+        let err = service.authenticate(login("cajero@example.co", "s3cret-Passw0rd", "10.0.0.1")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.locked");
+        assert_eq!(service.authenticate(login("cajero@example.co", "s3cret-Passw0rd", "10.0.0.3")).await.unwrap(), id);
+
+        let err = service.authenticate(login("cajero@example.co", "x", "no-es-ip")).await.unwrap_err();
+        assert_eq!(err.code(), "user.session.invalid_format");
+    }
+
+    #[async_std::test]
+    async fn test_unknown_emails_still_run_the_hasher() {
+        let store = InMemoryStore::new();
+        let roles = InMemoryRoleRepository::new(store.clone());
+        seed_role(&roles, "customer").await;
+        let hasher = Arc::new(CountingHasher::default());
+        let uow = InMemoryUnitOfWorkFactory::new(store, Arc::new(InMemoryDomainEventPublisher::new()));
+        let service = UserService::new(Arc::new(uow), hasher.clone());
+        service.register_user(register("conocido@example.co")).await.unwrap();
+        let login = |email: &str| AuthenticateUser { email: email.into(), password: "0000".into(), ..Default::default() };
+        let counts = || (hasher.hashes.load(Ordering::SeqCst), hasher.verifies.load(Ordering::SeqCst));
+        let (hashes, verifies) = counts();
+
+        // 🔹 Cuenta existente con contraseña incorrecta: una verificación
+        let known = service.authenticate(login("conocido@example.co")).await.unwrap_err();
+        assert_eq!(counts(), (hashes, verifies + 1));
+
+        // 🔹 Email desconocido: mismo error y también se verifica, contra el hash señuelo
+        let unknown = service.authenticate(login("desconocido@example.co")).await.unwrap_err();
+        println!("🕵️ {} / {}", known.code(), unknown.code());
+        assert_eq!(unknown.code(), known.code());
+        assert_eq!(counts(), (hashes + 1, verifies + 2));
+
+        // 🔹 El señuelo se calcula una sola vez
+        service.authenticate(login("otro.desconocido@example.co")).await.unwrap_err();
+        assert_eq!(counts(), (hashes + 1, verifies + 3));
+    }
+
+    /// Repite el mismo inicio de sesión `times` veces y devuelve los códigos de error.
+    async fn failed_codes(service: &UserService, cmd: AuthenticateUser, times: usize) -> Vec<String> {
+        let mut codes = Vec::new();
+        for _ in 0..times {
+            codes.push(service.authenticate(cmd.clone()).await.unwrap_err().code());
+        }
+        codes
+    }
+}
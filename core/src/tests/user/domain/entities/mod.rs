@@ -1 +1,2 @@
 pub mod tests_user;
+pub mod tests_user_event_sourcing;
@@ -1,8 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::user::domain::entities::User;
+    use crate::user::domain::entities::{User, UserSnapshot};
     use crate::user::domain::vo::*;
-    use crate::user::domain::validations::UserDomainError;
+    use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
     use crate::user::domain::events::UserDomainEvent;
 
     fn new_email(value: &str) -> Result<Email, UserDomainError> {
@@ -108,7 +108,7 @@ mod tests {
                 match result {
                     Ok(_) => {
                         assert!(result.is_ok());
-                        assert!(!user.phone_verified());
+                        assert!(user.phone_verified());
                     }
                     Err(err) => println!("Error creando número: {err}"),
                 }
@@ -153,13 +153,13 @@ mod tests {
                 if let Ok(new_email) = new_email("new.user@example.co") {
                     let _ = user.update_email(new_email);
                     let events = user.take_events();
-                    assert!(events.iter().any(|e| matches!(**e, UserDomainEvent::EmailUpdated(_))));
+                    assert!(events.iter().any(|e| matches!(e, UserDomainEvent::EmailUpdated(_))));
                 }
 
                 let result = user.verify_email();
                 if result.is_ok() {
                     let events = user.take_events();
-                    assert!(events.iter().any(|e| matches!(**e, UserDomainEvent::EmailVerified(_))));
+                    assert!(events.iter().any(|e| matches!(e, UserDomainEvent::EmailVerified(_))));
                 } else {
                     println!("(OK) No se pudo verificar correo, error esperado en flujo inconsistente");
                 }
@@ -167,4 +167,261 @@ mod tests {
             Err(err) => println!("❌ Error creando correo en test de secuencia: {err}"),
         }
     }
+
+    fn active_user() -> User {
+        let mut user = User::register(new_email("state.user@example.co").unwrap());
+        user.activate().unwrap();
+        user.take_events();
+        user
+    }
+
+    #[test]
+    fn assign_username_updates_state_and_emits_event() {
+        let mut user = active_user();
+        let username = new_username("store_owner").unwrap();
+
+        assert!(user.assign_username(username.clone()).is_ok());
+        assert_eq!(user.username(), Some(&username));
+
+        let events = user.take_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            UserDomainEvent::UsernameAssigned(e) => {
+                assert_eq!(e.username(), &username);
+                assert_eq!(e.user_id(), user.id());
+            }
+            _ => panic!("Se esperaba UsernameAssigned"),
+        }
+
+        // Reasignar el mismo valor no produce cambios ni eventos
+        let err = user.assign_username(username.clone()).unwrap_err();
+        assert_eq!(err.category(), &CategoryError::Username);
+        assert!(matches!(err.detail(), TypeError::Unchanged { .. }));
+        assert!(user.take_events().is_empty());
+
+        // Reemplazar por uno distinto sí es un cambio
+        let other = new_username("store_admin").unwrap();
+        assert!(user.assign_username(other.clone()).is_ok());
+        assert_eq!(user.username(), Some(&other));
+    }
+
+    #[test]
+    fn assign_phone_resets_verification_and_rejects_unchanged() {
+        let mut user = active_user();
+        let phone = new_phone("57", "3001234567").unwrap();
+
+        assert!(user.assign_phone(phone.clone()).is_ok());
+        assert_eq!(user.phone(), Some(&phone));
+        assert!(!user.phone_verified());
+
+        assert!(user.verify_phone().is_ok());
+        assert!(user.phone_verified());
+
+        let err = user.assign_phone(phone.clone()).unwrap_err();
+        assert_eq!(err.code(), "user.phone.unchanged");
+        assert!(user.phone_verified(), "Un cambio rechazado no debe tocar el estado");
+
+        let new_phone = new_phone("57", "3109876543").unwrap();
+        assert!(user.assign_phone(new_phone.clone()).is_ok());
+        assert_eq!(user.phone(), Some(&new_phone));
+        assert!(!user.phone_verified());
+
+        let events = user.take_events();
+        let names: Vec<&str> = events.iter().map(|e| e.event_name()).collect();
+        assert_eq!(names, vec!["user_phone_assigned", "user_phone_verified", "user_phone_assigned"]);
+    }
+
+    #[test]
+    fn unassign_phone_clears_state_and_emits_event() {
+        let mut user = active_user();
+
+        let err = user.unassign_phone().unwrap_err();
+        assert_eq!(err.code(), "user.phone.missing");
+
+        let phone = new_phone("57", "3001234567").unwrap();
+        user.assign_phone(phone.clone()).unwrap();
+        user.verify_phone().unwrap();
+        user.take_events();
+
+        assert!(user.unassign_phone().is_ok());
+        assert!(user.phone().is_none());
+        assert!(!user.phone_verified());
+        assert!(user.verify_phone().is_err());
+
+        let events = user.take_events();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            UserDomainEvent::PhoneUnassigned(e) => assert_eq!(e.phone(), &phone),
+            _ => panic!("Se esperaba PhoneUnassigned"),
+        }
+    }
+
+    #[test]
+    fn link_and_unlink_external_id() {
+        let mut user = active_user();
+        let external_id = new_external_id("EXTERNAL-ABC-123456789").unwrap();
+
+        assert!(user.unlink_external_id().is_err());
+
+        assert!(user.link_external_id(external_id.clone()).is_ok());
+        assert_eq!(user.external_id(), Some(&external_id));
+
+        let err = user.link_external_id(external_id.clone()).unwrap_err();
+        assert_eq!(err.code(), "user.external_id.unchanged");
+
+        assert!(user.unlink_external_id().is_ok());
+        assert!(user.external_id().is_none());
+
+        let events = user.take_events();
+        assert!(matches!(&events[0], UserDomainEvent::ExternalIdLinked(e) if e.external_id() == &external_id));
+        assert!(matches!(&events[1], UserDomainEvent::ExternalIdUnlinked(e) if e.external_id() == &external_id));
+        assert_eq!(events.len(), 2);
+    }
+
+    #[test]
+    fn changes_are_refused_for_suspended_and_deleted_users() {
+        let phone = new_phone("57", "3001234567").unwrap();
+        let username = new_username("blocked_user").unwrap();
+        let external_id = new_external_id("EXTERNAL-XYZ-987654321").unwrap();
+
+        let mut suspended = active_user();
+        suspended.assign_phone(phone.clone()).unwrap();
+        suspended.suspend().unwrap();
+        suspended.take_events();
+
+        let mut deleted = active_user();
+        deleted.delete().unwrap();
+        deleted.take_events();
+
+        for user in [&mut suspended, &mut deleted] {
+            let status = user.status().clone();
+            let before_updated_at = user.updated_at().clone();
+
+            let err = user.assign_username(username.clone()).unwrap_err();
+            assert_eq!(err.detail(), &TypeError::InvalidStatus { status: status.clone() });
+            assert!(user.assign_phone(new_phone("57", "3109876543").unwrap()).is_err());
+            assert!(user.link_external_id(external_id.clone()).is_err());
+            assert!(user.unlink_external_id().is_err());
+            assert!(user.unassign_phone().is_err());
+            let err = user.update_email(new_email("blocked.new@example.co").unwrap()).unwrap_err();
+            assert_eq!(err.category(), &CategoryError::Email);
+            assert_eq!(user.status(), &status);
+
+            assert!(user.username().is_none());
+            assert!(user.external_id().is_none());
+            assert_eq!(user.updated_at(), &before_updated_at);
+            assert!(user.take_events().is_empty(), "No deben emitirse eventos para '{}'", status);
+        }
+
+        assert_eq!(suspended.phone(), Some(&phone));
+        let err = suspended.verify_phone().unwrap_err();
+        assert_eq!(err.category(), &CategoryError::Phone);
+        assert!(!suspended.phone_verified());
+    }
+
+    #[test]
+    fn state_and_event_stream_stay_consistent() {
+        let mut user = User::register(new_email("stream.user@example.co").unwrap());
+        user.verify_email().unwrap();
+        user.activate().unwrap();
+        user.assign_username(new_username("stream_user").unwrap()).unwrap();
+        user.assign_phone(new_phone("57", "3001234567").unwrap()).unwrap();
+        user.verify_phone().unwrap();
+        user.link_external_id(new_external_id("EXTERNAL-STREAM-0000001").unwrap()).unwrap();
+        user.unassign_phone().unwrap();
+
+        let mut username = None;
+        let mut phone = None;
+        let mut phone_verified = false;
+        let mut external_id = None;
+
+        for event in user.take_events() {
+            match event {
+                UserDomainEvent::UsernameAssigned(e) => username = Some(e.username().clone()),
+                UserDomainEvent::PhoneAssigned(e) => { phone = Some(e.phone().clone()); phone_verified = false; }
+                UserDomainEvent::PhoneVerified(_) => phone_verified = true,
+                UserDomainEvent::PhoneUnassigned(_) => { phone = None; phone_verified = false; }
+                UserDomainEvent::ExternalIdLinked(e) => external_id = Some(e.external_id().clone()),
+                UserDomainEvent::ExternalIdUnlinked(_) => external_id = None,
+                _ => {}
+            }
+        }
+
+        assert_eq!(user.username(), username.as_ref());
+        assert_eq!(user.phone(), phone.as_ref());
+        assert_eq!(user.phone_verified(), phone_verified);
+        assert_eq!(user.external_id(), external_id.as_ref());
+    }
+
+    #[test]
+    fn snapshot_and_restore_round_trip_without_events() {
+        let mut user = active_user();
+        user.assign_username(new_username("snapshot_user").unwrap()).unwrap();
+        user.assign_phone(new_phone("57", "3001234567").unwrap()).unwrap();
+        user.verify_phone().unwrap();
+        user.link_external_id(new_external_id("EXTERNAL-SNAP-000000001").unwrap()).unwrap();
+
+        let snapshot = user.snapshot();
+        let mut restored = User::restore(snapshot.clone()).unwrap();
+
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(restored.id(), user.id());
+        assert_eq!(restored.status(), &UserStatus::Active);
+        assert!(restored.phone_verified());
+        assert!(restored.take_events().is_empty(), "restore no debe emitir eventos");
+
+        // El usuario restaurado sigue aplicando sus invariantes
+        assert!(restored.assign_username(new_username("snapshot_user").unwrap()).is_err());
+        assert!(restored.suspend().is_ok());
+    }
+
+    #[test]
+    fn snapshot_serializes_to_json_and_back() {
+        let mut user = active_user();
+        user.assign_phone(new_phone("44", "7700901234").unwrap()).unwrap();
+
+        let json = serde_json::to_value(user.snapshot()).unwrap();
+        println!("🧾 Snapshot: {json}");
+        assert_eq!(json["email"], "state.user@example.co");
+        assert_eq!(json["phone"], "+44 7700901234");
+        assert_eq!(json["status"], "active");
+        assert_eq!(json["id"], user.id().as_string());
+
+        let back: UserSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(back, user.snapshot());
+
+        let invalid = serde_json::json!({
+            "id": user.id().as_string(), "external_id": null, "username": null,
+            "email": "not-an-email", "email_verified": false, "phone": null, "phone_verified": false,
+            "status": "active", "created_at": "2024-05-10T12:30:00Z", "updated_at": "2024-05-10T12:30:00Z",
+            "deleted_at": null
+        });
+        assert!(serde_json::from_value::<UserSnapshot>(invalid).is_err());
+
+        // La versión es obligatoria: sin ella el usuario parecería nuevo
+        let mut unversioned = serde_json::to_value(user.snapshot()).unwrap();
+        unversioned.as_object_mut().unwrap().remove("version");
+        assert!(serde_json::from_value::<UserSnapshot>(unversioned).is_err());
+    }
+
+    #[test]
+    fn restore_rejects_inconsistent_snapshots() {
+        let user = active_user();
+
+        let mut snapshot = user.snapshot();
+        snapshot.phone_verified = true;
+        assert_eq!(User::restore(snapshot).unwrap_err().code(), "user.phone.missing");
+
+        let mut snapshot = user.snapshot();
+        snapshot.status = UserStatus::Deleted;
+        assert_eq!(User::restore(snapshot).unwrap_err().code(), "user.status.invalid_status");
+
+        let mut snapshot = user.snapshot();
+        snapshot.deleted_at = Some(OccurredAt::now());
+        assert!(User::restore(snapshot).is_err());
+
+        let mut snapshot = user.snapshot();
+        snapshot.version = 0;
+        assert_eq!(User::restore(snapshot).unwrap_err().code(), "user.version.missing");
+    }
 }
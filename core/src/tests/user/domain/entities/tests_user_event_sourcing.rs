@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use crate::user::domain::entities::User;
+    use crate::user::domain::events::{UserDomainEvent, UserEmailVerified, UserRegistered};
+    use crate::user::domain::vo::*;
+
+    fn email(value: &str) -> Email {
+        Email::new(value).unwrap()
+    }
+
+    /// Usuario con historial completo: devuelve el usuario y todos sus eventos.
+    fn user_with_history() -> (User, Vec<UserDomainEvent>) {
+        let mut user = User::register(email("history.user@example.co"));
+        user.verify_email().unwrap();
+        user.activate().unwrap();
+        user.assign_username(Username::new("history_user").unwrap()).unwrap();
+        user.assign_phone(Phone::new("57", "3001234567").unwrap()).unwrap();
+        user.verify_phone().unwrap();
+        user.link_external_id(ExternalId::new("EXTERNAL-HISTORY-000001").unwrap()).unwrap();
+        user.update_email(email("history.new@example.co")).unwrap();
+        user.suspend().unwrap_err(); // pending tras cambiar correo → no se puede suspender
+        user.activate().unwrap();
+        user.suspend().unwrap();
+
+        let events = user.take_events();
+        (user, events)
+    }
+
+    #[test]
+    fn version_counts_recorded_events() {
+        let mut user = User::register(email("version.user@example.co"));
+        assert_eq!(user.version(), 1);
+        assert_eq!(user.persisted_version(), 0);
+
+        user.activate().unwrap();
+        assert_eq!(user.version(), 2);
+
+        // Un comando rechazado no cambia la versión
+        assert!(user.activate().is_err());
+        assert_eq!(user.version(), 2);
+
+        user.take_events();
+        assert_eq!(user.persisted_version(), 2);
+    }
+
+    #[test]
+    fn replaying_history_rebuilds_identical_state() {
+        let (user, events) = user_with_history();
+        println!("📜 {} eventos: {:?}", events.len(), events.iter().map(|e| e.event_name()).collect::<Vec<_>>());
+
+        let mut replayed = User::from_events(events.clone()).unwrap();
+
+        assert_eq!(replayed.snapshot(), user.snapshot());
+        assert_eq!(replayed.version(), events.len() as u64);
+        assert!(replayed.take_events().is_empty(), "Reproducir no genera eventos pendientes");
+        assert_eq!(replayed.status(), &UserStatus::Suspended);
+        assert_eq!(replayed.email().as_str(), "history.new@example.co");
+        assert!(!replayed.email_verified());
+        assert!(replayed.phone_verified());
+    }
+
+    #[test]
+    fn replayed_user_keeps_accepting_commands() {
+        let (_, events) = user_with_history();
+        let mut replayed = User::from_events(events).unwrap();
+        let version = replayed.version();
+
+        replayed.activate().unwrap();
+        replayed.delete().unwrap();
+
+        assert_eq!(replayed.version(), version + 2);
+        assert_eq!(replayed.persisted_version(), version);
+        let deleted = replayed.take_events()[1].occurred_at();
+        assert_eq!(replayed.deleted_at(), Some(&deleted));
+    }
+
+    #[test]
+    fn timestamps_come_from_events() {
+        let (user, events) = user_with_history();
+
+        assert_eq!(user.created_at(), &events.first().unwrap().occurred_at());
+        assert_eq!(user.updated_at(), &events.last().unwrap().occurred_at());
+    }
+
+    #[test]
+    fn invalid_streams_are_rejected() {
+        assert_eq!(User::from_events(Vec::new()).unwrap_err().code(), "user.event.empty");
+
+        let (_, events) = user_with_history();
+        let without_registration: Vec<UserDomainEvent> = events.iter().skip(1).cloned().collect();
+        assert_eq!(User::from_events(without_registration).unwrap_err().code(), "user.event.missing");
+
+        let mut foreign = events.clone();
+        foreign.push(UserDomainEvent::EmailVerified(UserEmailVerified::new(UserId::new(), email("other@example.co"))));
+        assert_eq!(User::from_events(foreign).unwrap_err().code(), "user.event.mismatch");
+
+        let mut duplicated = events.clone();
+        let registered = UserRegistered::new(events[0].user_id().clone(), email("again@example.co"));
+        duplicated.push(UserDomainEvent::Registered(registered));
+        assert_eq!(User::from_events(duplicated).unwrap_err().code(), "user.event.not_supported");
+    }
+}
@@ -0,0 +1 @@
+pub mod tests_event_envelope;
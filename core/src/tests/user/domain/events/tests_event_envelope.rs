@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::user::domain::entities::User;
+    use crate::user::domain::events::{UserDomainEvent, UserEventEnvelope, UserRegistered};
+    use crate::user::domain::vo::*;
+
+    fn email(value: &str) -> Email {
+        Email::new(value).unwrap()
+    }
+
+    /// Usuario con varios eventos registrados (sin tomar).
+    fn user_with_events() -> User {
+        let mut user = User::register(email("envelope.user@example.co"));
+        user.verify_email().unwrap();
+        user.activate().unwrap();
+        user.assign_username(Username::new("envelope_user").unwrap()).unwrap();
+        user.assign_phone(Phone::new("57", "3001234567").unwrap()).unwrap();
+        user.link_external_id(ExternalId::new("EXTERNAL-ENVELOPE-00001").unwrap()).unwrap();
+        user.update_email(email("envelope.new@example.co")).unwrap();
+        user
+    }
+
+    #[test]
+    fn recorded_events_carry_metadata() {
+        let mut user = user_with_events();
+        let user_id = user.id().clone();
+        let events = user.take_events();
+
+        for (index, event) in events.iter().enumerate() {
+            println!("🏷️ v{} {} {}", event.aggregate_version(), event.event_name(), event.event_id());
+            assert_eq!(event.aggregate_version(), index as u64 + 1);
+            assert_eq!(event.user_id(), &user_id);
+            assert_eq!(event.schema_version(), UserDomainEvent::SCHEMA_VERSION);
+        }
+
+        // Cada evento tiene su propio identificador
+        let mut ids: Vec<_> = events.iter().map(|e| e.event_id()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), events.len());
+    }
+
+    #[test]
+    fn occurred_at_is_the_stored_time() {
+        let event = UserDomainEvent::Registered(UserRegistered::new(UserId::new(), email("stored.time@example.co")));
+
+        let first = event.occurred_at();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert_eq!(event.occurred_at(), first, "occurred_at no debe recalcularse");
+    }
+
+    #[test]
+    fn envelope_has_stable_shape() {
+        let mut user = user_with_events();
+        let events = user.take_events();
+        let updated = events.last().unwrap();
+
+        let value = updated.to_json();
+        println!("📦 {}", serde_json::to_string_pretty(&value).unwrap());
+
+        assert_eq!(value["type"], "user_email_updated");
+        assert_eq!(value["event_id"], json!(updated.event_id().to_string()));
+        assert_eq!(value["aggregate_id"], json!(user.id().to_string()));
+        assert_eq!(value["aggregate_version"], json!(events.len()));
+        assert_eq!(value["schema_version"], json!(1));
+        assert_eq!(value["occurred_at"], json!(updated.occurred_at().to_string()));
+        assert_eq!(value["payload"]["old_email"], "envelope.user@example.co");
+        assert_eq!(value["payload"]["new_email"], "envelope.new@example.co");
+        assert!(value["payload"].get("metadata").is_none(), "Los metadatos viajan fuera del payload");
+    }
+
+    #[test]
+    fn envelope_round_trip_preserves_everything() {
+        let mut user = user_with_events();
+        let events = user.take_events();
+
+        let decoded: Vec<UserDomainEvent> = events
+            .iter()
+            .map(|e| UserDomainEvent::from_json(e.to_json()).unwrap())
+            .collect();
+
+        for (original, decoded) in events.iter().zip(&decoded) {
+            assert_eq!(decoded.event_name(), original.event_name());
+            assert_eq!(decoded.metadata(), original.metadata());
+            assert_eq!(decoded.to_envelope(), original.to_envelope());
+        }
+
+        // Los eventos decodificados reconstruyen el mismo usuario
+        let replayed = User::from_events(decoded).unwrap();
+        assert_eq!(replayed.snapshot(), user.snapshot());
+    }
+
+    #[test]
+    fn invalid_envelopes_are_rejected() {
+        let mut user = user_with_events();
+        let envelope = user.take_events().remove(0).to_envelope();
+
+        let unknown = UserEventEnvelope { event_type: "user_teleported".into(), ..envelope.clone() };
+        assert_eq!(UserDomainEvent::from_envelope(unknown).unwrap_err().code(), "user.event.not_supported");
+
+        let future = UserEventEnvelope { schema_version: UserDomainEvent::SCHEMA_VERSION + 1, ..envelope.clone() };
+        assert_eq!(UserDomainEvent::from_envelope(future).unwrap_err().code(), "user.event.invalid_state");
+
+        let other_user = UserEventEnvelope { aggregate_id: UserId::new(), ..envelope.clone() };
+        assert_eq!(UserDomainEvent::from_envelope(other_user).unwrap_err().code(), "user.event.mismatch");
+
+        let bad_payload = UserEventEnvelope { payload: json!({ "email": "no-es-un-correo" }), ..envelope };
+        assert_eq!(UserDomainEvent::from_envelope(bad_payload).unwrap_err().code(), "user.event.invalid_format");
+
+        assert_eq!(UserDomainEvent::from_json(json!({ "type": "user_registered" })).unwrap_err().code(), "user.event.invalid_format");
+    }
+}
@@ -1,2 +1,5 @@
 pub mod vo;
 pub mod entities;
+pub mod events;
+pub mod validations;
+pub mod services;
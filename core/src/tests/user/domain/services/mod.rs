@@ -0,0 +1,5 @@
+pub mod tests_user_registration;
+pub mod tests_lockout;
+pub mod tests_password_reset;
+pub mod tests_password_history;
+pub mod tests_session_service;
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use std::net::IpAddr;
+
+    use crate::user::domain::entities::{User, UserPassword};
+    use crate::user::domain::events::UserDomainEvent;
+    use crate::user::domain::services::{AuthenticationService, LockoutPolicy, LoginThrottle, PasswordCheck};
+    use crate::user::domain::validations::{TypeError, UserDomainError};
+    use crate::user::infrastructure::services_impl::InMemoryLoginThrottle;
+    use crate::tests::user::fixtures::{registered, start, stored_password, PlainHasher};
+
+    fn policy() -> LockoutPolicy {
+        LockoutPolicy {
+            max_failed_attempts: 3,
+            base_lock_duration: Duration::minutes(1),
+            max_lock_duration: Duration::minutes(10),
+            failure_window: Duration::minutes(15),
+            max_failed_attempts_per_ip: 4,
+            ip_lock_duration: Duration::minutes(30),
+        }
+    }
+
+    fn cashier() -> (User, UserPassword) {
+        let user = registered("cajero@example.co");
+        let password = stored_password(user.id().as_uuid(), "clave-caja");
+        (user, password)
+    }
+
+    fn attempt(
+        user: &mut User,
+        password: &mut UserPassword,
+        raw: &str,
+        now: DateTime<Utc>,
+    ) -> Result<PasswordCheck, UserDomainError> {
+        AuthenticationService::authenticate_with_password(user, raw, password, &PlainHasher, &policy(), now)
+    }
+
+    #[test]
+    fn test_threshold_locks_with_distinct_error_and_event() {
+        let (mut user, mut password) = cashier();
+        let now = start();
+
+        // 🔹 Por debajo del umbral: contraseña incorrecta
+        for n in 1..=2 {
+            let err = attempt(&mut user, &mut password, "0000", now).unwrap_err();
+            println!("❌ intento {n}: {}", err.code());
+            assert_eq!(err.code(), "user.password.mismatch");
+        }
+        assert!(user.pending_events().is_empty());
+
+        // 🔹 El tercer fallo bloquea y lo indica
+        let err = attempt(&mut user, &mut password, "0000", now).unwrap_err();
+        println!("🔒 {}", err.code());
+        assert_eq!(err.code(), "user.password.locked");
+        assert_eq!(err.detail(), &TypeError::Locked { until: (now + Duration::minutes(1)).to_rfc3339() });
+        assert_eq!(password.locked_until, Some(now + Duration::minutes(1)));
+
+        let events = user.take_events();
+        match events.as_slice() {
+            [UserDomainEvent::LockedOut(e)] => {
+                assert_eq!(e.failed_attempts(), 3);
+                assert_eq!(e.locked_until().value(), now + Duration::minutes(1));
+            }
+            other => panic!("Se esperaba UserLockedOut, hubo {other:?}"),
+        }
+
+        // 🔹 Bloqueada: ni la contraseña correcta entra, y no se cuenta el intento
+        let err = attempt(&mut user, &mut password, "clave-caja", now + Duration::seconds(30)).unwrap_err();
+        assert_eq!(err.code(), "user.password.locked");
+        assert_eq!(password.failed_attempts, 3);
+
+        // 🔹 Al caducar, la contraseña correcta desbloquea y reinicia el contador
+        let later = now + Duration::minutes(2);
+        assert_eq!(attempt(&mut user, &mut password, "clave-caja", later).unwrap(), PasswordCheck::Valid);
+        assert_eq!(password.failed_attempts, 0);
+        assert_eq!(password.locked_until, None);
+        assert!(matches!(user.take_events().as_slice(), [UserDomainEvent::Unlocked(_)]));
+    }
+
+    #[test]
+    fn test_lock_duration_grows_exponentially_within_the_window() {
+        let (mut user, mut password) = cashier();
+        let mut now = start();
+
+        for _ in 0..3 {
+            let _ = attempt(&mut user, &mut password, "0000", now);
+        }
+        assert_eq!(password.locked_until, Some(now + Duration::minutes(1)));
+
+        // 🔹 Cada fallo tras un bloqueo duplica la duración, hasta el máximo
+        for expected in [2, 4, 8, 10, 10] {
+            now = password.locked_until.unwrap() + Duration::seconds(1);
+            let err = attempt(&mut user, &mut password, "0000", now).unwrap_err();
+            assert_eq!(err.code(), "user.password.locked");
+            println!("⏱️ bloqueo de {expected} min");
+            assert_eq!(password.locked_until, Some(now + Duration::minutes(expected)));
+        }
+
+        let policy = policy();
+        assert_eq!(policy.lock_duration(2), None);
+        assert_eq!(policy.lock_duration(3), Some(Duration::minutes(1)));
+        assert_eq!(policy.lock_duration(500), Some(Duration::minutes(10)));
+    }
+
+    #[test]
+    fn test_sliding_window_forgets_old_failures() {
+        let (mut user, mut password) = cashier();
+        let now = start();
+
+        let _ = attempt(&mut user, &mut password, "0000", now);
+        let _ = attempt(&mut user, &mut password, "0000", now + Duration::minutes(10));
+        assert_eq!(password.failed_attempts, 2);
+        assert_eq!(password.last_failed_at, Some(now + Duration::minutes(10)));
+
+        // 🔹 La ventana cuenta desde el último fallo: más de 15 min después, se empieza de cero
+        let err = attempt(&mut user, &mut password, "0000", now + Duration::minutes(26)).unwrap_err();
+        assert_eq!(err.code(), "user.password.mismatch");
+        assert_eq!(password.failed_attempts, 1);
+        assert!(password.locked_until.is_none());
+    }
+
+    #[test]
+    fn test_manual_unlock() {
+        let (mut user, mut password) = cashier();
+        let now = start();
+
+        let err = AuthenticationService::unlock(&mut user, &mut password).unwrap_err();
+        assert_eq!(err.code(), "user.password.unchanged");
+
+        for _ in 0..3 {
+            let _ = attempt(&mut user, &mut password, "0000", now);
+        }
+        user.take_events();
+
+        AuthenticationService::unlock(&mut user, &mut password).unwrap();
+        assert_eq!(password.failed_attempts, 0);
+        assert!(!password.is_locked(now));
+        assert!(matches!(user.take_events().as_slice(), [UserDomainEvent::Unlocked(_)]));
+        assert_eq!(attempt(&mut user, &mut password, "clave-caja", now).unwrap(), PasswordCheck::Valid);
+    }
+
+    #[test]
+    fn test_ip_counters_block_across_accounts() {
+        let policy = policy();
+        let throttle = InMemoryLoginThrottle::new();
+        let ip: IpAddr = "10.0.0.7".parse().unwrap();
+        let other: IpAddr = "10.0.0.8".parse().unwrap();
+        let now = start();
+
+        // 🔹 Fallos antiguos salen de la ventana
+        policy.register_ip_failure(&throttle, ip, now - Duration::hours(1));
+        for n in 0..3 {
+            policy.register_ip_failure(&throttle, ip, now + Duration::seconds(n));
+            policy.check_ip(&throttle, ip, now).unwrap();
+        }
+
+        // 🔹 El cuarto fallo en ventana bloquea la IP, no las demás
+        policy.register_ip_failure(&throttle, ip, now + Duration::seconds(3));
+        let err = policy.check_ip(&throttle, ip, now + Duration::minutes(1)).unwrap_err();
+        println!("🚫 {}", err.code());
+        assert_eq!(err.code(), "user.password.locked");
+        policy.check_ip(&throttle, other, now).unwrap();
+
+        // 🔹 Caduca sola o se limpia
+        policy.check_ip(&throttle, ip, now + Duration::minutes(31)).unwrap();
+        throttle.clear(ip);
+        assert_eq!(throttle.blocked_until(ip), None);
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use crate::user::domain::entities::UserPassword;
+    use crate::user::domain::services::PasswordHistoryPolicy;
+    use crate::tests::user::fixtures::{plain, start, stored_password, PlainHasher};
+
+    fn stored(raw: &str) -> UserPassword {
+        stored_password(Uuid::new_v4(), raw)
+    }
+
+    #[test]
+    fn test_history_rejects_the_last_n_passwords() {
+        let policy = PasswordHistoryPolicy { remember: 3, ..Default::default() };
+        let mut password = stored("Clave-Inicial-0");
+        let now = start();
+
+        // 🔹 La actual también cuenta
+        let err = policy.replace(&mut password, &plain("Clave-Inicial-0"), &PlainHasher, now).unwrap_err();
+        println!("♻️ {} {:?}", err.code(), err.params());
+        assert_eq!(err.code(), "user.password.reused");
+        assert_eq!(err.params()["count"], "3");
+
+        for (i, raw) in ["Clave-Rotada-1", "Clave-Rotada-2", "Clave-Rotada-3"].iter().enumerate() {
+            policy.replace(&mut password, &plain(raw), &PlainHasher, now + Duration::days(i as i64)).unwrap();
+        }
+
+        // 🔹 Se recuerdan la actual y las dos anteriores, de la más reciente a la más antigua
+        assert_eq!(password.password_hash, "plain$Clave-Rotada-3");
+        assert_eq!(password.password_history, ["plain$Clave-Rotada-2", "plain$Clave-Rotada-1"]);
+        assert_eq!(password.password_changed_at, Some(now + Duration::days(2)));
+        assert!(policy.check_reuse(&password, &plain("Clave-Rotada-1"), &PlainHasher).is_err());
+        assert!(policy.check_reuse(&password, &plain("Clave-Inicial-0"), &PlainHasher).is_ok());
+
+        // 🔹 Hashes que el hasher ya no reconoce no bloquean el cambio
+        password.password_history.insert(0, "$2b$04$legado".into());
+        assert!(policy.check_reuse(&password, &plain("Clave-Nueva-4"), &PlainHasher).is_ok());
+
+        // 🔹 `remember: 0` desactiva la comprobación y no guarda historial
+        let disabled = PasswordHistoryPolicy { remember: 0, ..Default::default() };
+        let mut password = stored("Clave-Inicial-0");
+        disabled.replace(&mut password, &plain("Clave-Inicial-0"), &PlainHasher, now).unwrap();
+        assert!(password.password_history.is_empty());
+    }
+
+    #[test]
+    fn test_min_age_counts_from_the_last_change() {
+        let policy = PasswordHistoryPolicy { remember: 5, min_age: Duration::days(1) };
+        let mut password = stored("Clave-Inicial-0");
+        let now = start();
+
+        // 🔹 La contraseña del alta se puede cambiar enseguida
+        policy.check_min_age(&password, now).unwrap();
+        policy.replace(&mut password, &plain("Clave-Rotada-1"), &PlainHasher, now).unwrap();
+
+        let err = policy.check_min_age(&password, now + Duration::hours(23)).unwrap_err();
+        println!("⏳ {} {:?}", err.code(), err.params());
+        assert_eq!(err.code(), "user.password.rate_limited");
+        assert_eq!(err.params()["retry_after"], (now + Duration::days(1)).to_rfc3339());
+        assert!(policy.check_min_age(&password, now + Duration::days(1)).is_ok());
+
+        // 🔹 Recalcular el hash de la misma contraseña no cuenta como cambio
+        password.update_password("plain$Clave-Rotada-1".into(), None).unwrap();
+        assert_eq!(password.password_changed_at, Some(now));
+        assert_eq!(password.password_history.len(), 1);
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::user::domain::entities::{User, UserPassword, UserSession};
+    use crate::user::domain::events::UserDomainEvent;
+    use crate::user::domain::services::{PasswordHasher, PasswordHistoryPolicy, PasswordResetPolicy, PasswordResetService};
+    use crate::tests::user::fixtures::{plain, registered, start, stored_password, PlainHasher};
+
+    fn account() -> (User, UserPassword) {
+        let user = registered("olvido@example.co");
+        let password = stored_password(user.id().as_uuid(), "vieja");
+        (user, password)
+    }
+
+    fn session(user: &User) -> UserSession {
+        let expires_at = Utc::now() + Duration::days(1);
+        UserSession::new(Uuid::new_v4(), user.id().as_uuid(), None, expires_at, None, None, None).unwrap()
+    }
+
+    #[test]
+    fn test_request_stores_only_the_hash_and_is_rate_limited() {
+        let (mut user, mut password) = account();
+        let policy = PasswordResetPolicy::default();
+        let now = start();
+
+        let token = PasswordResetService::request(&mut user, &mut password, &policy, now).unwrap();
+        println!("🎟️ token de {} caracteres", token.len());
+
+        // 🔹 Token aleatorio; sólo se guarda su hash
+        assert_eq!(token.len(), 64);
+        assert_eq!(password.reset_token, Some(PasswordResetService::hash_token(&token)));
+        assert_ne!(password.reset_token.as_deref(), Some(token.as_str()));
+        assert_eq!(password.reset_token_expires, Some(now + Duration::hours(1)));
+        assert_eq!(password.reset_requested_at, Some(now));
+
+        // 🔹 El evento no lleva el token, ni en `Debug` ni serializado
+        let events = user.take_events();
+        let [UserDomainEvent::PasswordResetRequested(event)] = events.as_slice() else {
+            panic!("Se esperaba UserPasswordResetRequested, hubo {events:?}");
+        };
+        assert!(!format!("{event:?}").contains(&token));
+        assert!(!events[0].to_json().to_string().contains(&token));
+
+        // 🔹 Una segunda solicitud antes del intervalo mínimo se rechaza
+        let err = PasswordResetService::request(&mut user, &mut password, &policy, now + Duration::minutes(1)).unwrap_err();
+        println!("⏳ {}", err.code());
+        assert_eq!(err.code(), "user.reset_token.rate_limited");
+        assert_eq!(err.params()["retry_after"], (now + Duration::minutes(5)).to_rfc3339());
+
+        // 🔹 Pasado el intervalo, el token nuevo sustituye al anterior
+        let newer = PasswordResetService::request(&mut user, &mut password, &policy, now + Duration::minutes(6)).unwrap();
+        assert_ne!(newer, token);
+        let err = PasswordResetService::reset(&mut user, &mut password, &mut [], &token, &plain("Nueva-Clave-1"), &PlainHasher, &PasswordHistoryPolicy::default(), now + Duration::minutes(7))
+            .unwrap_err();
+        assert_eq!(err.code(), "user.reset_token.mismatch");
+    }
+
+    #[test]
+    fn test_reset_is_single_use_and_closes_every_session() {
+        let (mut user, mut password) = account();
+        let now = start();
+        let token = PasswordResetService::request(&mut user, &mut password, &PasswordResetPolicy::default(), now).unwrap();
+        password.failed_attempts = 7;
+        password.lock_until(now + Duration::hours(2));
+        user.take_events();
+
+        let mut sessions = vec![session(&user), session(&user), session(&user)];
+        sessions[2].terminate();
+
+        // 🔹 Caducado
+        let err = PasswordResetService::reset(&mut user, &mut password, &mut sessions, &token, &plain("Nueva-Clave-1"), &PlainHasher, &PasswordHistoryPolicy::default(), now + Duration::hours(1))
+            .unwrap_err();
+        assert_eq!(err.code(), "user.reset_token.expired");
+
+        // 🔹 Válido: nueva contraseña, token consumido, bloqueo levantado y sesiones cerradas
+        let revoked =
+            PasswordResetService::reset(&mut user, &mut password, &mut sessions, &token, &plain("Nueva-Clave-1"), &PlainHasher, &PasswordHistoryPolicy::default(), now + Duration::minutes(30))
+                .unwrap();
+        println!("🔐 {revoked} sesiones cerradas");
+        assert_eq!(revoked, 2);
+        assert!(sessions.iter().all(|s| !s.is_active));
+        assert_eq!(sessions.iter().map(|s| s.access_token_version).collect::<Vec<_>>(), [2, 2, 1]);
+        assert!(PlainHasher.verify("Nueva-Clave-1", &password.password_hash).unwrap());
+        assert_eq!((password.reset_token.as_deref(), password.reset_token_expires), (None, None));
+        assert_eq!((password.failed_attempts, password.locked_until), (0, None));
+
+        let names: Vec<&str> = user.take_events().iter().map(|e| e.event_name()).collect();
+        assert_eq!(names, ["user_unlocked", "user_password_changed"]);
+
+        // 🔹 Un solo uso
+        let err = PasswordResetService::reset(&mut user, &mut password, &mut sessions, &token, &plain("Otra-Clave-22"), &PlainHasher, &PasswordHistoryPolicy::default(), now + Duration::minutes(31))
+            .unwrap_err();
+        assert_eq!(err.code(), "user.reset_token.missing");
+    }
+    #[test]
+    fn test_deleted_accounts_cannot_reset() {
+        let (mut user, mut password) = account();
+        let now = start();
+        let token = PasswordResetService::request(&mut user, &mut password, &PasswordResetPolicy::default(), now).unwrap();
+        user.delete().unwrap();
+        user.take_events();
+
+        // 🔹 El token sigue guardado, pero la cuenta ya no admite cambios
+        let err = PasswordResetService::reset(&mut user, &mut password, &mut [], &token, &plain("Nueva-Clave-1"), &PlainHasher, &PasswordHistoryPolicy::default(), now + Duration::minutes(1))
+            .unwrap_err();
+        println!("🗑️ {}", err.code());
+        assert_eq!(err.code(), "user.status.invalid_status");
+        assert!(PlainHasher.verify("vieja", &password.password_hash).unwrap());
+        assert!(user.pending_events().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::user::domain::entities::{User, UserSession};
+    use crate::user::domain::services::{AccessTokenClaims, AccessTokenCodec, SessionPolicy, SessionService};
+    use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+    use crate::tests::user::fixtures::{self, start};
+
+    /// Codec sin firma: los claims en JSON, para no depender del adaptador JWT.
+    struct JsonCodec;
+
+    impl AccessTokenCodec for JsonCodec {
+        fn encode(&self, claims: &AccessTokenClaims) -> Result<String, UserDomainError> {
+            Ok(serde_json::to_string(claims).unwrap())
+        }
+
+        fn decode(&self, token: &str) -> Result<AccessTokenClaims, UserDomainError> {
+            serde_json::from_str(token).map_err(|_| (CategoryError::Session, TypeError::Format { format: "json".into() }).into())
+        }
+    }
+
+    fn user() -> User {
+        fixtures::registered("cajero@example.co")
+    }
+
+    fn claims_of(session: &UserSession, policy: &SessionPolicy, now: DateTime<Utc>) -> AccessTokenClaims {
+        let token = SessionService::issue(session, &JsonCodec, policy, now).unwrap();
+        JsonCodec.decode(&token.token).unwrap()
+    }
+
+    #[test]
+    fn test_issue_binds_session_and_version_and_expires() {
+        let user = user();
+        let policy = SessionPolicy::default();
+        let now = start();
+
+        let session = SessionService::open(&user, &policy, Some("10.0.0.1".into()), Some("pos/1.0".into()), now).unwrap();
+        assert_eq!(session.created_at, now);
+        assert_eq!(session.expires_at, now + Duration::days(30));
+
+        let token = SessionService::issue(&session, &JsonCodec, &policy, now).unwrap();
+        println!("🎫 {token:?}");
+        assert!(!format!("{token:?}").contains(&token.token));
+        assert_eq!(token.expires_at, now + Duration::minutes(15));
+
+        let claims = JsonCodec.decode(&token.token).unwrap();
+        assert_eq!((claims.sub, claims.sid, claims.ver), (user.id().as_uuid(), session.session_id, session.access_token_version));
+        assert_eq!(claims.iat, now.timestamp());
+
+        // 🔹 Vale hasta su caducidad, no después
+        let sessions = [session.clone()];
+        SessionService::verify(&claims, &user, &sessions, now + Duration::minutes(14)).unwrap();
+        let err = SessionService::verify(&claims, &user, &sessions, now + Duration::minutes(15)).unwrap_err();
+        println!("⌛ {}", err.code());
+        assert_eq!(err.code(), "user.session.expired");
+
+        // 🔹 El token nunca dura más que la sesión
+        let short = SessionPolicy { session_ttl: Duration::minutes(5), ..policy.clone() };
+        let session = SessionService::open(&user, &short, None, None, now).unwrap();
+        let token = SessionService::issue(&session, &JsonCodec, &short, now).unwrap();
+        assert_eq!(token.expires_at, now + Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_verify_rejects_revoked_sessions_and_stale_versions() {
+        let mut user = user();
+        let policy = SessionPolicy::default();
+        let now = start();
+        let mut sessions = vec![
+            SessionService::open(&user, &policy, None, None, now).unwrap(),
+            SessionService::open(&user, &policy, None, None, now).unwrap(),
+        ];
+        let first = claims_of(&sessions[0], &policy, now);
+        let second = claims_of(&sessions[1], &policy, now);
+
+        // 🔹 Sesión desconocida o de otro usuario
+        let err = SessionService::verify(&first, &user, &sessions[1..], now).unwrap_err();
+        assert_eq!(err.code(), "user.session.revoked");
+        let stranger = self::user();
+        assert!(SessionService::verify(&first, &stranger, &sessions, now).is_err());
+
+        // 🔹 Invalidar tokens sube la versión: los emitidos antes dejan de valer
+        sessions[0].invalidate_tokens();
+        let err = SessionService::verify(&first, &user, &sessions, now).unwrap_err();
+        println!("🚫 {}", err.code());
+        assert_eq!(err.code(), "user.session.revoked");
+        SessionService::verify(&claims_of(&sessions[0], &policy, now), &user, &sessions, now).unwrap();
+
+        // 🔹 Cerrar una sesión no afecta a las demás
+        SessionService::logout(&mut sessions[0]);
+        assert!(SessionService::issue(&sessions[0], &JsonCodec, &policy, now).is_err());
+        SessionService::verify(&second, &user, &sessions, now).unwrap();
+
+        // 🔹 Cerrar todas cuenta sólo las que seguían activas
+        assert_eq!(SessionService::logout_everywhere(&mut sessions), 1);
+        assert!(SessionService::verify(&second, &user, &sessions, now).is_err());
+
+        // 🔹 Cuentas suspendidas: ni tokens válidos ni sesiones nuevas
+        let sessions = [SessionService::open(&user, &policy, None, None, now).unwrap()];
+        let claims = claims_of(&sessions[0], &policy, now);
+        user.activate().unwrap();
+        user.suspend().unwrap();
+        assert_eq!(SessionService::verify(&claims, &user, &sessions, now).unwrap_err().code(), "user.session.revoked");
+        let err = SessionService::open(&user, &policy, None, None, now).unwrap_err();
+        assert_eq!(err.code(), "user.status.invalid_status");
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::repositories::{OutboxRepository, UserAggregateRepository, UserRepository};
+    use crate::user::domain::services::{
+        PasswordHasher, RegistrationError, RegistrationPolicy, RegistrationProfile, RegistrationRequest,
+        UserRegistrationService,
+    };
+    use crate::user::domain::vo::*;
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::memory::{
+        InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory, InMemoryUserAggregateRepository,
+        InMemoryUserRepository,
+    };
+    use crate::user::infrastructure::persistence::orm::{
+        OutboxRepositorySeaOrm, RoleRepositorySeaOrm, UnitOfWorkFactorySeaOrm, UserAggregateRepositorySeaOrm,
+        UserRepositorySeaOrm,
+    };
+    use crate::user::infrastructure::services_impl::Argon2PasswordHasher;
+    use crate::tests::user::fixtures::{self, PlainHasher};
+
+    struct Fixture {
+        store: InMemoryStore,
+        users: Arc<InMemoryUserRepository>,
+        aggregates: Arc<InMemoryUserAggregateRepository>,
+        publisher: InMemoryDomainEventPublisher,
+        service: UserRegistrationService,
+    }
+
+    async fn fixture_with(hasher: Arc<dyn PasswordHasher>, seed_role: bool) -> Fixture {
+        let store = InMemoryStore::new();
+        let users = Arc::new(InMemoryUserRepository::new(store.clone()));
+        let roles = Arc::new(InMemoryRoleRepository::new(store.clone()));
+        let aggregates = Arc::new(InMemoryUserAggregateRepository::new(store.clone()));
+        let publisher = InMemoryDomainEventPublisher::new();
+
+        if seed_role {
+            fixtures::seed_role(roles.as_ref(), "customer").await;
+        }
+
+        let uow = InMemoryUnitOfWorkFactory::new(store.clone(), Arc::new(publisher.clone()));
+        let service = UserRegistrationService::new(Arc::new(uow), hasher);
+        Fixture { store, users, aggregates, publisher, service }
+    }
+
+    async fn fixture() -> Fixture {
+        fixture_with(Arc::new(PlainHasher), true).await
+    }
+
+    fn request(email: &str) -> RegistrationRequest {
+        RegistrationRequest {
+            email: email.into(),
+            username: None,
+            password: "s3cret-Passw0rd".into(),
+            consents: vec![ConsentType::TermsOfService, ConsentType::PrivacyPolicy],
+            ..Default::default()
+        }
+    }
+
+    #[async_std::test]
+    async fn test_register_user_builds_and_persists_the_full_aggregate() {
+        let fx = fixture_with(Arc::new(Argon2PasswordHasher::default()), true).await;
+
+        let mut req = request("Nueva.Cliente@Example.co");
+        req.username = Some("nueva_cliente".into());
+        req.consents.push(ConsentType::MarketingEmails);
+        req.consents.push(ConsentType::TermsOfService); // duplicado: se ignora
+        req.ip_address = Some("10.0.0.7".parse().unwrap());
+        req.profile = Some(RegistrationProfile { display_name: Some("Ana Pérez".into()), ..Default::default() });
+
+        let aggregate = fx.service.register_user(req).await.unwrap();
+        println!("✅ Registrado {} con {} consentimientos", aggregate.user.email().as_str(), aggregate.gdpr_consents.len());
+
+        let stored = fx
+            .aggregates
+            .load(aggregate.user.id(), LoadOptions::all())
+            .await
+            .unwrap()
+            .expect("el agregado quedó persistido");
+
+        assert_eq!(stored.user.status(), &UserStatus::Pending);
+        assert_eq!(stored.user.username().map(|u| u.as_str()), Some("nueva_cliente"));
+        assert_eq!(stored.roles.len(), 1);
+        assert_eq!(stored.gdpr_consents.len(), 3);
+        assert!(stored.gdpr_consents.iter().all(|c| c.consent_given && c.ip_address.is_some()));
+        assert_eq!(stored.profile.as_ref().and_then(|p| p.display_name.as_deref()), Some("Ana Pérez"));
+
+        // La contraseña nunca se guarda en claro
+        let password = stored.password.expect("contraseña inicial");
+        assert_ne!(password.password_hash, "s3cret-Passw0rd");
+        assert!(Argon2PasswordHasher::default().verify("s3cret-Passw0rd", &password.password_hash).unwrap());
+        assert!(!Argon2PasswordHasher::default().verify("otra", &password.password_hash).unwrap());
+
+        assert_eq!(stored.auth_methods.len(), 1);
+        assert_eq!(stored.auth_methods[0].auth_type, AuthType::Password);
+        assert!(stored.auth_methods[0].is_primary);
+
+        // Los eventos se publican y también quedan en la bandeja de salida
+        let published: Vec<&str> = fx.publisher.published().iter().map(|e| e.event_name()).collect();
+        println!("📣 Publicados: {published:?}");
+        assert_eq!(published.first(), Some(&"user_registered"));
+        assert!(aggregate.user.pending_events().is_empty());
+        let outbox: Vec<String> = fx.store.outbox().into_iter().map(|m| m.event_type).collect();
+        assert_eq!(outbox.len(), published.len());
+        assert!(outbox.iter().any(|t| t == "user_registered"));
+    }
+
+    #[async_std::test]
+    async fn test_register_user_rejects_taken_email_and_username() {
+        let fx = fixture().await;
+
+        let mut first = request("dueno@example.co");
+        first.username = Some("dueno_tienda".into());
+        fx.service.register_user(first).await.unwrap();
+
+        // El email se normaliza antes de comprobar la unicidad
+        let err = fx.service.register_user(request("DUENO@example.co")).await.unwrap_err();
+        println!("🚫 {err}");
+        assert!(matches!(err, RegistrationError::EmailTaken(_)));
+        assert_eq!(err.code(), "user.registration.email_taken");
+
+        let mut same_username = request("otro@example.co");
+        same_username.username = Some("dueno_tienda".into());
+        let err = fx.service.register_user(same_username).await.unwrap_err();
+        println!("🚫 {err}");
+        assert!(matches!(err, RegistrationError::UsernameTaken(_)));
+        assert_eq!(err.code(), "user.registration.username_taken");
+
+        assert_eq!(fx.users.list_all().await.unwrap().len(), 1);
+        assert_eq!(fx.publisher.published().iter().filter(|e| e.event_name() == "user_registered").count(), 1);
+    }
+
+    #[async_std::test]
+    async fn test_register_user_requires_mandatory_consents() {
+        let fx = fixture().await;
+
+        let mut req = request("sin.consentimiento@example.co");
+        req.consents = vec![ConsentType::TermsOfService, ConsentType::MarketingEmails];
+
+        let err = fx.service.register_user(req).await.unwrap_err();
+        println!("📝 {err}");
+        assert!(matches!(err, RegistrationError::MissingConsent(ConsentType::PrivacyPolicy)));
+        assert_eq!(err.code(), "user.registration.missing_consent");
+
+        // Una política sin consentimientos obligatorios sí lo permite
+        let relaxed = fixture().await;
+        let service = relaxed.service.with_policy(RegistrationPolicy { required_consents: vec![], ..Default::default() });
+        let mut req = request("sin.consentimiento@example.co");
+        req.consents.clear();
+        let aggregate = service.register_user(req).await.unwrap();
+        assert!(aggregate.gdpr_consents.is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_register_user_reports_invalid_fields_before_any_io() {
+        let fx = fixture().await;
+
+        let err = fx.service.register_user(request("no-es-un-email")).await.unwrap_err();
+        println!("❌ {} → {}", err.code(), err);
+        assert!(matches!(err, RegistrationError::InvalidField(_)));
+        assert!(err.code().starts_with("user.email."));
+
+        let mut bad_username = request("valido@example.co");
+        bad_username.username = Some("x".into());
+        let err = fx.service.register_user(bad_username).await.unwrap_err();
+        assert!(err.code().starts_with("user.username."), "{}", err.code());
+
+        let mut empty_password = request("valido@example.co");
+        empty_password.password.clear();
+        let err = fx.service.register_user(empty_password).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.empty");
+
+        let mut bad_profile = request("valido@example.co");
+        bad_profile.profile = Some(RegistrationProfile { display_name: Some("A".into()), ..Default::default() });
+        let err = fx.service.register_user(bad_profile).await.unwrap_err();
+        println!("❌ {}", err.code());
+        assert!(matches!(err, RegistrationError::InvalidField(_)));
+
+        assert!(fx.users.list_all().await.unwrap().is_empty());
+        assert!(fx.publisher.published().is_empty());
+        assert!(fx.store.outbox().is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_register_user_fails_when_default_role_is_missing() {
+        let fx = fixture_with(Arc::new(PlainHasher), false).await;
+
+        let err = fx.service.register_user(request("sin.rol@example.co")).await.unwrap_err();
+        println!("⚙️ {err}");
+        assert!(matches!(err, RegistrationError::DefaultRoleMissing(_)));
+        assert_eq!(err.code(), "user.registration.default_role_missing");
+        assert!(fx.users.list_all().await.unwrap().is_empty());
+    }
+
+    #[async_std::test]
+    async fn test_register_user_is_atomic_on_sqlite() {
+        let db = fixtures::sqlite().await;
+        let roles = RoleRepositorySeaOrm::new(db.clone());
+        let customer = fixtures::seed_role(&roles, "customer").await;
+
+        let users = Arc::new(UserRepositorySeaOrm::new(db.clone()));
+        let aggregates = Arc::new(UserAggregateRepositorySeaOrm::new(db.clone()));
+        let uow = UnitOfWorkFactorySeaOrm::new(db.clone(), Arc::new(InMemoryDomainEventPublisher::new()));
+        let service = UserRegistrationService::new(Arc::new(uow), Arc::new(PlainHasher));
+
+        let mut req = request("sqlite.alta@example.co");
+        req.username = Some("sqlite_alta".into());
+        let aggregate = service.register_user(req).await.unwrap();
+
+        let stored = aggregates.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().unwrap();
+        assert_eq!(stored.roles[0].role_id, customer.role_id);
+        assert_eq!(stored.gdpr_consents.len(), 2);
+        assert!(PlainHasher.verify("s3cret-Passw0rd", &stored.password.unwrap().password_hash).unwrap());
+
+        // El evento quedó en la bandeja y, ya entregado al confirmar, marcado
+        let outbox = OutboxRepositorySeaOrm::new(db);
+        let stored = outbox.list_all().await.unwrap();
+        println!("📦 Outbox SQLite: {}", stored.len());
+        assert!(stored.iter().any(|m| m.event_type == "user_registered" && m.published_at.is_some()));
+        assert!(outbox.pending(Utc::now(), 5, 10).await.unwrap().is_empty());
+
+        let err = service.register_user(request("sqlite.alta@example.co")).await.unwrap_err();
+        assert!(matches!(err, RegistrationError::EmailTaken(_)));
+        assert_eq!(users.list_all().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod test_errors;
+pub mod test_messages;
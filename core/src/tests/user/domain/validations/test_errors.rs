@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+    use crate::user::domain::vo::{Email, UserStatus};
+    use crate::user::domain::entities::{UserPassword, UserSession};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_error_accessors_and_codes() {
+        let err: UserDomainError = (CategoryError::Email, TypeError::TooShort { short: 6 }).into();
+
+        assert_eq!(err.category(), &CategoryError::Email);
+        assert_eq!(err.detail(), &TypeError::TooShort { short: 6 });
+        assert_eq!(err.code(), "user.email.too_short");
+
+        let cases = vec![
+            (UserDomainError::new(CategoryError::PhoneCountryCode, TypeError::Empty), "user.phone_country_code.empty"),
+            (UserDomainError::new(CategoryError::Username, TypeError::Format { format: "USERNAME_REGEX".into() }), "user.username.invalid_format"),
+            (UserDomainError::new(CategoryError::Status, TypeError::Transition { from: UserStatus::Deleted, to: UserStatus::Active }), "user.status.invalid_transition"),
+            (UserDomainError::new(CategoryError::Password, TypeError::Mismatch), "user.password.mismatch"),
+            (UserDomainError::new(CategoryError::Session, TypeError::Expired), "user.session.expired"),
+            (UserDomainError::new(CategoryError::ActivityLog, TypeError::Empty), "user.activity_log.empty"),
+        ];
+
+        for (err, expected) in cases {
+            println!("🔑 {} → {}", err, err.code());
+            assert_eq!(err.code(), expected);
+        }
+    }
+
+    #[test]
+    fn test_value_object_errors_expose_category() {
+        let err = Email::new("a@b").unwrap_err();
+        assert_eq!(err.category(), &CategoryError::Email);
+        assert_eq!(err.code(), "user.email.too_short");
+    }
+
+    #[test]
+    fn test_entity_errors_use_domain_categories() {
+        let err = UserPassword::new(Uuid::new_v4(), Uuid::new_v4(), "  ".into(), None, None, None, None, None, None, None).unwrap_err();
+        assert_eq!(err.code(), "user.password.empty");
+
+        let err = UserSession::new(Uuid::new_v4(), Uuid::new_v4(), None, Utc::now() - Duration::minutes(1), None, None, None).unwrap_err();
+        assert_eq!(err.code(), "user.session.expired");
+    }
+}
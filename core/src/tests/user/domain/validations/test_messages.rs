@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use crate::user::domain::validations::{CategoryError, ErrorCatalog, ErrorMessages, TypeError, UserDomainError};
+    use crate::user::domain::vo::{Locale, UserStatus};
+
+    fn locale(tag: &str) -> Locale {
+        Locale::new(tag).unwrap()
+    }
+
+    fn all_categories() -> Vec<CategoryError> {
+        vec![
+            CategoryError::Id, CategoryError::ExternalId, CategoryError::Username, CategoryError::Email,
+            CategoryError::Phone, CategoryError::PhoneNumber, CategoryError::PhoneCountryCode, CategoryError::Status,
+            CategoryError::Role, CategoryError::Locale, CategoryError::Timezone, CategoryError::Gender,
+            CategoryError::AuthType, CategoryError::SubscriptionTier, CategoryError::SubscriptionStatus,
+            CategoryError::ConsentType, CategoryError::OccurredAt, CategoryError::Password, CategoryError::Session,
+            CategoryError::Mfa, CategoryError::Subscription, CategoryError::ActivityLog, CategoryError::Consent,
+            CategoryError::DisplayName, CategoryError::AuthMethod, CategoryError::UserRole, CategoryError::Event,
+            CategoryError::Query, CategoryError::ResetToken, CategoryError::Version,
+        ]
+    }
+
+    fn all_details() -> Vec<TypeError> {
+        vec![
+            TypeError::Empty, TypeError::Missing, TypeError::NotSupported,
+            TypeError::Format { format: "bcp47".into() },
+            TypeError::TooShort { short: 6 },
+            TypeError::TooLong { long: 254 },
+            TypeError::Unchanged { value: "x".into() },
+            TypeError::Characters { value: "@".into() },
+            TypeError::StartsWithChar { start: "+".into() },
+            TypeError::EndsWithChar { end: ".".into() },
+            TypeError::AlreadyVerified,
+            TypeError::InvalidStatus { status: UserStatus::Deleted },
+            TypeError::Transition { from: UserStatus::Deleted, to: UserStatus::Active },
+            TypeError::Expired, TypeError::Mismatch,
+            TypeError::InvalidState { state: "canceled".into() },
+            TypeError::InvalidRange { start: "2024-01-02".into(), end: "2024-01-01".into() },
+            TypeError::Locked { until: "2024-01-01T00:15:00+00:00".into() },
+            TypeError::RateLimited { retry_after: "2024-01-01T00:05:00+00:00".into() },
+            TypeError::MissingCharacterClass { class: "digit".into() },
+            TypeError::PersonalData,
+            TypeError::TooWeak { strength: "weak".into() },
+            TypeError::Breached,
+            TypeError::Reused { count: 5 },
+            TypeError::Revoked,
+        ]
+    }
+
+    #[test]
+    fn test_embedded_catalogs_cover_every_error() {
+        let messages = ErrorMessages::embedded();
+        assert!(messages.locales().contains(&"es-ES"));
+        assert!(messages.locales().contains(&"en-US"));
+
+        for tag in ["es-ES", "en-US"] {
+            for category in all_categories() {
+                for detail in all_details() {
+                    let err = UserDomainError::new(category.clone(), detail);
+                    let message = err.message(&locale(tag));
+
+                    assert_ne!(message, err.code(), "{tag}: falta plantilla para {}", err.code());
+                    assert!(!message.contains('{'), "{tag}: marcador sin reemplazar en '{message}'");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_with_params_in_both_languages() {
+        let err: UserDomainError = (CategoryError::Email, TypeError::TooShort { short: 6 }).into();
+
+        assert_eq!(err.code(), "user.email.too_short");
+        assert_eq!(err.params().get("short").map(String::as_str), Some("6"));
+        assert_eq!(err.message(&locale("es-ES")), "El campo correo electrónico debe tener al menos 6 caracteres.");
+        assert_eq!(err.message(&locale("en-US")), "The email address must be at least 6 characters long.");
+
+        let err: UserDomainError = (CategoryError::Status, TypeError::Transition { from: UserStatus::Deleted, to: UserStatus::Active }).into();
+        println!("🌐 {}", err.message(&locale("es-ES")));
+        assert!(err.message(&locale("en-US")).contains("\"deleted\""));
+    }
+
+    #[test]
+    fn test_code_specific_templates_take_precedence() {
+        let err: UserDomainError = (CategoryError::Password, TypeError::Mismatch).into();
+        assert_eq!(err.message(&locale("es-ES")), "Usuario o contraseña incorrectos.");
+        assert_eq!(err.message(&locale("en-US")), "Incorrect username or password.");
+    }
+
+    #[test]
+    fn test_locale_fallbacks() {
+        let err: UserDomainError = (CategoryError::Username, TypeError::Empty).into();
+
+        // Mismo idioma, otra región → catálogo del idioma
+        assert_eq!(err.message(&locale("es-CO")), err.message(&locale("es-ES")));
+        assert_eq!(err.message(&locale("en-GB")), err.message(&locale("en-US")));
+
+        // Idioma sin catálogo → Locale::DEFAULT
+        assert_eq!(err.message(&locale("fr-FR")), err.message(&Locale::default()));
+    }
+
+    #[test]
+    fn test_runtime_catalog_extension_and_json_payload() {
+        let mut messages = ErrorMessages::embedded().clone();
+        let catalog = ErrorCatalog::from_json(r#"{
+            "locale": "pt-BR",
+            "fields": { "email": "e-mail" },
+            "details": { "empty": "O {field} não pode ficar vazio." }
+        }"#).unwrap();
+        messages.add(catalog);
+
+        let err: UserDomainError = (CategoryError::Email, TypeError::Empty).into();
+        assert_eq!(messages.render(&err, &locale("pt-BR")), "O e-mail não pode ficar vazio.");
+
+        // Sin plantilla para el detalle → se devuelve el código estable
+        let err: UserDomainError = (CategoryError::Email, TypeError::Expired).into();
+        assert_eq!(messages.render(&err, &locale("pt-BR")), "user.email.expired");
+
+        let payload = err.to_json(&locale("en-US"));
+        assert_eq!(payload["code"], "user.email.expired");
+        assert_eq!(payload["message"], "The email address has expired.");
+        assert!(payload["params"].as_object().unwrap().is_empty());
+    }
+}
@@ -13,3 +13,4 @@ pub mod test_user_status;
 pub mod test_username;
 pub mod test_user_id;
 pub mod test_occurred_at;
+pub mod test_plain_password;
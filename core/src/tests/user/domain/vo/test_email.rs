@@ -23,7 +23,7 @@ mod tests {
         ];
 
         for input in inputs {
-            let result = Email::new(&input);
+            let result = Email::new(input);
 
             match result {
                 Ok(email) => println!("✅ '{input}' → creado como: {}", email),
@@ -54,6 +54,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_phone_serialization_round_trip() {
+        let cases = vec![
+            ("57", "3201234567"),  // ✅ código de dos dígitos
+            ("1", "2025550147"),   // ✅ código de un dígito
+            ("5", "1234567"),      // ✅ código que E.164 confundiría con +51
+            ("0", "1234567"),      // ✅ código que E.164 no sabría leer
+            ("598", "94123456"),   // ✅ código de tres dígitos
+        ];
+
+        for (cc, num) in cases {
+            let phone = Phone::new(cc, num).unwrap();
+            let stored: String = phone.clone().into();
+            let restored = Phone::try_from(stored.clone()).unwrap();
+
+            assert_eq!(restored, phone, "'{stored}' no conserva el teléfono");
+            assert_eq!(serde_json::from_value::<Phone>(serde_json::to_value(&phone).unwrap()).unwrap(), phone);
+            println!("✅ '{cc}' '{num}' → guardado como '{stored}'");
+        }
+    }
+
+    #[test]
+    fn test_phone_reads_legacy_e164_values() {
+        let phone = Phone::try_from("+573201234567".to_string()).unwrap();
+
+        assert_eq!((phone.country_code(), phone.number()), (57, 3201234567));
+        println!("✅ E.164 heredado → {phone}");
+    }
+
     // #[test]
     // fn test_phone_display_and_accessors() {
     //     let phone = Phone::new("57", "3201234567").unwrap();
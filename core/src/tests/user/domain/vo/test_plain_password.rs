@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use crate::user::domain::vo::{PasswordPolicy, PasswordStrength, PlainPassword};
+
+    fn code(raw: &str, policy: &PasswordPolicy, personal: &[&str]) -> String {
+        match PlainPassword::new(raw, policy, personal) {
+            Ok(password) => format!("ok:{}", password.strength()),
+            Err(err) => err.code(),
+        }
+    }
+
+    #[test]
+    fn test_plain_password_policy() {
+        let policy = PasswordPolicy::default();
+        let personal = ["marta.cajera@example.co", "mcajera"];
+        let long = "x".repeat(129);
+
+        let cases = vec![
+            ("", "user.password.empty"),                                      // vacía
+            ("corta-1", "user.password.too_short"),                           // menos de 10
+            (long.as_str(), "user.password.too_long"),                        // límite para Argon2
+            ("Marta.Cajera-2025", "user.password.contains_personal_data"),    // parte local del email
+            ("clave-MCAJERA-77", "user.password.contains_personal_data"),     // nombre de usuario
+            ("Password123", "user.password.too_weak"),                        // palabra común con adornos
+            ("P@ssw0rd2024!", "user.password.too_weak"),                      // «l33t» deshecho
+            ("aaaaaaaaaaaa", "user.password.too_weak"),                       // repetición
+            ("qwertyuiop12", "user.password.too_weak"),                       // fila del teclado
+            ("abcdefghijklmnop", "user.password.too_weak"),                   // secuencia
+            ("s3cret-Passw0rd", "ok:very_strong"),                            // válida
+            ("caballo correcto batería grapa", "ok:very_strong"),             // frase larga
+        ];
+
+        for (raw, expected) in cases {
+            let result = code(raw, &policy, &personal);
+            println!("{} '{raw}' → {result}", if result.starts_with("ok") { "✅" } else { "❌" });
+            assert_eq!(result, expected, "'{raw}'");
+        }
+
+        // 🔹 El error de fortaleza indica el nivel alcanzado
+        let err = PlainPassword::new("aaaaaaaaaaaa", &policy, &[]).unwrap_err();
+        assert_eq!(err.params()["strength"], "very_weak");
+    }
+
+    #[test]
+    fn test_plain_password_character_classes_and_personal_data_are_configurable() {
+        let strict = PasswordPolicy {
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: true,
+            ..Default::default()
+        };
+
+        // 🔹 Se informa la primera clase que falta
+        let err = PlainPassword::new("sin mayusculas 1", &strict, &[]).unwrap_err();
+        println!("❌ {} → {:?}", err.code(), err.params());
+        assert_eq!(err.code(), "user.password.missing_character_class");
+        assert_eq!(err.params()["class"], "uppercase");
+        assert_eq!(code("Sin Digitos Aqui", &strict, &[]), "user.password.missing_character_class");
+        assert_eq!(code("Con-Todo-Junto-9", &strict, &[]), "ok:very_strong");
+
+        // 🔹 Los datos personales cortos no cuentan; la comprobación puede desactivarse
+        assert_eq!(code("ana-vende-cafe-9", &PasswordPolicy::default(), &["an@example.co"]), "ok:very_strong");
+        let lenient = PasswordPolicy { reject_personal_data: false, min_strength: PasswordStrength::VeryWeak, ..Default::default() };
+        assert!(PlainPassword::new("mcajera-2025", &lenient, &["mcajera"]).is_ok());
+    }
+
+    #[test]
+    fn test_plain_password_never_exposes_its_value() {
+        let password = PlainPassword::new("s3cret-Passw0rd", &PasswordPolicy::default(), &[]).unwrap();
+        let debug = format!("{password:?}");
+        println!("🔒 {debug}");
+
+        assert!(!debug.contains("s3cret"));
+        assert_eq!(password.as_str(), "s3cret-Passw0rd");
+        assert!(password.strength() >= PasswordStrength::Strong);
+
+        let password = PlainPassword::new("password-largo", &PasswordPolicy { min_strength: PasswordStrength::VeryWeak, ..Default::default() }, &[]);
+        assert_eq!(password.unwrap().sha1_hex().len(), 40);
+    }
+}
@@ -0,0 +1,116 @@
+//! Datos y dobles compartidos por las pruebas del contexto `User`.
+
+use chrono::{DateTime, TimeZone, Utc};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use sea_orm_migration::MigratorTrait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::user::application::{RegisterUser, UserService};
+use crate::user::domain::entities::{Role, User, UserPassword};
+use crate::user::domain::repositories::{RoleRepository, UserRepository};
+use crate::user::domain::services::PasswordHasher;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+use crate::user::domain::vo::{Email, PasswordPolicy, PlainPassword};
+use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+use crate::user::infrastructure::persistence::migrations::Migrator;
+use crate::user::infrastructure::persistence::orm::{
+    RoleRepositorySeaOrm, UnitOfWorkFactorySeaOrm, UserAggregateRepositorySeaOrm,
+};
+
+/// Hasher trivial para no pagar Argon2 en cada caso; rechaza otros formatos.
+pub struct PlainHasher;
+
+impl PasswordHasher for PlainHasher {
+    fn hash(&self, password: &str) -> Result<String, UserDomainError> {
+        Ok(format!("plain${password}"))
+    }
+
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError> {
+        if !hash.starts_with("plain$") {
+            return Err((CategoryError::Password, TypeError::NotSupported).into());
+        }
+        Ok(hash == format!("plain${password}"))
+    }
+}
+
+/// Instante fijo de partida para las pruebas con reloj.
+pub fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap()
+}
+
+/// Usuario recién registrado, sin eventos pendientes.
+pub fn registered(email: &str) -> User {
+    let mut user = User::register(Email::new(email).unwrap());
+    user.take_events();
+    user
+}
+
+/// Contraseña de `user_id` guardada con `PlainHasher`.
+pub fn stored_password(user_id: Uuid, raw: &str) -> UserPassword {
+    UserPassword::new(Uuid::new_v4(), user_id, format!("plain${raw}"), None, None, None, None, None, None, None).unwrap()
+}
+
+/// Contraseña en claro válida para la política por defecto.
+pub fn plain(raw: &str) -> PlainPassword {
+    PlainPassword::new(raw, &PasswordPolicy::default(), &[]).unwrap()
+}
+
+/// SQLite en memoria con todas las migraciones aplicadas.
+pub async fn sqlite() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:").await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    db
+}
+
+/// Como `sqlite`, pero con dos conexiones sobre la misma base, para abrir
+/// transacciones a la vez.
+pub async fn shared_sqlite() -> DatabaseConnection {
+    let mut options = ConnectOptions::new(format!("sqlite:file:{}?mode=memory&cache=shared", Uuid::new_v4()));
+    options.max_connections(2).min_connections(1);
+    let db = Database::connect(options).await.unwrap();
+    Migrator::up(&db, None).await.unwrap();
+    db
+}
+
+/// Guarda y marca los eventos como persistidos, como haría la aplicación.
+pub async fn save(repo: &dyn UserRepository, user: &mut User) {
+    repo.save(user).await.unwrap();
+    user.take_events();
+}
+
+/// Crea el rol `name` marcado como de sistema.
+pub async fn seed_role(roles: &dyn RoleRepository, name: &str) -> Role {
+    let role = Role::new(Uuid::new_v4(), name, None, None, vec![], true, None).unwrap();
+    roles.save(&role).await.unwrap();
+    role
+}
+
+/// Alta con la contraseña y los consentimientos obligatorios de siempre.
+pub fn register(email: &str) -> RegisterUser {
+    RegisterUser {
+        email: email.into(),
+        password: "s3cret-Passw0rd".into(),
+        consents: vec!["terms_of_service".into(), "privacy_policy".into()],
+        ..Default::default()
+    }
+}
+
+/// Servicio de aplicación y lo que las pruebas inspeccionan a su alrededor.
+pub struct ServiceFixture {
+    pub uow: Arc<UnitOfWorkFactorySeaOrm>,
+    pub publisher: InMemoryDomainEventPublisher,
+    pub aggregates: UserAggregateRepositorySeaOrm,
+    pub service: UserService,
+}
+
+/// `UserService` sobre SQLite en memoria con el rol `customer` ya creado.
+pub async fn service() -> ServiceFixture {
+    let db = sqlite().await;
+    seed_role(&RoleRepositorySeaOrm::new(db.clone()), "customer").await;
+
+    let publisher = InMemoryDomainEventPublisher::new();
+    let uow = Arc::new(UnitOfWorkFactorySeaOrm::new(db.clone(), Arc::new(publisher.clone())));
+    let service = UserService::new(uow.clone(), Arc::new(PlainHasher));
+    ServiceFixture { uow, publisher, aggregates: UserAggregateRepositorySeaOrm::new(db), service }
+}
@@ -0,0 +1,73 @@
+//! Batería de conformidad para cualquier adaptador de `UserRepository`,
+//! `RoleRepository`, `UserQueryRepository` y `UnitOfWorkFactory`.
+//!
+//! Cada comprobación recibe un `Subject` recién creado (almacenamiento vacío),
+//! así que el orden de ejecución no importa. Para cubrir un adaptador nuevo basta
+//! con implementar `Adapter` y llamar a `run_all`.
+
+pub mod query_checks;
+pub mod role_checks;
+pub mod uow_checks;
+pub mod user_checks;
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::user::domain::repositories::{
+    RoleRepository, UnitOfWorkFactory, UserAggregateRepository, UserQueryRepository, UserRepository,
+};
+use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+
+/// Repositorios bajo prueba, compartiendo el mismo almacenamiento.
+pub struct Subject {
+    pub users: Arc<dyn UserRepository>,
+    pub roles: Arc<dyn RoleRepository>,
+    pub aggregates: Arc<dyn UserAggregateRepository>,
+    pub queries: Arc<dyn UserQueryRepository>,
+    pub uow: Arc<dyn UnitOfWorkFactory>,
+    /// Publicador al que confirman las unidades de trabajo de `uow`.
+    pub published: InMemoryDomainEventPublisher,
+}
+
+/// Fábrica de `Subject` vacíos para un adaptador concreto.
+#[async_trait]
+pub trait Adapter: Sync {
+    fn name(&self) -> &'static str;
+
+    async fn fresh(&self) -> Subject;
+
+    /// Como `fresh`, pero admitiendo varias unidades de trabajo abiertas a la
+    /// vez (SQLite en memoria sólo tiene una conexión por defecto).
+    async fn fresh_concurrent(&self) -> Subject {
+        self.fresh().await
+    }
+}
+
+/// Ejecuta todas las comprobaciones contra `adapter`.
+pub async fn run_all(adapter: &dyn Adapter) {
+    println!("🧪 Conformidad: {}", adapter.name());
+
+    user_checks::round_trip_preserves_every_value_object(adapter.fresh().await).await;
+    user_checks::unique_fields_are_enforced(adapter.fresh().await).await;
+    user_checks::soft_deleted_users_stay_visible(adapter.fresh().await).await;
+    user_checks::hard_delete_removes_the_user(adapter.fresh().await).await;
+    user_checks::list_all_is_ordered_by_creation(adapter.fresh().await).await;
+    user_checks::concurrent_updates_have_a_single_winner(adapter.fresh().await).await;
+    user_checks::child_changes_without_events_bump_the_row_version(adapter.fresh().await).await;
+    user_checks::versions_decide_insert_or_update(adapter.fresh().await).await;
+
+    role_checks::round_trip_and_upsert(adapter.fresh().await).await;
+    role_checks::unique_name_and_ordering(adapter.fresh().await).await;
+    role_checks::delete_missing_role_fails(adapter.fresh().await).await;
+
+    query_checks::filters_combine(adapter.fresh().await).await;
+    query_checks::cursor_pagination_is_stable(adapter.fresh().await).await;
+    query_checks::invalid_queries_are_rejected(adapter.fresh().await).await;
+
+    uow_checks::commit_publishes_every_write_at_once(adapter.fresh().await).await;
+    uow_checks::rollback_and_drop_discard_every_write(adapter.fresh().await).await;
+    uow_checks::failed_write_only_undoes_itself(adapter.fresh().await).await;
+    uow_checks::concurrent_units_on_different_users_both_commit(adapter.fresh_concurrent().await).await;
+
+    println!("✅ {} cumple el contrato", adapter.name());
+}
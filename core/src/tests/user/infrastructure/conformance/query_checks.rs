@@ -0,0 +1,191 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::user::domain::aggregates::UserAggregate;
+use crate::user::domain::entities::{Role, User, UserProfile, UserRole, UserSubscription};
+use crate::user::domain::repositories::{
+    SortDirection, UserCursor, UserFilter, UserPage, UserQuery, UserSortField,
+};
+use crate::user::domain::vo::*;
+
+use super::Subject;
+
+fn user(email: &str) -> User {
+    User::register(Email::new(email).unwrap())
+}
+
+fn emails(page: &UserPage) -> Vec<String> {
+    page.items.iter().map(|item| item.email.as_str().to_string()).collect()
+}
+
+async fn search(subject: &Subject, filter: UserFilter) -> Vec<String> {
+    let page = subject.queries.search(&UserQuery::new(filter).sorted_by(UserSortField::Email, SortDirection::Asc)).await.unwrap();
+    emails(&page)
+}
+
+/// Recorre todas las páginas y devuelve los emails en orden.
+async fn walk(subject: &Subject, query: UserQuery) -> Vec<String> {
+    let mut seen = Vec::new();
+    let mut query = query;
+
+    loop {
+        let page = subject.queries.search(&query).await.unwrap();
+        seen.extend(emails(&page));
+        match page.next_cursor {
+            Some(cursor) => query = query.after(cursor),
+            None => return seen,
+        }
+    }
+}
+
+pub async fn filters_combine(subject: Subject) {
+    let admin = Role::new(Uuid::new_v4(), "admin", None, None, vec![], true, None).unwrap();
+    subject.roles.save(&admin).await.unwrap();
+
+    // Ana: activa, verificada, admin, premium y con nombre para mostrar
+    let mut ana = UserAggregate::new(user("ana.query@example.co"));
+    let uid = ana.id();
+    ana.user.assign_username(Username::new("ana_admin").unwrap()).unwrap();
+    ana.user.activate().unwrap();
+    ana.user.verify_email().unwrap();
+    ana.add_role(UserRole::new(Uuid::new_v4(), uid, admin.role_id, None, None, None, None).unwrap());
+    ana.subscriptions.push(
+        UserSubscription::new(Uuid::new_v4(), uid, SubscriptionTier::Premium, SubscriptionStatus::Active, Utc::now(), None, true, None)
+            .unwrap(),
+    );
+    ana.set_profile(
+        UserProfile::new(Uuid::new_v4(), uid, None, None, Some("Ana Margarita".into()), None, None, None, None, None, None, None)
+            .unwrap(),
+    );
+    subject.aggregates.save(&ana).await.unwrap();
+
+    // Bruno: pendiente, con un rol admin vencido y una suscripción premium cancelada
+    let mut bruno = UserAggregate::new(user("bruno.query@example.co"));
+    let uid = bruno.id();
+    let mut expired = UserRole::new(Uuid::new_v4(), uid, admin.role_id, None, None, None, None).unwrap();
+    expired.expires_at = Some(Utc::now() - Duration::days(1));
+    bruno.add_role(expired);
+    bruno.subscriptions.push(
+        UserSubscription::new(Uuid::new_v4(), uid, SubscriptionTier::Premium, SubscriptionStatus::Canceled, Utc::now(), None, false, None)
+            .unwrap(),
+    );
+    subject.aggregates.save(&bruno).await.unwrap();
+
+    // Carla: suspendida, teléfono verificado
+    let mut carla = user("carla_100@example.co");
+    carla.assign_phone(Phone::new("57", "3001234567").unwrap()).unwrap();
+    carla.verify_phone().unwrap();
+    carla.activate().unwrap();
+    carla.suspend().unwrap();
+    subject.users.save(&carla).await.unwrap();
+
+    let all = search(&subject, UserFilter::default()).await;
+    println!("🔎 {all:?}");
+    assert_eq!(all.len(), 3);
+
+    let active = UserFilter { statuses: vec![UserStatus::Active, UserStatus::Suspended], ..Default::default() };
+    assert_eq!(search(&subject, active).await, ["ana.query@example.co", "carla_100@example.co"]);
+
+    let verified = UserFilter { email_verified: Some(true), ..Default::default() };
+    assert_eq!(search(&subject, verified).await, ["ana.query@example.co"]);
+
+    let unverified_phone = UserFilter { phone_verified: Some(false), ..Default::default() };
+    assert_eq!(search(&subject, unverified_phone).await, ["ana.query@example.co", "bruno.query@example.co"]);
+
+    let admins = UserFilter { role: Some(RoleName::new("admin").unwrap()), ..Default::default() };
+    assert_eq!(search(&subject, admins).await, ["ana.query@example.co"], "El rol vencido no cuenta");
+
+    let premium = UserFilter { subscription_tier: Some(SubscriptionTier::Premium), ..Default::default() };
+    assert_eq!(search(&subject, premium).await, ["ana.query@example.co"], "La suscripción cancelada no cuenta");
+
+    // Texto: email, username o nombre para mostrar, sin mayúsculas y sin comodines
+    for (text, expected) in [
+        ("MARGARITA", vec!["ana.query@example.co"]),
+        ("ana_ad", vec!["ana.query@example.co"]),
+        ("bruno", vec!["bruno.query@example.co"]),
+        ("_1", vec!["carla_100@example.co"]),
+        ("a_q", vec![]),
+        ("%", vec![]),
+        ("  ", vec!["ana.query@example.co", "bruno.query@example.co", "carla_100@example.co"]),
+    ] {
+        let filter = UserFilter { text: Some(text.into()), ..Default::default() };
+        assert_eq!(search(&subject, filter).await, expected, "texto '{text}'");
+    }
+
+    let future = UserFilter { created_from: Some(Utc::now() + Duration::hours(1)), ..Default::default() };
+    assert!(search(&subject, future).await.is_empty());
+    let past = UserFilter { created_to: Some(Utc::now() + Duration::hours(1)), ..Default::default() };
+    assert_eq!(search(&subject, past).await.len(), 3);
+
+    let summary = subject
+        .queries
+        .search(&UserQuery::new(UserFilter { text: Some("margarita".into()), ..Default::default() }))
+        .await
+        .unwrap()
+        .items
+        .remove(0);
+    assert_eq!(summary.display_name.as_deref(), Some("Ana Margarita"));
+    assert_eq!(summary.username.as_ref().map(|u| u.as_ref().to_string()).as_deref(), Some("ana_admin"));
+    assert_eq!(summary.status, UserStatus::Active);
+}
+
+pub async fn cursor_pagination_is_stable(subject: Subject) {
+    let mut registered = Vec::new();
+    for i in 0..7 {
+        let u = user(&format!("page{i}.query@example.co"));
+        subject.users.save(&u).await.unwrap();
+        registered.push(u.email().as_str().to_string());
+    }
+
+    // Más recientes primero por defecto
+    let newest_first = walk(&subject, UserQuery::default().with_limit(3)).await;
+    let mut expected = registered.clone();
+    expected.reverse();
+    println!("📄 {newest_first:?}");
+    assert_eq!(newest_first, expected);
+
+    let by_email = walk(&subject, UserQuery::default().sorted_by(UserSortField::Email, SortDirection::Asc).with_limit(2)).await;
+    assert_eq!(by_email, registered);
+
+    // Insertar entre páginas no duplica ni desplaza lo que queda por leer
+    let query = UserQuery::default().sorted_by(UserSortField::Email, SortDirection::Asc).with_limit(3);
+    let first = subject.queries.search(&query).await.unwrap();
+    assert_eq!(emails(&first), registered[..3]);
+
+    subject.users.save(&user("page0a.query@example.co")).await.unwrap();
+    subject.users.save(&user("page9.query@example.co")).await.unwrap();
+
+    // `page0a` cae antes del cursor y no aparece; `page9` sí, al final
+    let rest = walk(&subject, query.after(first.next_cursor.unwrap())).await;
+    let mut expected_rest = registered[3..].to_vec();
+    expected_rest.push("page9.query@example.co".into());
+    assert_eq!(rest, expected_rest);
+
+    // El límite se acota a [1, MAX_LIMIT]
+    let page = subject.queries.search(&UserQuery::default().with_limit(0)).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    let page = subject.queries.search(&UserQuery::default().with_limit(10_000)).await.unwrap();
+    assert_eq!(page.items.len(), 9);
+    assert!(page.next_cursor.is_none());
+}
+
+pub async fn invalid_queries_are_rejected(subject: Subject) {
+    for i in 0..3 {
+        subject.users.save(&user(&format!("invalid{i}.query@example.co"))).await.unwrap();
+    }
+
+    let garbage = UserQuery::default().after(UserCursor::from("not-a-cursor".to_string()));
+    let err = subject.queries.search(&garbage).await.unwrap_err();
+    println!("🚫 {}", err.code());
+    assert_eq!(err.code(), "user.query.invalid_format");
+
+    // Un cursor sólo sirve para el orden con que se generó
+    let page = subject.queries.search(&UserQuery::default().with_limit(1)).await.unwrap();
+    let other_order = UserQuery::default().sorted_by(UserSortField::Email, SortDirection::Asc).after(page.next_cursor.unwrap());
+    assert_eq!(subject.queries.search(&other_order).await.unwrap_err().code(), "user.query.mismatch");
+
+    let now = Utc::now();
+    let backwards = UserFilter { created_from: Some(now), created_to: Some(now - Duration::days(1)), ..Default::default() };
+    let err = subject.queries.search(&UserQuery::new(backwards)).await.unwrap_err();
+    assert_eq!(err.code(), "user.query.invalid_range");
+}
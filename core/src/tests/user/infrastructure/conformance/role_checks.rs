@@ -0,0 +1,70 @@
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+use crate::user::domain::entities::Role;
+use crate::user::domain::repositories::RepositoryError;
+use crate::user::domain::vo::RoleName;
+
+use super::Subject;
+
+fn role(name: &str) -> Role {
+    Role::new(Uuid::new_v4(), name, None, None, vec![], false, None).unwrap()
+}
+
+pub async fn round_trip_and_upsert(subject: Subject) {
+    let repo = subject.roles.as_ref();
+
+    let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 8, 30, 0).unwrap();
+    let mut admin = Role::new(
+        Uuid::new_v4(),
+        "admin",
+        Some("Administrador".into()),
+        Some("Acceso total".into()),
+        vec!["users.read".into(), "users.write".into()],
+        true,
+        Some(created_at),
+    )
+    .unwrap();
+    repo.save(&admin).await.unwrap();
+
+    let stored = repo.get_by_id(admin.role_id).await.unwrap().unwrap();
+    assert_eq!(stored, admin);
+    assert_eq!(repo.get_by_name(&RoleName::new("admin").unwrap()).await.unwrap(), Some(admin.clone()));
+
+    // Guardar de nuevo actualiza en sitio y conserva la fecha de creación
+    admin.permissions.push("roles.write".into());
+    admin.description = None;
+    repo.save(&Role { created_at: Utc::now(), ..admin.clone() }).await.unwrap();
+
+    let updated = repo.get_by_id(admin.role_id).await.unwrap().unwrap();
+    println!("🛡️ {:?}", updated.permissions);
+    assert_eq!(updated, admin);
+    assert_eq!(repo.list_all().await.unwrap().len(), 1);
+}
+
+pub async fn unique_name_and_ordering(subject: Subject) {
+    let repo = subject.roles.as_ref();
+
+    for name in ["viewer", "admin", "member"] {
+        repo.save(&role(name)).await.unwrap();
+    }
+
+    let err = repo.save(&role("member")).await.unwrap_err();
+    println!("🚧 {err}");
+    assert!(matches!(err, RepositoryError::Conflict { entity: "role", field: "name", .. }));
+
+    let names: Vec<String> = repo.list_all().await.unwrap().iter().map(|r| r.name.as_str().to_string()).collect();
+    assert_eq!(names, ["admin", "member", "viewer"]);
+}
+
+pub async fn delete_missing_role_fails(subject: Subject) {
+    let repo = subject.roles.as_ref();
+
+    let temp = role("temporary");
+    repo.save(&temp).await.unwrap();
+    repo.delete(temp.role_id).await.unwrap();
+    assert!(repo.get_by_id(temp.role_id).await.unwrap().is_none());
+
+    let err = repo.delete(temp.role_id).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::NotFound { entity: "role", .. }));
+}
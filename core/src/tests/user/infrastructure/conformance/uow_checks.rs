@@ -0,0 +1,118 @@
+use uuid::Uuid;
+
+use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+use crate::user::domain::entities::{Role, User, UserRole};
+use crate::user::domain::repositories::RepositoryError;
+use crate::user::domain::vo::*;
+
+use super::Subject;
+
+fn aggregate(email: &str) -> UserAggregate {
+    UserAggregate::new(User::register(Email::new(email).unwrap()))
+}
+
+fn role(name: &str) -> Role {
+    Role::new(Uuid::new_v4(), name, None, None, vec![], false, None).unwrap()
+}
+
+pub async fn commit_publishes_every_write_at_once(subject: Subject) {
+    let mut uow = subject.uow.begin().await.unwrap();
+
+    let seller = role("seller");
+    uow.roles().save(&seller).await.unwrap();
+
+    let mut user = aggregate("commit.uow@example.co");
+    user.add_role(UserRole::new(Uuid::new_v4(), user.id(), seller.role_id, None, None, None, None).unwrap());
+    uow.save_aggregate(&mut user).await.unwrap();
+
+    // Dentro de la unidad se leen las propias escrituras
+    assert!(uow.users().get_by_id(user.user.id()).await.unwrap().is_some());
+    assert!(user.user.pending_events().is_empty());
+    assert!(subject.published.published().is_empty(), "Nada se publica antes del commit");
+
+    uow.commit().await.unwrap();
+
+    let stored = subject.aggregates.load(user.user.id(), LoadOptions::lazy()).await.unwrap().unwrap();
+    assert_eq!(stored.roles[0].role_id, seller.role_id);
+    assert!(subject.roles.get_by_name(&seller.name).await.unwrap().is_some());
+
+    let published: Vec<&str> = subject.published.published().iter().map(|e| e.event_name()).collect();
+    println!("📣 Tras el commit: {published:?}");
+    assert_eq!(published, ["user_registered"]);
+}
+
+pub async fn rollback_and_drop_discard_every_write(subject: Subject) {
+    let mut uow = subject.uow.begin().await.unwrap();
+    uow.roles().save(&role("discarded")).await.unwrap();
+    let mut rolled_back = aggregate("rollback.uow@example.co");
+    uow.save_aggregate(&mut rolled_back).await.unwrap();
+    uow.rollback().await.unwrap();
+
+    {
+        let mut uow = subject.uow.begin().await.unwrap();
+        let mut dropped = aggregate("dropped.uow@example.co");
+        uow.save_aggregate(&mut dropped).await.unwrap();
+        // Se suelta sin confirmar
+    }
+
+    let users = subject.users.list_all().await.unwrap();
+    let roles = subject.roles.list_all().await.unwrap();
+    println!("↩️ Tras rollback y drop: {} usuarios, {} roles", users.len(), roles.len());
+    assert!(users.is_empty());
+    assert!(roles.is_empty());
+    assert!(subject.published.published().is_empty());
+}
+
+pub async fn failed_write_only_undoes_itself(subject: Subject) {
+    let mut taken = aggregate("taken.uow@example.co");
+    subject.aggregates.save(&taken).await.unwrap();
+    taken.user.take_events();
+
+    let mut uow = subject.uow.begin().await.unwrap();
+    let mut first = aggregate("first.uow@example.co");
+    uow.save_aggregate(&mut first).await.unwrap();
+
+    let mut clash = aggregate("taken.uow@example.co");
+    let err = uow.save_aggregate(&mut clash).await.unwrap_err();
+    println!("🚧 {err}");
+    assert!(matches!(err, RepositoryError::Conflict { field: "email", .. }));
+    assert_eq!(clash.user.pending_events().len(), 1, "Los eventos del intento fallido no se encolan");
+
+    // La unidad sigue siendo utilizable y confirma lo que sí se escribió
+    uow.commit().await.unwrap();
+
+    let emails: Vec<String> =
+        subject.users.list_all().await.unwrap().iter().map(|u| u.email().as_str().to_string()).collect();
+    assert_eq!(emails.len(), 2);
+    assert!(emails.contains(&"first.uow@example.co".to_string()));
+    assert_eq!(subject.published.published().len(), 1);
+}
+
+pub async fn concurrent_units_on_different_users_both_commit(subject: Subject) {
+    let mut seeded = Vec::new();
+    for email in ["ana.parallel@example.co", "bruno.parallel@example.co"] {
+        let mut user = aggregate(email);
+        subject.aggregates.save(&user).await.unwrap();
+        user.user.take_events();
+        seeded.push(user.id());
+    }
+
+    // Las dos unidades se abren antes de que ninguna confirme
+    let first = subject.uow.begin().await.unwrap();
+    let second = subject.uow.begin().await.unwrap();
+
+    for (mut uow, id) in [first, second].into_iter().zip(&seeded) {
+        let mut user = uow.aggregates().load(&UserId::from_uuid(*id), LoadOptions::lazy()).await.unwrap().unwrap();
+        user.user.activate().unwrap();
+        uow.save_aggregate(&mut user).await.unwrap();
+        uow.commit().await.unwrap();
+    }
+
+    for id in &seeded {
+        let stored = subject.users.get_by_id(&UserId::from_uuid(*id)).await.unwrap().unwrap();
+        println!("🤝 {} v{}", stored.email(), stored.version());
+        assert_eq!(stored.status(), &UserStatus::Active);
+        assert_eq!(stored.version(), 2);
+    }
+    assert_eq!(subject.published.published().len(), 2);
+}
@@ -0,0 +1,255 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+use crate::user::domain::entities::User;
+use crate::user::domain::repositories::{RepositoryError, UserRepository};
+use crate::user::domain::vo::*;
+
+use super::Subject;
+use crate::tests::user::fixtures::{save, start, stored_password};
+
+fn user(email: &str) -> User {
+    User::register(Email::new(email).unwrap())
+}
+
+pub async fn round_trip_preserves_every_value_object(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    let mut full = user("Full.Fidelity@Example.co");
+    full.assign_username(Username::new("full_fidelity").unwrap()).unwrap();
+    full.link_external_id(ExternalId::new("EXTERNAL-CONFORMANCE-01").unwrap()).unwrap();
+    full.assign_phone(Phone::new("57", "3001234567").unwrap()).unwrap();
+    full.verify_email().unwrap();
+    full.verify_phone().unwrap();
+    full.activate().unwrap();
+    save(repo, &mut full).await;
+
+    let mut suspended = user("suspended.fidelity@example.co");
+    suspended.activate().unwrap();
+    suspended.suspend().unwrap();
+    save(repo, &mut suspended).await;
+
+    let mut deleted = user("deleted.fidelity@example.co");
+    deleted.delete().unwrap();
+    save(repo, &mut deleted).await;
+
+    // Códigos que no sobreviven a un E.164 sin separador: `+51234567`
+    // se leería como +51 y `+01234567` ni siquiera se podría leer.
+    let mut ambiguous = user("ambiguous.fidelity@example.co");
+    ambiguous.assign_phone(Phone::new("5", "1234567").unwrap()).unwrap();
+    save(repo, &mut ambiguous).await;
+
+    let mut zero_code = user("zero.code.fidelity@example.co");
+    zero_code.assign_phone(Phone::new("0", "1234567").unwrap()).unwrap();
+    save(repo, &mut zero_code).await;
+
+    let minimal = {
+        let mut minimal = user("minimal.fidelity@example.co");
+        save(repo, &mut minimal).await;
+        minimal
+    };
+
+    for expected in [&full, &suspended, &deleted, &ambiguous, &zero_code, &minimal] {
+        let stored = repo.get_by_id(expected.id()).await.unwrap().unwrap();
+        println!("🔁 {} → {}", expected.email().as_str(), stored.status().as_str());
+        assert_eq!(stored.snapshot(), expected.snapshot());
+        assert_eq!(stored.persisted_version(), expected.version());
+        assert!(stored.pending_events().is_empty());
+    }
+
+    let by_email = repo.get_by_email(full.email()).await.unwrap().unwrap();
+    assert_eq!(by_email.snapshot(), full.snapshot());
+    let by_username = repo.get_by_username(full.username().unwrap()).await.unwrap().unwrap();
+    assert_eq!(by_username.snapshot(), full.snapshot());
+
+    assert!(repo.get_by_id(&UserId::from_uuid(Uuid::new_v4())).await.unwrap().is_none());
+    assert!(repo.get_by_email(&Email::new("missing.fidelity@example.co").unwrap()).await.unwrap().is_none());
+}
+
+pub async fn unique_fields_are_enforced(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    let mut owner = user("owner.unique@example.co");
+    owner.assign_username(Username::new("owner_unique").unwrap()).unwrap();
+    owner.link_external_id(ExternalId::new("EXTERNAL-CONFORMANCE-02").unwrap()).unwrap();
+    save(repo, &mut owner).await;
+
+    let err = repo.save(&user("owner.unique@example.co")).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::Conflict { entity: "user", field: "email", .. }), "{err}");
+
+    let mut same_username = user("other.unique@example.co");
+    same_username.assign_username(Username::new("owner_unique").unwrap()).unwrap();
+    let err = repo.save(&same_username).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::Conflict { field: "username", .. }), "{err}");
+
+    let mut same_external = user("other.unique@example.co");
+    same_external.link_external_id(ExternalId::new("EXTERNAL-CONFORMANCE-02").unwrap()).unwrap();
+    let err = repo.save(&same_external).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::Conflict { field: "external_id", .. }), "{err}");
+
+    // Cambiar el email de un usuario existente a uno ocupado también choca
+    let mut other = user("other.unique@example.co");
+    save(repo, &mut other).await;
+    other.update_email(Email::new("owner.unique@example.co").unwrap()).unwrap();
+    let err = repo.save(&other).await.unwrap_err();
+    println!("🚧 {err}");
+    assert!(matches!(err, RepositoryError::Conflict { field: "email", .. }));
+
+    assert_eq!(repo.list_all().await.unwrap().len(), 2, "Ningún intento fallido dejó filas");
+}
+
+pub async fn soft_deleted_users_stay_visible(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    let mut gone = user("gone.soft@example.co");
+    gone.assign_username(Username::new("gone_soft").unwrap()).unwrap();
+    save(repo, &mut gone).await;
+
+    repo.soft_delete(gone.id()).await.unwrap();
+
+    let stored = repo.get_by_id(gone.id()).await.unwrap().unwrap();
+    println!("🗑️ {} {:?}", stored.status().as_str(), stored.deleted_at());
+    assert_eq!(stored.status(), &UserStatus::Deleted);
+    assert!(stored.deleted_at().is_some());
+    assert!(repo.exists_by_email(gone.email()).await.unwrap());
+    assert!(repo.exists_by_username(gone.username().unwrap()).await.unwrap());
+    assert_eq!(repo.list_all().await.unwrap().len(), 1);
+
+    // El email sigue reservado y un segundo borrado es una transición inválida
+    let err = repo.save(&user("gone.soft@example.co")).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::Conflict { .. }));
+    assert!(matches!(repo.soft_delete(gone.id()).await.unwrap_err(), RepositoryError::Domain(_)));
+
+    let err = repo.soft_delete(&UserId::from_uuid(Uuid::new_v4())).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::NotFound { entity: "user", .. }));
+}
+
+pub async fn hard_delete_removes_the_user(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    let mut forgotten = user("forgotten.hard@example.co");
+    save(repo, &mut forgotten).await;
+
+    repo.delete(forgotten.id()).await.unwrap();
+    assert!(repo.get_by_id(forgotten.id()).await.unwrap().is_none());
+    assert!(!repo.exists_by_email(forgotten.email()).await.unwrap());
+
+    let err = repo.delete(forgotten.id()).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::NotFound { entity: "user", .. }));
+
+    // Tras el borrado físico el email vuelve a estar libre
+    let mut again = user("forgotten.hard@example.co");
+    save(repo, &mut again).await;
+}
+
+pub async fn list_all_is_ordered_by_creation(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    let mut first = user("first.order@example.co");
+    let mut second = user("second.order@example.co");
+    let mut third = user("third.order@example.co");
+
+    // Se guardan desordenados; el listado sigue la fecha de creación
+    save(repo, &mut third).await;
+    save(repo, &mut first).await;
+    save(repo, &mut second).await;
+
+    let listed: Vec<String> = repo.list_all().await.unwrap().iter().map(|u| u.email().as_str().to_string()).collect();
+    println!("📋 {listed:?}");
+    assert_eq!(listed, ["first.order@example.co", "second.order@example.co", "third.order@example.co"]);
+}
+
+pub async fn concurrent_updates_have_a_single_winner(subject: Subject) {
+    let repo: Arc<dyn UserRepository> = subject.users;
+
+    let mut contended = user("contended.race@example.co");
+    save(repo.as_ref(), &mut contended).await;
+
+    // Todos leen la misma versión antes de que nadie escriba
+    let mut copies = Vec::new();
+    for _ in 0..5 {
+        let mut copy = repo.get_by_id(contended.id()).await.unwrap().unwrap();
+        copy.activate().unwrap();
+        copies.push(copy);
+    }
+
+    let writers: Vec<_> = copies
+        .into_iter()
+        .map(|copy| {
+            let repo = repo.clone();
+            async_std::task::spawn(async move { repo.save(&copy).await })
+        })
+        .collect();
+
+    let mut won = 0;
+    for writer in writers {
+        match writer.await {
+            Ok(()) => won += 1,
+            Err(RepositoryError::Concurrency { expected: 1, actual: 2, .. }) => {}
+            Err(other) => panic!("error inesperado: {other}"),
+        }
+    }
+    println!("🏁 ganadores: {won}");
+    assert_eq!(won, 1);
+
+    let stored = repo.get_by_id(contended.id()).await.unwrap().unwrap();
+    assert_eq!(stored.version(), 2);
+    assert_eq!(stored.status(), &UserStatus::Active);
+}
+
+pub async fn child_changes_without_events_bump_the_row_version(subject: Subject) {
+    let mut seeded = UserAggregate::new(user("counted.race@example.co"));
+    seeded.password = Some(stored_password(seeded.id(), "clave-caja"));
+    assert_eq!(subject.aggregates.save(&seeded).await.unwrap().row_version, 1);
+    let id = seeded.user.id();
+
+    // 🔹 Dos intentos fallidos leen el mismo contador
+    let mut copies = Vec::new();
+    for _ in 0..2 {
+        let mut copy = subject.aggregates.load(id, LoadOptions::lazy()).await.unwrap().unwrap();
+        copy.password.as_mut().unwrap().register_failed_attempt_at(start());
+        copies.push(copy);
+    }
+
+    assert_eq!(subject.aggregates.save(&copies[0]).await.unwrap().row_version, 2);
+    let err = subject.aggregates.save(&copies[1]).await.unwrap_err();
+    println!("🔢 {err}");
+    assert!(matches!(err, RepositoryError::Concurrency { expected: 1, actual: 2, .. }));
+
+    // 🔹 La versión del usuario sigue siendo el número de eventos
+    let mut stored = subject.aggregates.load(id, LoadOptions::lazy()).await.unwrap().unwrap();
+    assert_eq!((stored.user.version(), stored.row_version()), (1, 2));
+    assert_eq!(stored.password.as_ref().unwrap().failed_attempts, 1);
+
+    // 🔹 Una copia obsoleta con eventos tampoco pisa el contador
+    let mut stale = copies.remove(1);
+    stale.user.activate().unwrap();
+    assert!(matches!(subject.aggregates.save(&stale).await.unwrap_err(), RepositoryError::Concurrency { .. }));
+
+    stored.user.activate().unwrap();
+    assert_eq!(subject.aggregates.save(&stored).await.unwrap().row_version, 3);
+    assert_eq!(subject.users.get_by_id(id).await.unwrap().unwrap().version(), 2);
+
+    // 🔹 Guardar sin cambios no estrena versión de fila
+    let unchanged = subject.aggregates.load(id, LoadOptions::lazy()).await.unwrap().unwrap();
+    let changes = subject.aggregates.save(&unchanged).await.unwrap();
+    assert!(changes.is_empty());
+    assert_eq!(changes.row_version, 3);
+}
+
+pub async fn versions_decide_insert_or_update(subject: Subject) {
+    let repo = subject.users.as_ref();
+
+    // 🔹 Repetir un alta ya guardada es un conflicto de versión
+    let fresh = user("fresh.version@example.co");
+    repo.save(&fresh).await.unwrap();
+    let err = repo.save(&fresh).await.unwrap_err();
+    assert!(matches!(err, RepositoryError::Concurrency { expected: 0, actual: 1, .. }));
+
+    // 🔹 Actualizar un usuario borrado físicamente no lo resucita
+    let mut stale = repo.get_by_id(fresh.id()).await.unwrap().unwrap();
+    repo.delete(fresh.id()).await.unwrap();
+    stale.activate().unwrap();
+    assert_eq!(repo.save(&stale).await.unwrap_err().code(), "user.repository.not_found");
+}
@@ -0,0 +1,13 @@
+pub mod tests_outbox;
+pub mod tests_event_bus;
+pub mod tests_event_store;
+pub mod tests_role_repository;
+pub mod tests_user_repository;
+pub mod tests_migrations;
+pub mod tests_user_aggregate_repository;
+pub mod tests_memory_repositories;
+pub mod conformance;
+pub mod tests_conformance;
+pub mod tests_password_hashers;
+pub mod tests_breached_passwords;
+pub mod tests_access_tokens;
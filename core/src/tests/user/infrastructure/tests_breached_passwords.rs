@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::user::application::*;
+    use crate::user::domain::services::BreachedPasswordSource;
+    use crate::user::domain::validations::UserDomainError;
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::memory::{InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory};
+    use crate::user::infrastructure::services_impl::{BcryptPasswordHasher, PrefixFileBreachedPasswords};
+    use crate::tests::user::fixtures::{self, plain, seed_role};
+
+    /// Fuente que anota los prefijos consultados.
+    struct RecordingSource {
+        inner: PrefixFileBreachedPasswords,
+        prefixes: Mutex<Vec<String>>,
+    }
+
+    impl BreachedPasswordSource for RecordingSource {
+        fn range(&self, prefix: &str) -> Result<Vec<(String, u32)>, UserDomainError> {
+            self.prefixes.lock().unwrap().push(prefix.to_string());
+            self.inner.range(prefix)
+        }
+    }
+
+    #[test]
+    fn test_prefix_file_lookup_only_shares_the_prefix() {
+        let leaked = plain("Summer2024!");
+        let hash = leaked.sha1_hex();
+        let contents = format!("# volcado de prueba\n\n{}:42\n{}\n", hash.to_lowercase(), "0".repeat(40));
+        let source = RecordingSource {
+            inner: PrefixFileBreachedPasswords::parse(&contents).unwrap(),
+            prefixes: Mutex::new(vec![]),
+        };
+        assert_eq!(source.inner.len(), 2);
+
+        // 🔹 Coincidencia con su número de apariciones; sólo viajan 5 caracteres
+        assert_eq!(source.occurrences(&leaked).unwrap(), 42);
+        assert_eq!(source.prefixes.lock().unwrap().as_slice(), [&hash[..5]]);
+        let err = source.check(&leaked).unwrap_err();
+        println!("🚨 {}", err.code());
+        assert_eq!(err.code(), "user.password.breached");
+
+        assert!(source.check(&plain("caballo correcto batería grapa")).is_ok());
+
+        // 🔹 Líneas mal formadas
+        for bad in ["XYZ", "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8:muchas"] {
+            let err = PrefixFileBreachedPasswords::parse(bad).unwrap_err();
+            assert_eq!(err.code(), "user.password.invalid_format");
+        }
+    }
+
+    #[async_std::test]
+    async fn test_bundled_list_blocks_new_passwords_but_not_logins() {
+        let bundled = PrefixFileBreachedPasswords::bundled();
+        println!("📚 {} hashes incluidos", bundled.len());
+        assert!(!bundled.is_empty());
+        assert!(bundled.check(&plain("Summer2024!")).is_err());
+        assert!(bundled.check(&plain("s3cret-Passw0rd")).is_ok());
+
+        let store = InMemoryStore::new();
+        let roles = InMemoryRoleRepository::new(store.clone());
+        seed_role(&roles, "customer").await;
+        let uow = Arc::new(InMemoryUnitOfWorkFactory::new(store, Arc::new(InMemoryDomainEventPublisher::new())));
+        let hasher = Arc::new(BcryptPasswordHasher::new(4).unwrap());
+        let register = |email: &str, password: &str| RegisterUser { password: password.into(), ..fixtures::register(email) };
+
+        // 🔹 Un usuario creado antes de activar la comprobación puede seguir entrando
+        let legacy = UserService::new(uow.clone(), hasher.clone());
+        let id = legacy.register_user(register("veterano@example.co", "Summer2024!")).await.unwrap();
+
+        let service = UserService::new(uow, hasher).with_breached_passwords(Arc::new(bundled));
+        let err = service.register_user(register("nuevo@example.co", "Summer2024!")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.breached");
+
+        let login = AuthenticateUser { email: "veterano@example.co".into(), password: "Summer2024!".into(), ..Default::default() };
+        assert_eq!(service.authenticate(login).await.unwrap(), id);
+
+        // 🔹 Pero no puede volver a elegirla
+        let change = |new_password: &str| ChangePassword {
+            user_id: id,
+            current_password: "Summer2024!".into(),
+            new_password: new_password.into(),
+        };
+        let err = service.change_password(change("Welcome123")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.too_weak");
+        let err = service.change_password(change("Spring2025!")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.breached");
+        let err = service.change_password(change("veterano-en-caja")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.contains_personal_data");
+        assert_eq!(service.change_password(change("Nueva-Clave-1")).await.unwrap(), id);
+    }
+}
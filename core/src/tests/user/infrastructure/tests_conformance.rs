@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use sea_orm::DatabaseConnection;
+    use std::sync::Arc;
+
+    use crate::tests::user::infrastructure::conformance::{run_all, Adapter, Subject};
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::memory::{
+        InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory, InMemoryUserAggregateRepository,
+        InMemoryUserQueryRepository, InMemoryUserRepository,
+    };
+    use crate::user::infrastructure::persistence::orm::{
+        RoleRepositorySeaOrm, UnitOfWorkFactorySeaOrm, UserAggregateRepositorySeaOrm, UserQueryRepositorySeaOrm,
+        UserRepositorySeaOrm,
+    };
+    use crate::tests::user::fixtures::{shared_sqlite, sqlite};
+
+    struct SqliteAdapter;
+
+    impl SqliteAdapter {
+        fn subject(db: DatabaseConnection) -> Subject {
+            let published = InMemoryDomainEventPublisher::new();
+
+            Subject {
+                users: Arc::new(UserRepositorySeaOrm::new(db.clone())),
+                roles: Arc::new(RoleRepositorySeaOrm::new(db.clone())),
+                aggregates: Arc::new(UserAggregateRepositorySeaOrm::new(db.clone())),
+                queries: Arc::new(UserQueryRepositorySeaOrm::new(db.clone())),
+                uow: Arc::new(UnitOfWorkFactorySeaOrm::new(db, Arc::new(published.clone()))),
+                published,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Adapter for SqliteAdapter {
+        fn name(&self) -> &'static str {
+            "SeaORM + SQLite"
+        }
+
+        async fn fresh(&self) -> Subject {
+            Self::subject(sqlite().await)
+        }
+
+        async fn fresh_concurrent(&self) -> Subject {
+            Self::subject(shared_sqlite().await)
+        }
+    }
+
+    struct MemoryAdapter;
+
+    #[async_trait]
+    impl Adapter for MemoryAdapter {
+        fn name(&self) -> &'static str {
+            "memoria"
+        }
+
+        async fn fresh(&self) -> Subject {
+            let store = InMemoryStore::new();
+            let published = InMemoryDomainEventPublisher::new();
+
+            Subject {
+                users: Arc::new(InMemoryUserRepository::new(store.clone())),
+                roles: Arc::new(InMemoryRoleRepository::new(store.clone())),
+                aggregates: Arc::new(InMemoryUserAggregateRepository::new(store.clone())),
+                queries: Arc::new(InMemoryUserQueryRepository::new(store.clone())),
+                uow: Arc::new(InMemoryUnitOfWorkFactory::new(store, Arc::new(published.clone()))),
+                published,
+            }
+        }
+    }
+
+    #[async_std::test]
+    async fn sqlite_adapter_conforms() {
+        run_all(&SqliteAdapter).await;
+    }
+
+    #[async_std::test]
+    async fn memory_adapter_conforms() {
+        run_all(&MemoryAdapter).await;
+    }
+}
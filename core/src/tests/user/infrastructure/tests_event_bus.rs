@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use crate::user::domain::entities::User;
+    use crate::user::domain::events::{UserDomainEvent, UserRegistered, UserSuspended};
+    use crate::user::domain::vo::Email;
+    use crate::user::infrastructure::messaging::{
+        DispatchMode, DomainEventPublisher, EventHandler, InProcessEventBus,
+    };
+
+    /// Simula el envío del correo de bienvenida.
+    #[derive(Clone, Default)]
+    struct SendWelcomeEmail {
+        sent_to: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler<UserRegistered> for SendWelcomeEmail {
+        async fn handle(&self, event: &UserRegistered) -> anyhow::Result<()> {
+            async_std::task::sleep(Duration::from_millis(10)).await;
+            self.sent_to.lock().unwrap().push(event.email().as_str().to_string());
+            Ok(())
+        }
+
+        fn name(&self) -> &str {
+            "send_welcome_email"
+        }
+    }
+
+    /// Simula la revocación de sesiones; siempre falla.
+    struct RevokeSessions;
+
+    #[async_trait]
+    impl EventHandler<UserSuspended> for RevokeSessions {
+        async fn handle(&self, _event: &UserSuspended) -> anyhow::Result<()> {
+            anyhow::bail!("session store unavailable")
+        }
+
+        fn name(&self) -> &str {
+            "revoke_sessions"
+        }
+    }
+
+    fn suspended_user_events() -> Vec<UserDomainEvent> {
+        let mut user = User::register(Email::new("bus.user@example.co").unwrap());
+        user.activate().unwrap();
+        user.suspend().unwrap();
+        user.take_events()
+    }
+
+    fn audit_log(bus: &InProcessEventBus) -> Arc<Mutex<Vec<&'static str>>> {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sink = log.clone();
+        bus.subscribe::<UserDomainEvent, _>(move |event: &UserDomainEvent| {
+            sink.lock().unwrap().push(event.event_name());
+            Ok(())
+        });
+        log
+    }
+
+    #[async_std::test]
+    async fn typed_handlers_only_receive_their_event() {
+        let bus = InProcessEventBus::new(DispatchMode::Sync);
+        let welcome = SendWelcomeEmail::default();
+        bus.subscribe::<UserRegistered, _>(welcome.clone());
+        let audit = audit_log(&bus);
+        assert_eq!(bus.handler_count(), 2);
+
+        let events = suspended_user_events();
+        for event in &events {
+            bus.publish(event).await.unwrap();
+        }
+
+        // En modo síncrono todo terminó al volver de `publish`
+        assert_eq!(*welcome.sent_to.lock().unwrap(), vec!["bus.user@example.co".to_string()]);
+        assert_eq!(*audit.lock().unwrap(), vec!["user_registered", "user_activated", "user_suspended"]);
+        assert!(bus.dead_letters().is_empty());
+    }
+
+    #[async_std::test]
+    async fn failing_handler_is_isolated_and_dead_lettered() {
+        let bus = InProcessEventBus::new(DispatchMode::Sync);
+        bus.subscribe::<UserSuspended, _>(RevokeSessions);
+        let audit = audit_log(&bus);
+
+        // 🔹 Sólo falla la publicación del evento cuyo manejador falló
+        let events = suspended_user_events();
+        let mut results = Vec::new();
+        for event in &events {
+            results.push(bus.publish(event).await.map_err(|err| err.to_string()));
+        }
+        println!("📣 {results:?}");
+        assert!(results[0].is_ok() && results[1].is_ok());
+        assert_eq!(results[2], Err("user_suspended failed for handler(s): revoke_sessions".to_string()));
+
+        // El manejador de auditoría recibió todos los eventos pese al fallo
+        assert_eq!(audit.lock().unwrap().len(), events.len());
+
+        let dead = bus.take_dead_letters();
+        println!("💀 {:?}", dead.iter().map(|d| (&d.handler, &d.error)).collect::<Vec<_>>());
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].handler, "revoke_sessions");
+        assert_eq!(dead[0].error, "session store unavailable");
+        assert_eq!(dead[0].event.event_id(), events[2].event_id());
+        assert!(bus.dead_letters().is_empty(), "take_dead_letters vacía la lista");
+    }
+
+    /// Simula un manejador con un error de programación.
+    struct Explodes;
+
+    #[async_trait]
+    impl EventHandler<UserRegistered> for Explodes {
+        async fn handle(&self, _event: &UserRegistered) -> anyhow::Result<()> {
+            panic!("index out of bounds")
+        }
+
+        fn name(&self) -> &str {
+            "explodes"
+        }
+    }
+
+    #[async_std::test]
+    async fn panicking_handler_is_caught_and_dead_lettered() {
+        for mode in [DispatchMode::Sync, DispatchMode::Async] {
+            let bus = InProcessEventBus::new(mode);
+            bus.subscribe::<UserRegistered, _>(Explodes);
+            let audit = audit_log(&bus);
+
+            let events = suspended_user_events();
+            let registered = bus.publish(&events[0]).await;
+            bus.wait_idle().await;
+            assert_eq!(registered.is_err(), mode == DispatchMode::Sync);
+            assert_eq!(audit.lock().unwrap().len(), 1, "Los demás manejadores siguen recibiendo el evento");
+
+            // 🔹 El bus sigue utilizable después del pánico
+            bus.publish(&events[1]).await.unwrap();
+            bus.wait_idle().await;
+
+            let dead = bus.take_dead_letters();
+            println!("💥 {mode:?} → {:?}", dead.iter().map(|d| &d.error).collect::<Vec<_>>());
+            assert_eq!(dead.len(), 1);
+            assert_eq!(dead[0].handler, "explodes");
+            assert_eq!(dead[0].error, "handler panicked: index out of bounds");
+        }
+    }
+
+    #[async_std::test]
+    async fn async_mode_returns_before_handlers_finish() {
+        let bus = InProcessEventBus::new(DispatchMode::Async);
+        let welcome = SendWelcomeEmail::default();
+        bus.subscribe::<UserRegistered, _>(welcome.clone());
+        bus.subscribe::<UserSuspended, _>(RevokeSessions);
+
+        for event in &suspended_user_events() {
+            bus.publish(event).await.unwrap();
+        }
+        assert!(welcome.sent_to.lock().unwrap().is_empty(), "El manejador sigue en curso");
+
+        bus.wait_idle().await;
+        assert_eq!(welcome.sent_to.lock().unwrap().len(), 1);
+        assert_eq!(bus.dead_letters().len(), 1);
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use crate::user::domain::entities::User;
+    use crate::user::domain::events::{UserDomainEvent, UserEventEnvelope};
+    use crate::user::domain::repositories::{user_stream_id, EventStore, RepositoryError};
+    use crate::user::domain::vo::Email;
+    use crate::user::infrastructure::persistence::memory::InMemoryEventStore;
+    use crate::user::infrastructure::persistence::orm::EventStoreSeaOrm;
+    use crate::tests::user::fixtures::sqlite;
+
+    async fn sqlite_store() -> EventStoreSeaOrm {
+        EventStoreSeaOrm::new(sqlite().await)
+    }
+
+    fn envelopes(user: &mut User) -> Vec<UserEventEnvelope> {
+        user.take_events().iter().map(UserDomainEvent::to_envelope).collect()
+    }
+
+    /// Guarda los cambios pendientes del usuario en su flujo.
+    async fn save(store: &dyn EventStore, user: &mut User) -> Result<u64, RepositoryError> {
+        let expected = user.persisted_version();
+        let events = envelopes(user);
+        store.append(&user_stream_id(user.id()), expected, &events).await
+    }
+
+    async fn load(store: &dyn EventStore, user: &User) -> User {
+        let stream = store.read_stream(&user_stream_id(user.id()), 1).await.unwrap();
+        let events = stream.into_iter().map(|e| UserDomainEvent::from_envelope(e).unwrap());
+        User::from_events(events).unwrap()
+    }
+
+    async fn append_and_read_round_trip(store: &dyn EventStore) {
+        let mut user = User::register(Email::new("store.user@example.co").unwrap());
+        user.activate().unwrap();
+        assert_eq!(save(store, &mut user).await.unwrap(), 2);
+
+        user.suspend().unwrap();
+        assert_eq!(save(store, &mut user).await.unwrap(), 3);
+
+        let stream_id = user_stream_id(user.id());
+        assert_eq!(store.stream_version(&stream_id).await.unwrap(), 3);
+        assert_eq!(store.stream_version("user-missing").await.unwrap(), 0);
+
+        let stream = store.read_stream(&stream_id, 1).await.unwrap();
+        println!("📚 {:?}", stream.iter().map(|e| (e.aggregate_version, e.event_type.as_str())).collect::<Vec<_>>());
+        assert_eq!(stream.iter().map(|e| e.aggregate_version).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let tail = store.read_stream(&stream_id, 3).await.unwrap();
+        assert_eq!(tail.len(), 1);
+        assert_eq!(tail[0].event_type, "user_suspended");
+
+        let loaded = load(store, &user).await;
+        assert_eq!(loaded.snapshot(), user.snapshot());
+        assert_eq!(loaded.version(), 3);
+    }
+
+    async fn conflicting_writes_fail(store: &dyn EventStore) {
+        let mut user = User::register(Email::new("race.user@example.co").unwrap());
+        save(store, &mut user).await.unwrap();
+
+        // Dos copias del mismo usuario cargadas en la versión 1
+        let mut first = load(store, &user).await;
+        let mut second = load(store, &user).await;
+
+        first.activate().unwrap();
+        save(store, &mut first).await.unwrap();
+
+        second.verify_email().unwrap();
+        let err = save(store, &mut second).await.unwrap_err();
+        println!("⚔️ {err}");
+        assert_eq!(err.code(), "user.repository.concurrency");
+        assert!(matches!(err, RepositoryError::Concurrency { expected: 1, actual: 2, .. }));
+
+        // El flujo no cambió con la escritura rechazada
+        assert_eq!(store.stream_version(&user_stream_id(user.id())).await.unwrap(), 2);
+    }
+
+    async fn duplicate_event_ids_are_not_concurrency(store: &dyn EventStore) {
+        let mut user = User::register(Email::new("replay.user@example.co").unwrap());
+        let registered = envelopes(&mut user);
+        store.append(&user_stream_id(user.id()), 0, &registered).await.unwrap();
+
+        // 🔹 El mismo evento en otro flujo, en una versión libre
+        let err = store.append("user-replayed", 0, &registered).await.unwrap_err();
+        println!("🔁 {err}");
+        assert!(matches!(&err, RepositoryError::Conflict { entity: "event", field: "event_id", value } if *value == registered[0].event_id.to_string()));
+
+        // 🔹 Repetido dentro del mismo lote
+        user.activate().unwrap();
+        let activated = envelopes(&mut user);
+        let batch = [activated[0].clone(), activated[0].clone()];
+        let err = store.append(&user_stream_id(user.id()), 1, &batch).await.unwrap_err();
+        assert_eq!(err.code(), "user.repository.conflict");
+
+        // 🔹 Nada quedó escrito y el almacén sigue operativo
+        assert_eq!(store.stream_version("user-replayed").await.unwrap(), 0);
+        assert_eq!(store.append(&user_stream_id(user.id()), 1, &activated).await.unwrap(), 2);
+    }
+
+    #[async_std::test]
+    async fn memory_duplicate_event_ids() {
+        duplicate_event_ids_are_not_concurrency(&InMemoryEventStore::new()).await;
+    }
+
+    #[async_std::test]
+    async fn sqlite_duplicate_event_ids() {
+        duplicate_event_ids_are_not_concurrency(&sqlite_store().await).await;
+    }
+
+    #[async_std::test]
+    async fn memory_round_trip() {
+        append_and_read_round_trip(&InMemoryEventStore::new()).await;
+    }
+
+    #[async_std::test]
+    async fn memory_concurrency() {
+        conflicting_writes_fail(&InMemoryEventStore::new()).await;
+    }
+
+    #[async_std::test]
+    async fn sqlite_round_trip() {
+        append_and_read_round_trip(&sqlite_store().await).await;
+    }
+
+    #[async_std::test]
+    async fn sqlite_concurrency() {
+        conflicting_writes_fail(&sqlite_store().await).await;
+    }
+}
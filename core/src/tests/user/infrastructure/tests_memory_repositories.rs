@@ -0,0 +1,256 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+    use crate::user::domain::entities::{Role, User, UserRole, UserSession};
+    use crate::user::domain::repositories::{
+        RepositoryError, RoleRepository, UnitOfWorkFactory, UserAggregateRepository, UserRepository,
+    };
+    use crate::user::domain::vo::*;
+    use crate::user::infrastructure::persistence::memory::{
+        InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory, InMemoryUserAggregateRepository,
+        InMemoryUserRepository,
+    };
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::orm::{
+        OutboxRepositorySeaOrm, RoleRepositorySeaOrm, UserAggregateRepositorySeaOrm, UserRepositorySeaOrm,
+    };
+    use crate::tests::user::fixtures;
+
+    /// Los tres repositorios sobre el mismo almacenamiento.
+    struct Backend {
+        users: Box<dyn UserRepository>,
+        roles: Box<dyn RoleRepository>,
+        aggregates: Box<dyn UserAggregateRepository>,
+    }
+
+    async fn sqlite() -> (Backend, OutboxRepositorySeaOrm) {
+        let db = fixtures::sqlite().await;
+
+        let backend = Backend {
+            users: Box::new(UserRepositorySeaOrm::new(db.clone())),
+            roles: Box::new(RoleRepositorySeaOrm::new(db.clone())),
+            aggregates: Box::new(UserAggregateRepositorySeaOrm::new(db.clone())),
+        };
+        (backend, OutboxRepositorySeaOrm::new(db))
+    }
+
+    fn memory(store: &InMemoryStore) -> Backend {
+        Backend {
+            users: Box::new(InMemoryUserRepository::new(store.clone())),
+            roles: Box::new(InMemoryRoleRepository::new(store.clone())),
+            aggregates: Box::new(InMemoryUserAggregateRepository::new(store.clone())),
+        }
+    }
+
+    fn outcome<T>(result: Result<T, RepositoryError>) -> String {
+        match result {
+            Ok(_) => "ok".into(),
+            Err(err) => err.code(),
+        }
+    }
+
+    fn user(email: &str) -> User {
+        User::register(Email::new(email).unwrap())
+    }
+
+    /// Ejecuta el mismo guion y devuelve una traza comparable entre adaptadores.
+    async fn scenario(backend: &Backend) -> Vec<String> {
+        let mut trace = Vec::new();
+        let users = backend.users.as_ref();
+
+        // Unicidad: email, username y external id
+        let mut ana = user("ana.parity@example.co");
+        ana.assign_username(Username::new("ana_parity").unwrap()).unwrap();
+        ana.link_external_id(ExternalId::new("EXTERNAL-PARITY-0000001").unwrap()).unwrap();
+        trace.push(outcome(users.save(&ana).await));
+        ana.take_events();
+
+        trace.push(outcome(users.save(&user("ana.parity@example.co")).await));
+
+        let mut same_username = user("otra.parity@example.co");
+        same_username.assign_username(Username::new("ana_parity").unwrap()).unwrap();
+        trace.push(outcome(users.save(&same_username).await));
+
+        let mut same_external = user("otra.parity@example.co");
+        same_external.link_external_id(ExternalId::new("EXTERNAL-PARITY-0000001").unwrap()).unwrap();
+        trace.push(outcome(users.save(&same_external).await));
+
+        // Concurrencia optimista
+        let mut first = users.get_by_id(ana.id()).await.unwrap().unwrap();
+        let mut second = users.get_by_id(ana.id()).await.unwrap().unwrap();
+        first.activate().unwrap();
+        trace.push(outcome(users.save(&first).await));
+        second.verify_email().unwrap();
+        trace.push(outcome(users.save(&second).await));
+
+        // Borrado lógico: sigue visible y sigue reservando su email
+        trace.push(outcome(users.soft_delete(ana.id()).await));
+        let deleted = users.get_by_id(ana.id()).await.unwrap().unwrap();
+        trace.push(deleted.status().as_str().to_string());
+        trace.push(users.exists_by_email(ana.email()).await.unwrap().to_string());
+        trace.push(outcome(users.save(&user("ana.parity@example.co")).await));
+        trace.push(outcome(users.soft_delete(ana.id()).await));
+
+        // Roles
+        let member = Role::new(Uuid::new_v4(), "member", None, None, vec!["read".into()], false, None).unwrap();
+        let admin = Role::new(Uuid::new_v4(), "admin", None, None, vec![], true, None).unwrap();
+        trace.push(outcome(backend.roles.save(&member).await));
+        trace.push(outcome(backend.roles.save(&admin).await));
+        let duplicate = Role::new(Uuid::new_v4(), "member", None, None, vec![], false, None).unwrap();
+        trace.push(outcome(backend.roles.save(&duplicate).await));
+        let names: Vec<String> = backend.roles.list_all().await.unwrap().iter().map(|r| r.name.as_str().to_string()).collect();
+        trace.push(names.join(","));
+        trace.push(outcome(backend.roles.delete(Uuid::new_v4()).await));
+
+        // Agregado: diferencias, carga diferida y cascada al borrar
+        let mut aggregate = UserAggregate::new(user("bea.parity@example.co"));
+        let uid = aggregate.id();
+        aggregate.add_role(UserRole::new(Uuid::new_v4(), uid, member.role_id, None, None, None, None).unwrap());
+        aggregate.sessions.push(UserSession::new(Uuid::new_v4(), uid, None, Utc::now() + Duration::hours(1), None, None, None).unwrap());
+        trace.push(format!("{:?}", backend.aggregates.save(&aggregate).await.unwrap()));
+        aggregate.user.take_events();
+
+        let mut lazy = backend.aggregates.load(aggregate.user.id(), LoadOptions::lazy()).await.unwrap().unwrap();
+        trace.push(format!("roles={} sessions={}", lazy.roles.len(), lazy.sessions.len()));
+        lazy.sessions.push(UserSession::new(Uuid::new_v4(), uid, None, Utc::now() + Duration::hours(2), None, None, None).unwrap());
+        trace.push(format!("{:?}", backend.aggregates.save(&lazy).await.unwrap()));
+        backend.aggregates.load_sessions(&mut lazy).await.unwrap();
+        trace.push(format!("sessions={}", lazy.sessions.len()));
+
+        // Borrar el rol elimina la asignación
+        trace.push(outcome(backend.roles.delete(member.role_id).await));
+        let reloaded = backend.aggregates.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().unwrap();
+        trace.push(format!("roles={}", reloaded.roles.len()));
+
+        // Un rol inexistente falla y no deja nada escrito
+        let mut orphan = UserAggregate::new(user("orphan.parity@example.co"));
+        let orphan_id = orphan.id();
+        orphan.add_role(UserRole::new(Uuid::new_v4(), orphan_id, Uuid::new_v4(), None, None, None, None).unwrap());
+        trace.push(backend.aggregates.save(&orphan).await.is_err().to_string());
+        trace.push(users.get_by_id(orphan.user.id()).await.unwrap().is_none().to_string());
+
+        // Borrado físico
+        trace.push(outcome(users.delete(aggregate.user.id()).await));
+        trace.push(backend.aggregates.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().is_none().to_string());
+        trace.push(outcome(users.delete(aggregate.user.id()).await));
+
+        let listed: Vec<String> = users.list_all().await.unwrap().iter().map(|u| u.email().as_str().to_string()).collect();
+        trace.push(listed.join(","));
+
+        trace
+    }
+
+    #[async_std::test]
+    async fn memory_adapters_match_sqlite() {
+        let (sql, outbox) = sqlite().await;
+        let store = InMemoryStore::new();
+
+        let expected = scenario(&sql).await;
+        let actual = scenario(&memory(&store)).await;
+
+        for (sql, mem) in expected.iter().zip(&actual) {
+            println!("🗄️ {sql:<60} 🧠 {mem}");
+        }
+        assert_eq!(actual, expected);
+
+        // La bandeja de salida recibe los mismos eventos
+        let sql_events: Vec<String> = outbox.list_all().await.unwrap().into_iter().map(|m| m.event_type).collect();
+        let mem_events: Vec<String> = store.outbox().into_iter().map(|m| m.event_type).collect();
+        assert_eq!(mem_events, sql_events);
+    }
+
+    #[async_std::test]
+    async fn stores_are_shared_and_thread_safe() {
+        let store = InMemoryStore::new();
+        let repo = InMemoryUserRepository::new(store.clone());
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let repo = repo.clone();
+                async_std::task::spawn(async move {
+                    repo.save(&user(&format!("thread{i}.parity@example.co"))).await
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        // Otro repositorio sobre el mismo almacén ve los mismos datos
+        let other = InMemoryUserRepository::new(store);
+        assert_eq!(other.list_all().await.unwrap().len(), 8);
+    }
+
+    #[async_std::test]
+    async fn unit_of_work_rejects_stale_commits() {
+        let store = InMemoryStore::new();
+        let factory = InMemoryUnitOfWorkFactory::new(store.clone(), Arc::new(InMemoryDomainEventPublisher::new()));
+        let users = InMemoryUserRepository::new(store.clone());
+        let mut shared = user("shared.stale@example.co");
+        users.save(&shared).await.unwrap();
+        shared.take_events();
+
+        // Las dos unidades copian el usuario en la misma versión
+        let first = factory.begin().await.unwrap();
+        let second = factory.begin().await.unwrap();
+        let mut activated = first.users().get_by_id(shared.id()).await.unwrap().unwrap();
+        activated.activate().unwrap();
+        first.users().save(&activated).await.unwrap();
+        let mut deleted = second.users().get_by_id(shared.id()).await.unwrap().unwrap();
+        deleted.delete().unwrap();
+        second.users().save(&deleted).await.unwrap();
+
+        first.commit().await.unwrap();
+        let err = second.commit().await.unwrap_err();
+        println!("⏱️ {err}");
+        assert!(matches!(err, RepositoryError::Concurrency { expected: 1, actual: 2, .. }));
+
+        // Una unidad sin escrituras no entra en conflicto
+        let idle = factory.begin().await.unwrap();
+        users.save(&user("third.stale@example.co")).await.unwrap();
+        idle.commit().await.unwrap();
+
+        let stored = users.get_by_id(shared.id()).await.unwrap().unwrap();
+        assert_eq!(stored.status(), &UserStatus::Active);
+        assert_eq!(users.list_all().await.unwrap().len(), 2);
+    }
+
+    #[async_std::test]
+    async fn concurrent_registrations_keep_emails_unique() {
+        let store = InMemoryStore::new();
+        let factory = InMemoryUnitOfWorkFactory::new(store.clone(), Arc::new(InMemoryDomainEventPublisher::new()));
+
+        // Ninguna copia ve el alta de la otra; el índice único se revisa al confirmar
+        let first = factory.begin().await.unwrap();
+        let second = factory.begin().await.unwrap();
+        first.users().save(&user("twice.unique@example.co")).await.unwrap();
+        second.users().save(&user("twice.unique@example.co")).await.unwrap();
+
+        first.commit().await.unwrap();
+        let err = second.commit().await.unwrap_err();
+        assert!(matches!(err, RepositoryError::Conflict { field: "email", .. }));
+        assert_eq!(InMemoryUserRepository::new(store).list_all().await.unwrap().len(), 1);
+    }
+
+    #[async_std::test]
+    async fn unit_of_work_marks_delivered_events_in_the_outbox() {
+        let store = InMemoryStore::new();
+        let publisher = InMemoryDomainEventPublisher::new();
+        let factory = InMemoryUnitOfWorkFactory::new(store.clone(), Arc::new(publisher.clone()));
+
+        let mut uow = factory.begin().await.unwrap();
+        let mut delivered = user("delivered.outbox@example.co");
+        uow.users().save(&delivered).await.unwrap();
+        uow.record_events(delivered.take_events());
+        uow.commit().await.unwrap();
+
+        let outbox = store.outbox();
+        println!("📮 {:?}", outbox.iter().map(|m| (m.event_type.as_str(), m.published_at.is_some())).collect::<Vec<_>>());
+        assert_eq!(outbox.len(), publisher.published().len());
+        assert!(outbox.iter().all(|m| m.published_at.is_some()));
+    }
+}
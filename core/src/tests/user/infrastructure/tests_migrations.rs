@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+    use sea_orm_migration::{MigratorTrait, SchemaManager};
+    use uuid::Uuid;
+
+    use crate::user::domain::entities::User;
+    use crate::user::domain::repositories::UserRepository;
+    use crate::user::domain::vo::Email;
+    use crate::user::infrastructure::persistence::migrations::Migrator;
+    use crate::user::infrastructure::persistence::orm::entities::*;
+    use crate::user::infrastructure::persistence::orm::UserRepositorySeaOrm;
+    use crate::tests::user::fixtures::sqlite;
+
+    const TABLES: [&str; 13] = [
+        "users",
+        "roles",
+        "user_roles",
+        "user_passwords",
+        "user_auth_methods",
+        "user_mfa",
+        "user_sessions",
+        "user_profiles",
+        "user_subscriptions",
+        "user_gdpr_consents",
+        "user_activity_logs",
+        "user_outbox",
+        "event_store",
+    ];
+
+    #[async_std::test]
+    async fn up_and_down_manage_every_table() {
+        let db = sqlite().await;
+        let manager = SchemaManager::new(&db);
+
+        for table in TABLES {
+            assert!(manager.has_table(table).await.unwrap(), "Falta la tabla {table}");
+        }
+        assert!(Migrator::get_pending_migrations(&db).await.unwrap().is_empty());
+
+        Migrator::down(&db, None).await.unwrap();
+        for table in TABLES {
+            assert!(!manager.has_table(table).await.unwrap(), "La tabla {table} sigue existiendo");
+        }
+
+        // Se pueden volver a aplicar
+        Migrator::up(&db, None).await.unwrap();
+        assert!(manager.has_table("users").await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn entities_match_the_migrated_schema() {
+        let db = sqlite().await;
+
+        // Un SELECT por entidad falla si alguna columna no existe
+        users::Entity::find().all(&db).await.unwrap();
+        roles::Entity::find().all(&db).await.unwrap();
+        user_roles::Entity::find().all(&db).await.unwrap();
+        user_passwords::Entity::find().all(&db).await.unwrap();
+        user_auth_methods::Entity::find().all(&db).await.unwrap();
+        user_mfa::Entity::find().all(&db).await.unwrap();
+        user_sessions::Entity::find().all(&db).await.unwrap();
+        user_profiles::Entity::find().all(&db).await.unwrap();
+        user_subscriptions::Entity::find().all(&db).await.unwrap();
+        user_gdpr_consents::Entity::find().all(&db).await.unwrap();
+        user_activity_logs::Entity::find().all(&db).await.unwrap();
+        user_outbox::Entity::find().all(&db).await.unwrap();
+        stored_event::Entity::find().all(&db).await.unwrap();
+    }
+
+    #[async_std::test]
+    async fn child_rows_require_and_follow_their_user() {
+        let db = sqlite().await;
+        let repo = UserRepositorySeaOrm::new(db.clone());
+
+        let user = User::register(Email::new("schema.user@example.co").unwrap());
+        repo.save(&user).await.unwrap();
+
+        let profile = |user_id: Uuid| user_profiles::ActiveModel {
+            profile_id: Set(Uuid::new_v4()),
+            user_id: Set(user_id),
+            first_name: Set(Some("Ada".into())),
+            last_name: Set(None),
+            display_name: Set(None),
+            avatar_url: Set(None),
+            bio: Set(None),
+            birth_date: Set(None),
+            gender: Set(None),
+            locale: Set("es-ES".into()),
+            timezone: Set("America/Bogota".into()),
+            created_at: Set(Utc::now()),
+        };
+
+        // Clave foránea: no hay perfil sin usuario
+        assert!(profile(Uuid::new_v4()).insert(&db).await.is_err());
+
+        profile(user.id().as_uuid()).insert(&db).await.unwrap();
+        assert!(profile(user.id().as_uuid()).insert(&db).await.is_err(), "Un perfil por usuario");
+
+        // Borrar el usuario arrastra su perfil
+        repo.delete(user.id()).await.unwrap();
+        assert!(user_profiles::Entity::find().all(&db).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use sea_orm::TransactionTrait;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::user::domain::entities::User;
+    use crate::user::domain::events::{UserDomainEvent, UserRegistered};
+    use crate::user::domain::repositories::{OutboxRepository, UnitOfWorkFactory};
+    use crate::user::domain::vo::Email;
+    use crate::user::infrastructure::messaging::{
+        DispatchMode, DomainEventPublisher, InMemoryDomainEventPublisher, InProcessEventBus, OutboxRelay, RetryPolicy,
+    };
+    use crate::user::infrastructure::persistence::orm::{OutboxRepositorySeaOrm, UnitOfWorkFactorySeaOrm};
+    use crate::tests::user::fixtures::sqlite;
+
+    fn registered_events(address: &str) -> Vec<UserDomainEvent> {
+        let mut user = User::register(Email::new(address).unwrap());
+        user.activate().unwrap();
+        user.take_events()
+    }
+
+    /// Publicador que falla las primeras `failures` llamadas.
+    struct FlakyPublisher {
+        failures: usize,
+        calls: AtomicUsize,
+        inner: InMemoryDomainEventPublisher,
+    }
+
+    #[async_trait]
+    impl DomainEventPublisher for FlakyPublisher {
+        async fn publish(&self, event: &UserDomainEvent) -> anyhow::Result<()> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                anyhow::bail!("broker unavailable");
+            }
+            self.inner.publish(event).await
+        }
+    }
+
+    #[async_std::test]
+    async fn events_are_written_in_the_aggregate_transaction() {
+        let db = sqlite().await;
+        let outbox = OutboxRepositorySeaOrm::new(db.clone());
+
+        // Transacción revertida: no queda nada en la bandeja
+        let txn = db.begin().await.unwrap();
+        OutboxRepositorySeaOrm::append(&txn, &registered_events("rollback.user@example.co")).await.unwrap();
+        txn.rollback().await.unwrap();
+        assert!(outbox.list_all().await.unwrap().is_empty());
+
+        // Transacción confirmada: los eventos quedan pendientes
+        let events = registered_events("commit.user@example.co");
+        let txn = db.begin().await.unwrap();
+        OutboxRepositorySeaOrm::append(&txn, &events).await.unwrap();
+        txn.commit().await.unwrap();
+
+        let stored = outbox.list_all().await.unwrap();
+        println!("📮 {} mensajes en la bandeja", stored.len());
+        assert_eq!(stored.len(), events.len());
+        assert!(stored.iter().all(|m| m.published_at.is_none() && m.attempts == 0));
+        assert_eq!(stored[0].to_event().unwrap().event_id(), events[0].event_id());
+
+        // Reinsertar los mismos eventos es inofensivo
+        OutboxRepositorySeaOrm::append(&db, &events).await.unwrap();
+        assert_eq!(outbox.list_all().await.unwrap().len(), events.len());
+    }
+
+    #[async_std::test]
+    async fn relay_publishes_pending_events_once() {
+        let db = sqlite().await;
+        let events = registered_events("relay.user@example.co");
+        OutboxRepositorySeaOrm::append(&db, &events).await.unwrap();
+
+        let publisher = InMemoryDomainEventPublisher::new();
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db.clone()), Arc::new(publisher.clone()));
+
+        let report = relay.relay_once().await.unwrap();
+        println!("📤 {report:?}");
+        assert_eq!(report.published, events.len());
+
+        let published = publisher.published();
+        assert_eq!(
+            published.iter().map(|e| e.event_id()).collect::<Vec<_>>(),
+            events.iter().map(|e| e.event_id()).collect::<Vec<_>>(),
+            "Se publican en orden de ocurrencia"
+        );
+
+        // Una segunda pasada no vuelve a publicar
+        assert_eq!(relay.relay_once().await.unwrap().published, 0);
+        assert_eq!(publisher.published().len(), events.len());
+
+        // Marcar de nuevo no tiene efecto
+        assert!(!relay.outbox().mark_published(events[0].event_id(), Utc::now()).await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn failed_deliveries_are_retried_with_backoff() {
+        let db = sqlite().await;
+        let events = registered_events("retry.user@example.co");
+        OutboxRepositorySeaOrm::append(&db, &events[..1]).await.unwrap();
+
+        let publisher = Arc::new(FlakyPublisher {
+            failures: 2,
+            calls: AtomicUsize::new(0),
+            inner: InMemoryDomainEventPublisher::new(),
+        });
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60),
+        };
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db.clone()), publisher.clone()).with_policy(policy);
+
+        let now = Utc::now();
+        let first = relay.relay_at(now).await.unwrap();
+        assert_eq!((first.published, first.failed), (0, 1));
+
+        let stored = relay.outbox().list_all().await.unwrap().remove(0);
+        println!("⏳ intento {} → {:?} ({:?})", stored.attempts, stored.next_attempt_at, stored.last_error);
+        assert_eq!(stored.attempts, 1);
+        assert_eq!(stored.last_error.as_deref(), Some("broker unavailable"));
+        assert_eq!(stored.next_attempt_at, now + chrono::Duration::seconds(10));
+
+        // Antes de que venza el backoff no se reintenta
+        assert_eq!(relay.relay_at(now + chrono::Duration::seconds(5)).await.unwrap(), Default::default());
+
+        let second = relay.relay_at(now + chrono::Duration::seconds(10)).await.unwrap();
+        assert_eq!(second.failed, 1);
+        let stored = relay.outbox().list_all().await.unwrap().remove(0);
+        assert_eq!(stored.next_attempt_at, now + chrono::Duration::seconds(30), "El backoff se duplica");
+
+        let third = relay.relay_at(now + chrono::Duration::seconds(30)).await.unwrap();
+        assert_eq!(third.published, 1);
+        assert_eq!(publisher.inner.published().len(), 1);
+    }
+
+    #[async_std::test]
+    async fn failures_hold_back_later_events_of_the_same_aggregate() {
+        let db = sqlite().await;
+        let blocked = registered_events("held.user@example.co");
+        let other = registered_events("free.user@example.co");
+        OutboxRepositorySeaOrm::append(&db, &blocked).await.unwrap();
+        OutboxRepositorySeaOrm::append(&db, &other).await.unwrap();
+
+        let publisher = Arc::new(FlakyPublisher {
+            failures: 1,
+            calls: AtomicUsize::new(0),
+            inner: InMemoryDomainEventPublisher::new(),
+        });
+        let policy = RetryPolicy { max_attempts: 5, base_delay: Duration::from_secs(10), max_delay: Duration::from_secs(60) };
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db.clone()), publisher.clone()).with_policy(policy);
+
+        // 🔹 Falla el alta del primer usuario: su activación espera, el otro agregado sigue
+        let now = Utc::now();
+        let report = relay.relay_at(now).await.unwrap();
+        println!("📤 {report:?}");
+        assert_eq!((report.published, report.failed, report.skipped), (2, 1, 1));
+        let ids = |events: &[UserDomainEvent]| events.iter().map(|e| e.event_id()).collect::<Vec<_>>();
+        assert_eq!(ids(&publisher.inner.published()), ids(&other));
+
+        // 🔹 Mientras corre el backoff tampoco se adelanta en pasadas siguientes
+        assert_eq!(relay.relay_at(now + chrono::Duration::seconds(5)).await.unwrap(), Default::default());
+
+        // 🔹 Vencido el backoff se entregan en orden
+        let report = relay.relay_at(now + chrono::Duration::seconds(10)).await.unwrap();
+        assert_eq!(report.published, 2);
+        assert_eq!(ids(&publisher.inner.published()[2..]), ids(&blocked));
+    }
+
+    #[async_std::test]
+    async fn failing_bus_handlers_keep_the_message_pending() {
+        let db = sqlite().await;
+        OutboxRepositorySeaOrm::append(&db, &registered_events("bus.retry@example.co")[..1]).await.unwrap();
+
+        let bus = InProcessEventBus::new(DispatchMode::Sync);
+        let healthy = Arc::new(AtomicBool::new(false));
+        let seen = healthy.clone();
+        bus.subscribe::<UserRegistered, _>(move |_: &UserRegistered| {
+            anyhow::ensure!(seen.load(Ordering::SeqCst), "mailer unavailable");
+            Ok(())
+        });
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db.clone()), Arc::new(bus.clone()))
+            .with_policy(RetryPolicy { max_attempts: 3, base_delay: Duration::ZERO, max_delay: Duration::ZERO });
+
+        // 🔹 El fallo del manejador no deja el mensaje como publicado
+        let report = relay.relay_once().await.unwrap();
+        println!("📤 {report:?} 💀 {}", bus.dead_letters().len());
+        assert_eq!((report.published, report.failed), (0, 1));
+        let stored = relay.outbox().list_all().await.unwrap().remove(0);
+        assert!(stored.published_at.is_none());
+        assert!(stored.last_error.unwrap().starts_with("user_registered failed for handler(s)"));
+
+        healthy.store(true, Ordering::SeqCst);
+        assert_eq!(relay.relay_once().await.unwrap().published, 1);
+    }
+
+    #[async_std::test]
+    async fn commit_and_relay_deliver_each_event_once() {
+        let db = sqlite().await;
+        let commit = |publisher: Arc<dyn DomainEventPublisher>, address: &'static str| {
+            let factory = UnitOfWorkFactorySeaOrm::new(db.clone(), publisher);
+            async move {
+                let mut uow = factory.begin().await.unwrap();
+                let mut user = User::register(Email::new(address).unwrap());
+                user.activate().unwrap();
+                uow.users().save(&user).await.unwrap();
+                uow.record_events(user.take_events());
+                uow.commit().await.unwrap();
+            }
+        };
+        let subscriber = InMemoryDomainEventPublisher::new();
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db.clone()), Arc::new(subscriber.clone()));
+
+        // 🔹 Entregados al confirmar: el relay no los repite
+        commit(Arc::new(subscriber.clone()), "once.user@example.co").await;
+        assert_eq!(relay.relay_once().await.unwrap().published, 0);
+        assert_eq!(subscriber.published().len(), 2);
+
+        // 🔹 Si la entrega al confirmar falla, el relay entrega ese y los siguientes, en orden
+        let flaky = Arc::new(FlakyPublisher { failures: 1, calls: AtomicUsize::new(0), inner: InMemoryDomainEventPublisher::new() });
+        commit(flaky.clone(), "later.user@example.co").await;
+        assert_eq!(flaky.calls.load(Ordering::SeqCst), 1, "Tras el primer fallo no se sigue publicando");
+        assert_eq!(relay.relay_once().await.unwrap().published, 2);
+        let names: Vec<_> = subscriber.published().iter().map(|e| e.event_name()).collect();
+        println!("📬 {names:?}");
+        assert_eq!(names, ["user_registered", "user_activated", "user_registered", "user_activated"]);
+    }
+
+    #[async_std::test]
+    async fn messages_stop_after_max_attempts() {
+        let db = sqlite().await;
+        OutboxRepositorySeaOrm::append(&db, &registered_events("dead.user@example.co")[..1]).await.unwrap();
+
+        let publisher = Arc::new(FlakyPublisher {
+            failures: usize::MAX,
+            calls: AtomicUsize::new(0),
+            inner: InMemoryDomainEventPublisher::new(),
+        });
+        let policy = RetryPolicy { max_attempts: 2, base_delay: Duration::ZERO, max_delay: Duration::ZERO };
+        let relay = OutboxRelay::new(OutboxRepositorySeaOrm::new(db), publisher).with_policy(policy);
+
+        assert_eq!(relay.relay_once().await.unwrap().exhausted, 0);
+        assert_eq!(relay.relay_once().await.unwrap().exhausted, 1);
+        assert_eq!(relay.relay_once().await.unwrap(), Default::default(), "Agotado: ya no se reintenta");
+    }
+
+    #[test]
+    fn backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(20),
+        };
+
+        let delays: Vec<u64> = (1..=7).map(|n| policy.delay_for(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 20, 20]);
+        assert_eq!(policy.delay_for(u32::MAX), Duration::from_secs(20));
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::user::application::*;
+    use crate::user::domain::aggregates::LoadOptions;
+    use crate::user::domain::repositories::UserAggregateRepository;
+    use crate::user::domain::services::PasswordHasher;
+    use crate::user::domain::vo::UserId;
+    use crate::user::infrastructure::messaging::domain_event_publisher::InMemoryDomainEventPublisher;
+    use crate::user::infrastructure::persistence::memory::{
+        InMemoryRoleRepository, InMemoryStore, InMemoryUnitOfWorkFactory, InMemoryUserAggregateRepository,
+    };
+    use crate::user::infrastructure::services_impl::{
+        Argon2Config, Argon2PasswordHasher, BcryptPasswordHasher, DelegatingPasswordHasher,
+    };
+    use crate::tests::user::fixtures::{register, seed_role};
+
+    /// Parámetros mínimos para que los tests no tarden.
+    fn argon2(memory_kib: u32, iterations: u32, pepper: Option<&[u8]>) -> Argon2PasswordHasher {
+        Argon2PasswordHasher::new(Argon2Config {
+            memory_kib,
+            iterations,
+            parallelism: 1,
+            pepper: pepper.map(<[u8]>::to_vec),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_argon2_uses_configured_params_and_pepper() {
+        let hasher = argon2(1024, 1, None);
+        let hash = hasher.hash("s3cret-Passw0rd").unwrap();
+        println!("🔐 {hash}");
+
+        // 🔹 Formato PHC con los parámetros configurados
+        assert!(hash.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert!(hasher.verify("s3cret-Passw0rd", &hash).unwrap());
+        assert!(!hasher.verify("otra", &hash).unwrap());
+        assert!(!hasher.needs_rehash(&hash));
+
+        // 🔹 Con pepper, el hash sólo se verifica con el mismo secreto
+        let peppered = argon2(1024, 1, Some(b"pimienta"));
+        let hash = peppered.hash("s3cret-Passw0rd").unwrap();
+        assert!(peppered.verify("s3cret-Passw0rd", &hash).unwrap());
+        assert!(!hasher.verify("s3cret-Passw0rd", &hash).unwrap());
+        assert!(!argon2(1024, 1, Some(b"otra")).verify("s3cret-Passw0rd", &hash).unwrap());
+
+        // 🔹 El pepper no aparece en `Debug`
+        let debug = format!("{:?}", peppered.config());
+        assert!(!debug.contains("pimienta"));
+
+        // 🔹 Parámetros inválidos
+        let err = Argon2PasswordHasher::new(Argon2Config { memory_kib: 1, ..Argon2Config::default() }).unwrap_err();
+        assert_eq!(err.code(), "user.password.invalid_state");
+
+        // 🔹 Un hash que no es PHC
+        let err = hasher.verify("s3cret-Passw0rd", "no-es-un-hash").unwrap_err();
+        assert_eq!(err.code(), "user.password.invalid_format");
+    }
+
+    #[test]
+    fn test_argon2_flags_hashes_with_old_params() {
+        let old = argon2(1024, 1, None).hash("s3cret-Passw0rd").unwrap();
+        let current = argon2(2048, 2, None);
+
+        // 🔹 Se sigue verificando con los parámetros del propio hash
+        assert!(current.verify("s3cret-Passw0rd", &old).unwrap());
+        assert!(current.needs_rehash(&old));
+        assert!(!current.needs_rehash(&current.hash("s3cret-Passw0rd").unwrap()));
+
+        // 🔹 Otras variantes de Argon2 y hashes ajenos
+        assert!(current.needs_rehash("$argon2i$v=19$m=2048,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA"));
+        assert!(current.needs_rehash("no-es-un-hash"));
+        assert!(!current.recognizes("$2b$04$abcdefghijklmnopqrstuu"));
+    }
+
+    #[test]
+    fn test_bcrypt_roundtrip_and_cost_upgrade() {
+        let cheap = BcryptPasswordHasher::new(4).unwrap();
+        let hash = cheap.hash("s3cret-Passw0rd").unwrap();
+        println!("🔐 {hash}");
+
+        assert!(cheap.recognizes(&hash));
+        assert!(cheap.verify("s3cret-Passw0rd", &hash).unwrap());
+        assert!(!cheap.verify("otra", &hash).unwrap());
+        assert!(!cheap.needs_rehash(&hash));
+
+        // 🔹 Subir el coste marca los hashes anteriores
+        let stronger = BcryptPasswordHasher::new(5).unwrap();
+        assert!(stronger.needs_rehash(&hash));
+        assert!(stronger.verify("s3cret-Passw0rd", &hash).unwrap());
+
+        assert_eq!(BcryptPasswordHasher::new(3).unwrap_err().code(), "user.password.invalid_state");
+        assert_eq!(BcryptPasswordHasher::new(32).unwrap_err().code(), "user.password.invalid_state");
+    }
+
+    #[test]
+    fn test_delegating_hasher_migrates_legacy_hashes() {
+        let bcrypt = Arc::new(BcryptPasswordHasher::new(4).unwrap());
+        let hasher = DelegatingPasswordHasher::new(Arc::new(argon2(1024, 1, None))).with_legacy(bcrypt.clone());
+
+        // 🔹 Los hashes nuevos salen del algoritmo actual
+        let fresh = hasher.hash("s3cret-Passw0rd").unwrap();
+        assert!(fresh.starts_with("$argon2id$"));
+        assert!(!hasher.needs_rehash(&fresh));
+
+        // 🔹 Los heredados se verifican y se marcan para recalcular
+        let legacy = bcrypt.hash("s3cret-Passw0rd").unwrap();
+        assert!(hasher.verify("s3cret-Passw0rd", &legacy).unwrap());
+        assert!(!hasher.verify("otra", &legacy).unwrap());
+        assert!(hasher.needs_rehash(&legacy));
+
+        // 🔹 Algoritmos desconocidos
+        let err = hasher.verify("s3cret-Passw0rd", "plain$s3cret-Passw0rd").unwrap_err();
+        println!("❌ {}", err.code());
+        assert_eq!(err.code(), "user.password.not_supported");
+    }
+
+    #[async_std::test]
+    async fn test_login_rehashes_legacy_password() {
+        let store = InMemoryStore::new();
+        let roles = InMemoryRoleRepository::new(store.clone());
+        seed_role(&roles, "customer").await;
+        let aggregates = InMemoryUserAggregateRepository::new(store.clone());
+        let uow = Arc::new(InMemoryUnitOfWorkFactory::new(store, Arc::new(InMemoryDomainEventPublisher::new())));
+
+        // 🔹 Usuario importado con un hash bcrypt
+        let bcrypt = Arc::new(BcryptPasswordHasher::new(4).unwrap());
+        let legacy = UserService::new(uow.clone(), bcrypt.clone());
+        let id = legacy.register_user(register("legado@example.co")).await.unwrap();
+
+        let stored_hash = || async {
+            let stored = aggregates.load(&UserId::from_uuid(id), LoadOptions::lazy()).await.unwrap().unwrap();
+            stored.password.unwrap().password_hash
+        };
+        assert!(stored_hash().await.starts_with("$2b$"));
+
+        let hasher = DelegatingPasswordHasher::new(Arc::new(argon2(1024, 1, None))).with_legacy(bcrypt);
+        let service = UserService::new(uow, Arc::new(hasher));
+        let login = |email: &str, password: &str| AuthenticateUser { email: email.into(), password: password.into(), ..Default::default() };
+
+        // 🔹 Contraseña incorrecta o email desconocido: mismo error, sin cambios
+        let err = service.authenticate(login("legado@example.co", "otra")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.mismatch");
+        let err = service.authenticate(login("nadie@example.co", "s3cret-Passw0rd")).await.unwrap_err();
+        assert_eq!(err.code(), "user.password.mismatch");
+        assert!(stored_hash().await.starts_with("$2b$"));
+
+        // 🔹 El primer login correcto migra el hash a Argon2id
+        assert_eq!(service.authenticate(login("legado@example.co", "s3cret-Passw0rd")).await.unwrap(), id);
+        let upgraded = stored_hash().await;
+        println!("🔄 {upgraded}");
+        assert!(upgraded.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+
+        // 🔹 Los siguientes no lo vuelven a tocar
+        assert_eq!(service.authenticate(login("legado@example.co", "s3cret-Passw0rd")).await.unwrap(), id);
+        assert_eq!(stored_hash().await, upgraded);
+    }
+}
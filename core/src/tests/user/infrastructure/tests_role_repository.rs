@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::user::domain::entities::Role;
+    use crate::user::domain::repositories::{RepositoryError, RoleRepository};
+    use crate::user::domain::vo::RoleName;
+    use crate::user::infrastructure::persistence::orm::RoleRepositorySeaOrm;
+    use crate::tests::user::fixtures::sqlite;
+
+    async fn repository() -> RoleRepositorySeaOrm {
+        RoleRepositorySeaOrm::new(sqlite().await)
+    }
+
+    fn role(name: &str, permissions: &[&str]) -> Role {
+        Role::new(
+            Uuid::new_v4(),
+            name,
+            Some(name.to_uppercase()),
+            None,
+            permissions.iter().map(|p| p.to_string()).collect(),
+            false,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[async_std::test]
+    async fn save_find_update_and_delete() {
+        let repo = repository().await;
+
+        let mut admin = role("admin", &["users.read", "users.write"]);
+        repo.save(&admin).await.unwrap();
+        repo.save(&role("viewer", &["users.read"])).await.unwrap();
+
+        let found = repo.get_by_id(admin.role_id).await.unwrap().unwrap();
+        println!("🛡️ {found:?}");
+        assert_eq!(found.name, admin.name);
+        assert_eq!(found.permissions, admin.permissions);
+
+        let by_name = repo.get_by_name(&RoleName::new("admin").unwrap()).await.unwrap().unwrap();
+        assert_eq!(by_name.role_id, admin.role_id);
+
+        // Guardar de nuevo actualiza
+        admin.add_permission("roles.write".into());
+        repo.save(&admin).await.unwrap();
+        let updated = repo.get_by_id(admin.role_id).await.unwrap().unwrap();
+        assert!(updated.has_permission("roles.write"));
+
+        let names: Vec<String> = repo.list_all().await.unwrap().iter().map(|r| r.name.to_string()).collect();
+        assert_eq!(names, vec!["admin", "viewer"]);
+
+        repo.delete(admin.role_id).await.unwrap();
+        assert!(repo.get_by_id(admin.role_id).await.unwrap().is_none());
+        assert!(matches!(repo.delete(admin.role_id).await, Err(RepositoryError::NotFound { entity: "role", .. })));
+    }
+
+    #[async_std::test]
+    async fn duplicate_names_conflict() {
+        let repo = repository().await;
+        repo.save(&role("editor", &[])).await.unwrap();
+
+        let err = repo.save(&role("editor", &[])).await.unwrap_err();
+        assert!(matches!(err, RepositoryError::Conflict { entity: "role", field: "name", .. }));
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use sea_orm::sea_query::Expr;
+    use sea_orm::{DatabaseConnection, EntityTrait};
+    use uuid::Uuid;
+
+    use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+    use crate::user::domain::entities::{Role, User, UserActivityLog, UserProfile, UserRole, UserSession};
+    use crate::user::domain::repositories::{RoleRepository, UserAggregateRepository, UserRepository};
+    use crate::user::domain::vo::*;
+    use crate::user::infrastructure::persistence::orm::entities::user_passwords;
+    use crate::user::infrastructure::persistence::orm::{
+        RoleRepositorySeaOrm, UserAggregateRepositorySeaOrm, UserRepositorySeaOrm,
+    };
+    use crate::tests::user::fixtures::{sqlite, stored_password};
+
+    fn session(user_id: Uuid) -> UserSession {
+        UserSession::new(Uuid::new_v4(), user_id, None, Utc::now() + Duration::hours(1), None, None, None).unwrap()
+    }
+
+    fn activity(user_id: Uuid, action: &str) -> UserActivityLog {
+        UserActivityLog::new(Uuid::new_v4(), user_id, action, None, None, None, true, None, None).unwrap()
+    }
+
+    /// Agregado con perfil, rol, una sesión y un registro de actividad.
+    async fn aggregate(db: &DatabaseConnection, email: &str) -> UserAggregate {
+        let role = Role::new(Uuid::new_v4(), "member", None, None, vec![], false, None).unwrap();
+        RoleRepositorySeaOrm::new(db.clone()).save(&role).await.unwrap();
+
+        let mut aggregate = UserAggregate::new(User::register(Email::new(email).unwrap()));
+        let uid = aggregate.id();
+        aggregate.set_profile(
+            UserProfile::new(Uuid::new_v4(), uid, Some("Ana".into()), None, Some("Ana Pérez".into()), None, None, None, None, None, None, None)
+                .unwrap(),
+        );
+        aggregate.add_role(UserRole::new(Uuid::new_v4(), uid, role.role_id, None, None, None, None).unwrap());
+        aggregate.sessions.push(session(uid));
+        aggregate.log_activity(activity(uid, "login"));
+        aggregate
+    }
+
+    #[async_std::test]
+    async fn save_and_load_round_trip() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+
+        let mut aggregate = aggregate(&db, "aggregate.user@example.co").await;
+        let changes = repo.save(&aggregate).await.unwrap();
+        aggregate.user.take_events();
+        println!("🧩 {:?}", changes);
+        assert_eq!(changes.get("profile").inserted, 1);
+        assert_eq!(changes.get("sessions").inserted, 1);
+
+        let loaded = repo.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().unwrap();
+        assert_eq!(loaded.user.snapshot(), aggregate.user.snapshot());
+        assert_eq!(loaded.profile, aggregate.profile);
+        assert_eq!(loaded.roles, aggregate.roles);
+        assert_eq!(loaded.sessions, aggregate.sessions);
+        assert_eq!(loaded.activity_logs, aggregate.activity_logs);
+        assert_eq!(loaded.loaded(), LoadOptions::all());
+
+        let missing = UserId::from_uuid(Uuid::new_v4());
+        assert!(repo.load(&missing, LoadOptions::all()).await.unwrap().is_none());
+    }
+
+    #[async_std::test]
+    async fn save_only_writes_the_diff() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+
+        let mut aggregate = aggregate(&db, "diff.user@example.co").await;
+        repo.save(&aggregate).await.unwrap();
+        aggregate.user.take_events();
+
+        // Sin cambios no se escribe nada
+        let mut loaded = repo.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().unwrap();
+        assert!(repo.save(&loaded).await.unwrap().is_empty());
+
+        // Una actualización, un borrado y una inserción
+        loaded.profile.as_mut().unwrap().update_bio("Hola".into());
+        loaded.sessions.clear();
+        let uid = loaded.id();
+        loaded.log_activity(activity(uid, "logout"));
+
+        let changes = repo.save(&loaded).await.unwrap();
+        println!("🔀 {:?}", changes);
+        assert_eq!(changes.get("profile").updated, 1);
+        assert_eq!(changes.get("sessions").deleted, 1);
+        assert_eq!(changes.get("activity_logs").inserted, 1);
+        assert!(changes.get("roles").is_empty());
+
+        let reloaded = repo.load(aggregate.user.id(), LoadOptions::all()).await.unwrap().unwrap();
+        assert_eq!(reloaded.profile.unwrap().bio.as_deref(), Some("Hola"));
+        assert!(reloaded.sessions.is_empty());
+        assert_eq!(reloaded.activity_logs.len(), 2);
+    }
+
+    #[async_std::test]
+    async fn lazy_collections_are_never_deleted() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+
+        let mut aggregate = aggregate(&db, "lazy.user@example.co").await;
+        repo.save(&aggregate).await.unwrap();
+        aggregate.user.take_events();
+
+        let mut lazy = repo.load(aggregate.user.id(), LoadOptions::lazy()).await.unwrap().unwrap();
+        assert!(lazy.sessions.is_empty());
+        assert!(lazy.activity_logs.is_empty());
+
+        // Añadir sin haber cargado: se inserta, pero lo almacenado no se toca
+        let uid = lazy.id();
+        lazy.sessions.push(session(uid));
+        let changes = repo.save(&lazy).await.unwrap();
+        assert_eq!(changes.get("sessions").inserted, 1);
+        assert_eq!(changes.get("sessions").deleted, 0);
+        assert!(changes.get("activity_logs").is_empty());
+
+        // La carga diferida conserva la sesión en memoria y trae el resto
+        repo.load_sessions(&mut lazy).await.unwrap();
+        repo.load_activity_logs(&mut lazy).await.unwrap();
+        println!("💤 sesiones: {}, actividad: {}", lazy.sessions.len(), lazy.activity_logs.len());
+        assert_eq!(lazy.sessions.len(), 2);
+        assert_eq!(lazy.activity_logs.len(), 1);
+        assert_eq!(lazy.loaded(), LoadOptions::all());
+    }
+
+    #[async_std::test]
+    async fn failed_child_write_rolls_back_the_root() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+
+        // El rol no existe: la clave foránea falla después de escribir el usuario
+        let mut aggregate = UserAggregate::new(User::register(Email::new("rollback.user@example.co").unwrap()));
+        let uid = aggregate.id();
+        aggregate.add_role(UserRole::new(Uuid::new_v4(), uid, Uuid::new_v4(), None, None, None, None).unwrap());
+
+        let err = repo.save(&aggregate).await.unwrap_err();
+        println!("↩️ {err}");
+
+        let users = UserRepositorySeaOrm::new(db);
+        assert!(users.get_by_id(aggregate.user.id()).await.unwrap().is_none(), "La transacción se deshizo");
+    }
+
+    #[async_std::test]
+    async fn children_of_another_user_are_rejected() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+
+        let mut aggregate = UserAggregate::new(User::register(Email::new("owner.user@example.co").unwrap()));
+        aggregate.sessions.push(session(Uuid::new_v4()));
+
+        let err = repo.save(&aggregate).await.unwrap_err();
+        println!("🚫 {}", err.code());
+        assert_eq!(err.code(), "user.id.mismatch");
+    }
+    #[async_std::test]
+    async fn empty_password_history_is_stored_as_null() {
+        let db = sqlite().await;
+        let repo = UserAggregateRepositorySeaOrm::new(db.clone());
+        let mut aggregate = UserAggregate::new(User::register(Email::new("sin.historial@example.co").unwrap()));
+        aggregate.password = Some(stored_password(aggregate.id(), "clave-inicial"));
+        repo.save(&aggregate).await.unwrap();
+
+        let row = user_passwords::Entity::find().one(&db).await.unwrap().unwrap();
+        assert_eq!(row.password_history, None);
+
+        // 🔹 Una fila antigua con `[]` equivale a NULL: guardar no la reescribe
+        user_passwords::Entity::update_many()
+            .col_expr(user_passwords::Column::PasswordHistory, Expr::value(serde_json::json!([])))
+            .exec(&db)
+            .await
+            .unwrap();
+        let loaded = repo.load(&UserId::from_uuid(aggregate.id()), LoadOptions::lazy()).await.unwrap().unwrap();
+        let changes = repo.save(&loaded).await.unwrap();
+        println!("🗂️ {changes:?}");
+        assert!(changes.is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::Expr;
+    use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+    use crate::user::domain::entities::User;
+    use crate::user::domain::repositories::{RepositoryError, UserRepository};
+    use crate::user::domain::vo::*;
+    use crate::user::infrastructure::persistence::orm::entities::users;
+    use crate::user::infrastructure::persistence::orm::{OutboxRepositorySeaOrm, UserRepositorySeaOrm};
+    use crate::tests::user::fixtures::{save, sqlite};
+
+    fn email(value: &str) -> Email {
+        Email::new(value).unwrap()
+    }
+
+    #[async_std::test]
+    async fn save_and_find_by_value_objects() {
+        let db = sqlite().await;
+        let repo = UserRepositorySeaOrm::new(db.clone());
+
+        let mut user = User::register(email("repo.user@example.co"));
+        user.assign_username(Username::new("repo_user").unwrap()).unwrap();
+        user.assign_phone(Phone::new("57", "3001234567").unwrap()).unwrap();
+        save(&repo, &mut user).await;
+
+        let by_id = repo.get_by_id(user.id()).await.unwrap().unwrap();
+        assert_eq!(by_id.snapshot(), user.snapshot());
+        assert_eq!(by_id.persisted_version(), user.version());
+
+        let by_email = repo.get_by_email(user.email()).await.unwrap().unwrap();
+        assert_eq!(by_email.id(), user.id());
+
+        let by_username = repo.get_by_username(&Username::new("repo_user").unwrap()).await.unwrap().unwrap();
+        assert_eq!(by_username.id(), user.id());
+
+        assert!(repo.exists_by_email(&email("repo.user@example.co")).await.unwrap());
+        assert!(!repo.exists_by_email(&email("nobody.here@example.co")).await.unwrap());
+        assert!(repo.exists_by_username(&Username::new("repo_user").unwrap()).await.unwrap());
+
+        // Los eventos viajaron a la bandeja de salida en la misma transacción
+        let outbox = OutboxRepositorySeaOrm::new(db).list_all().await.unwrap();
+        println!("📮 {:?}", outbox.iter().map(|m| m.event_type.as_str()).collect::<Vec<_>>());
+        assert_eq!(outbox.len(), 3);
+    }
+
+    #[async_std::test]
+    async fn updates_use_optimistic_concurrency() {
+        let repo = UserRepositorySeaOrm::new(sqlite().await);
+
+        let mut user = User::register(email("stale.user@example.co"));
+        save(&repo, &mut user).await;
+
+        let mut first = repo.get_by_id(user.id()).await.unwrap().unwrap();
+        let mut second = repo.get_by_id(user.id()).await.unwrap().unwrap();
+
+        first.activate().unwrap();
+        save(&repo, &mut first).await;
+
+        second.verify_email().unwrap();
+        let err = repo.save(&second).await.unwrap_err();
+        println!("⚔️ {err}");
+        assert!(matches!(err, RepositoryError::Concurrency { expected: 1, actual: 2, .. }));
+
+        let stored = repo.get_by_id(user.id()).await.unwrap().unwrap();
+        assert_eq!(stored.status(), &UserStatus::Active);
+        assert!(!stored.email_verified(), "La escritura rechazada no se aplicó");
+    }
+
+    #[async_std::test]
+    async fn rows_stored_at_version_zero_are_rejected() {
+        let db = sqlite().await;
+        let repo = UserRepositorySeaOrm::new(db.clone());
+
+        // Fila escrita por fuera del dominio, sin versión
+        let mut user = User::register(email("unversioned.user@example.co"));
+        save(&repo, &mut user).await;
+        users::Entity::update_many()
+            .col_expr(users::Column::Version, Expr::value(0))
+            .filter(users::Column::UserId.eq(user.id().as_uuid()))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        let err = repo.get_by_id(user.id()).await.unwrap_err();
+        println!("🚫 {err}");
+        assert_eq!(err.code(), "user.version.missing");
+
+        // Un alta repetida con el mismo id sigue siendo un conflicto de versión
+        let fresh = User::register(email("fresh.user@example.co"));
+        repo.save(&fresh).await.unwrap();
+        let err = repo.save(&fresh).await.unwrap_err();
+        println!("⚔️ {err}");
+        assert!(matches!(err, RepositoryError::Concurrency { expected: 0, actual: 1, .. }));
+    }
+
+    #[async_std::test]
+    async fn unique_values_are_enforced() {
+        let repo = UserRepositorySeaOrm::new(sqlite().await);
+
+        let mut first = User::register(email("taken.user@example.co"));
+        first.assign_username(Username::new("taken_user").unwrap()).unwrap();
+        save(&repo, &mut first).await;
+
+        let duplicate_email = User::register(email("taken.user@example.co"));
+        let err = repo.save(&duplicate_email).await.unwrap_err();
+        assert!(matches!(err, RepositoryError::Conflict { entity: "user", field: "email", .. }));
+
+        let mut duplicate_username = User::register(email("other.user@example.co"));
+        duplicate_username.assign_username(Username::new("taken_user").unwrap()).unwrap();
+        let err = repo.save(&duplicate_username).await.unwrap_err();
+        assert_eq!(err.code(), "user.repository.conflict");
+        assert!(matches!(err, RepositoryError::Conflict { field: "username", .. }));
+
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+    }
+
+    #[async_std::test]
+    async fn soft_delete_list_and_delete() {
+        let repo = UserRepositorySeaOrm::new(sqlite().await);
+
+        let mut kept = User::register(email("kept.user@example.co"));
+        save(&repo, &mut kept).await;
+        let mut removed = User::register(email("removed.user@example.co"));
+        save(&repo, &mut removed).await;
+
+        repo.soft_delete(removed.id()).await.unwrap();
+        let deleted = repo.get_by_id(removed.id()).await.unwrap().unwrap();
+        assert_eq!(deleted.status(), &UserStatus::Deleted);
+        assert!(deleted.deleted_at().is_some());
+
+        // El borrado lógico mantiene la fila
+        assert_eq!(repo.list_all().await.unwrap().len(), 2);
+
+        repo.delete(removed.id()).await.unwrap();
+        assert!(repo.get_by_id(removed.id()).await.unwrap().is_none());
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+
+        let missing = repo.delete(removed.id()).await.unwrap_err();
+        assert_eq!(missing.code(), "user.repository.not_found");
+        assert_eq!(repo.soft_delete(&UserId::new()).await.unwrap_err().code(), "user.repository.not_found");
+    }
+}
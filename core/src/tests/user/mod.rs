@@ -1,3 +1,4 @@
 mod application;
 mod domain;
+mod fixtures;
 mod infrastructure;
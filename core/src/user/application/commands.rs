@@ -0,0 +1,146 @@
+//! Comandos del contexto `User`.
+//!
+//! Llevan los datos crudos tal como llegan del backend o de la app de
+//! escritorio; `UserService` los valida al convertirlos en value objects.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegisterUser {
+    pub email: String,
+    pub username: Option<String>,
+    pub password: String,
+    /// Consentimientos aceptados, p. ej. `terms_of_service`.
+    pub consents: Vec<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub display_name: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerifyEmail {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeEmail {
+    pub user_id: Uuid,
+    pub new_email: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignUsername {
+    pub user_id: Uuid,
+    pub username: String,
+}
+
+/// `phone` en formato internacional, con el código de país separado del
+/// número por un espacio o un guion, p. ej. `+57 3001234567`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignPhone {
+    pub user_id: Uuid,
+    pub phone: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerifyPhone {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivateUser {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspendUser {
+    pub user_id: Uuid,
+}
+
+/// Borrado lógico: el usuario pasa a `deleted` y conserva sus datos.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeleteUser {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignRole {
+    pub user_id: Uuid,
+    pub role: String,
+    pub granted_by: Option<Uuid>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RevokeRole {
+    pub user_id: Uuid,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangePassword {
+    pub user_id: Uuid,
+    pub current_password: String,
+    pub new_password: String,
+}
+
+/// Levanta el bloqueo por intentos fallidos (p. ej. desde administración).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnlockUser {
+    pub user_id: Uuid,
+}
+
+/// Inicio de sesión con email y contraseña. No forma parte de `UserCommand`,
+/// igual que los comandos de restablecimiento: son flujos de credenciales con
+/// respuestas propias.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticateUser {
+    pub email: String,
+    pub password: String,
+    /// IP del terminal, para los contadores por IP.
+    #[serde(default)]
+    pub ip_address: Option<String>,
+    /// Se guarda en la sesión que abre `UserService::login`.
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+/// Solicita un enlace para restablecer la contraseña.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestPasswordReset {
+    pub email: String,
+}
+
+/// Cambia la contraseña con el token recibido por correo.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResetPassword {
+    pub email: String,
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Punto de entrada único para quien despacha comandos serializados
+/// (p. ej. un comando de Tauri): `{"type": "verify_email", "user_id": ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UserCommand {
+    RegisterUser(RegisterUser),
+    VerifyEmail(VerifyEmail),
+    ChangeEmail(ChangeEmail),
+    AssignUsername(AssignUsername),
+    AssignPhone(AssignPhone),
+    VerifyPhone(VerifyPhone),
+    ActivateUser(ActivateUser),
+    SuspendUser(SuspendUser),
+    DeleteUser(DeleteUser),
+    AssignRole(AssignRole),
+    RevokeRole(RevokeRole),
+    ChangePassword(ChangePassword),
+    UnlockUser(UnlockUser),
+}
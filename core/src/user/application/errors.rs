@@ -0,0 +1,74 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::user::domain::repositories::RepositoryError;
+use crate::user::domain::services::RegistrationError;
+use crate::user::domain::validations::UserDomainError;
+
+/// Errores expuestos por los casos de uso del contexto `User`.
+/// Compone los errores de dominio y de persistencia sin perder su origen.
+#[derive(Debug)]
+pub enum UserApplicationError {
+    Domain(UserDomainError),
+    Repository(RepositoryError),
+    Registration(RegistrationError),
+}
+
+impl UserApplicationError {
+    /// Código estable y legible por máquinas (delegado a la capa de origen).
+    pub fn code(&self) -> String {
+        match self {
+            UserApplicationError::Domain(err) => err.code(),
+            UserApplicationError::Repository(err) => err.code(),
+            UserApplicationError::Registration(err) => err.code(),
+        }
+    }
+
+    pub fn as_domain(&self) -> Option<&UserDomainError> {
+        match self {
+            UserApplicationError::Domain(err) => Some(err),
+            UserApplicationError::Repository(RepositoryError::Domain(err)) => Some(err),
+            UserApplicationError::Registration(RegistrationError::InvalidField(err)) => Some(err),
+            UserApplicationError::Registration(RegistrationError::Repository(RepositoryError::Domain(err))) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UserApplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserApplicationError::Domain(err) => write!(f, "{err}"),
+            UserApplicationError::Repository(err) => write!(f, "{err}"),
+            UserApplicationError::Registration(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl StdError for UserApplicationError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            UserApplicationError::Domain(err) => Some(err),
+            UserApplicationError::Repository(err) => Some(err),
+            UserApplicationError::Registration(err) => Some(err),
+        }
+    }
+}
+
+impl From<UserDomainError> for UserApplicationError {
+    fn from(err: UserDomainError) -> Self {
+        UserApplicationError::Domain(err)
+    }
+}
+
+impl From<RepositoryError> for UserApplicationError {
+    fn from(err: RepositoryError) -> Self {
+        UserApplicationError::Repository(err)
+    }
+}
+
+impl From<RegistrationError> for UserApplicationError {
+    fn from(err: RegistrationError) -> Self {
+        UserApplicationError::Registration(err)
+    }
+}
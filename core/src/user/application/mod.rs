@@ -1 +1,11 @@
+pub mod commands;
+pub mod errors;
+pub mod user_builder;
+pub mod user_factory;
+pub mod user_service;
 
+pub use commands::*;
+pub use errors::UserApplicationError;
+pub use user_builder::{UserBuilder, UserValidationErrors};
+pub use user_factory::UserFactory;
+pub use user_service::{IssuedSession, UserService};
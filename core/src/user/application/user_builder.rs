@@ -0,0 +1,305 @@
+use chrono::{DateTime, Utc};
+use std::error::Error as StdError;
+use std::fmt;
+use uuid::Uuid;
+
+use crate::user::domain::{
+    CategoryError, Email, ExternalId, Locale, OccurredAt, Phone, TypeError, User, UserDomainError, UserId,
+    UserSnapshot, UserStatus, Username,
+};
+
+/// Errores de validación de un `UserBuilder`, uno por campo y en el orden del
+/// formulario (`id`, `email`, `username`, `phone`, `external_id`, `status`,
+/// `version`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserValidationErrors {
+    errors: Vec<(&'static str, UserDomainError)>,
+}
+
+impl UserValidationErrors {
+    fn push(&mut self, field: &'static str, err: UserDomainError) {
+        if self.get(field).is_none() {
+            self.errors.push((field, err));
+        }
+    }
+
+    pub fn get(&self, field: &str) -> Option<&UserDomainError> {
+        self.errors.iter().find(|(name, _)| *name == field).map(|(_, err)| err)
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.errors.iter().map(|(field, _)| *field)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &UserDomainError)> {
+        self.errors.iter().map(|(field, err)| (*field, err))
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Primer error, para quien sólo necesita uno.
+    pub fn first(&self) -> Option<&UserDomainError> {
+        self.errors.first().map(|(_, err)| err)
+    }
+
+    /// `{ "email": { "code": ..., "params": ..., "message": ... }, ... }`
+    pub fn to_json(&self, locale: &Locale) -> serde_json::Value {
+        self.errors.iter().map(|(field, err)| (field.to_string(), err.to_json(locale))).collect()
+    }
+}
+
+impl fmt::Display for UserValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<String> = self.iter().map(|(field, err)| format!("{field}: {}", err.code())).collect();
+        write!(f, "invalid user fields ({})", fields.join(", "))
+    }
+}
+
+impl StdError for UserValidationErrors {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.first().map(|err| err as &(dyn StdError + 'static))
+    }
+}
+
+/// Value objects ya validados.
+struct ValidFields {
+    email: Email,
+    username: Option<Username>,
+    phone: Option<Phone>,
+    external_id: Option<ExternalId>,
+    status: UserStatus,
+}
+
+/// Construye un `User` a partir de datos crudos validando cada campo por
+/// separado, de modo que un formulario recibe todos sus errores de una vez.
+///
+/// - `build_new`: alta nueva; emite `UserRegistered` y un evento por cada dato
+///   adicional, igual que si se hubieran invocado los métodos del agregado.
+/// - `build_restored`: reconstruye un usuario existente sin emitir eventos;
+///   exige `id` y `version`.
+#[derive(Debug, Clone, Default)]
+pub struct UserBuilder {
+    id: Option<Uuid>,
+    email: Option<String>,
+    username: Option<String>,
+    phone: Option<String>,
+    external_id: Option<String>,
+    status: Option<String>,
+    email_verified: bool,
+    phone_verified: bool,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+    deleted_at: Option<DateTime<Utc>>,
+    version: Option<u64>,
+}
+
+impl UserBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sólo se usa al restaurar; un alta nueva genera su propio id.
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn email(mut self, raw: impl Into<String>) -> Self {
+        self.email = Some(raw.into());
+        self
+    }
+
+    pub fn username(mut self, raw: impl Into<String>) -> Self {
+        self.username = Some(raw.into());
+        self
+    }
+
+    /// Formato internacional con el código de país separado del número, p. ej.
+    /// `+57 3001234567`.
+    pub fn phone(mut self, raw: impl Into<String>) -> Self {
+        self.phone = Some(raw.into());
+        self
+    }
+
+    pub fn external_id(mut self, raw: impl Into<String>) -> Self {
+        self.external_id = Some(raw.into());
+        self
+    }
+
+    /// Por defecto `pending`.
+    pub fn status(mut self, raw: impl Into<String>) -> Self {
+        self.status = Some(raw.into());
+        self
+    }
+
+    pub fn email_verified(mut self, verified: bool) -> Self {
+        self.email_verified = verified;
+        self
+    }
+
+    pub fn phone_verified(mut self, verified: bool) -> Self {
+        self.phone_verified = verified;
+        self
+    }
+
+    pub fn created_at(mut self, at: DateTime<Utc>) -> Self {
+        self.created_at = Some(at);
+        self
+    }
+
+    pub fn updated_at(mut self, at: DateTime<Utc>) -> Self {
+        self.updated_at = Some(at);
+        self
+    }
+
+    /// Si se omite para un usuario `deleted`, se usa `updated_at`.
+    pub fn deleted_at(mut self, at: DateTime<Utc>) -> Self {
+        self.deleted_at = Some(at);
+        self
+    }
+
+    /// Eventos aplicados al usuario persistido; sólo se usa al restaurar.
+    pub fn version(mut self, version: u64) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Valida todos los campos sin construir nada.
+    pub fn validate(&self) -> Result<(), UserValidationErrors> {
+        let mut errors = UserValidationErrors::default();
+        self.fields(&mut errors);
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Alta nueva con sus eventos pendientes.
+    ///
+    /// Sólo admite los estados alcanzables desde el alta sin pasos intermedios:
+    /// `pending` y `active`.
+    pub fn build_new(self) -> Result<User, UserValidationErrors> {
+        let mut errors = UserValidationErrors::default();
+        let fields = self.fields(&mut errors);
+
+        if let Some(fields) = &fields && !matches!(fields.status, UserStatus::Pending | UserStatus::Active) {
+            errors.push(
+                "status",
+                (CategoryError::Status, TypeError::Transition { from: UserStatus::Pending, to: fields.status.clone() }).into(),
+            );
+        }
+        if self.phone_verified && self.phone.is_none() {
+            errors.push("phone", (CategoryError::Phone, TypeError::Missing).into());
+        }
+
+        let Some(fields) = fields.filter(|_| errors.is_empty()) else {
+            return Err(errors);
+        };
+
+        let mut user = User::register(fields.email);
+        let mut apply = |field: &'static str, result: Result<(), UserDomainError>| {
+            if let Err(err) = result {
+                errors.push(field, err);
+            }
+        };
+
+        if let Some(username) = fields.username {
+            apply("username", user.assign_username(username));
+        }
+        if let Some(phone) = fields.phone {
+            apply("phone", user.assign_phone(phone));
+        }
+        if let Some(external_id) = fields.external_id {
+            apply("external_id", user.link_external_id(external_id));
+        }
+        if self.email_verified {
+            apply("email", user.verify_email());
+        }
+        if self.phone_verified {
+            apply("phone", user.verify_phone());
+        }
+        if fields.status == UserStatus::Active {
+            apply("status", user.activate());
+        }
+
+        if errors.is_empty() { Ok(user) } else { Err(errors) }
+    }
+
+    /// Reconstruye un usuario persistido, sin eventos.
+    ///
+    /// Requiere `id` y una `version` de al menos 1: todo usuario guardado tiene
+    /// como mínimo su alta, y con 0 el repositorio lo trataría como nuevo.
+    pub fn build_restored(self) -> Result<User, UserValidationErrors> {
+        let mut errors = UserValidationErrors::default();
+        if self.id.is_none() {
+            errors.push("id", (CategoryError::Id, TypeError::Missing).into());
+        }
+        let fields = self.fields(&mut errors);
+        let version = self.version.filter(|version| *version >= 1);
+        if version.is_none() {
+            errors.push("version", (CategoryError::Version, TypeError::Missing).into());
+        }
+
+        let (Some(id), Some(fields), Some(version)) = (self.id, fields.filter(|_| errors.is_empty()), version) else {
+            return Err(errors);
+        };
+
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
+        let updated_at = self.updated_at.unwrap_or(created_at);
+        let deleted_at = match (&fields.status, self.deleted_at) {
+            (UserStatus::Deleted, at) => Some(at.unwrap_or(updated_at)),
+            (_, at) => at,
+        };
+
+        User::restore(UserSnapshot {
+            id: UserId::from_uuid(id),
+            external_id: fields.external_id,
+            username: fields.username,
+            email: fields.email,
+            email_verified: self.email_verified,
+            phone: fields.phone,
+            phone_verified: self.phone_verified,
+            status: fields.status,
+            created_at: OccurredAt::from_datetime(created_at),
+            updated_at: OccurredAt::from_datetime(updated_at),
+            deleted_at: deleted_at.map(OccurredAt::from_datetime),
+            version,
+        })
+        .map_err(|err| {
+            let field = match err.category() {
+                CategoryError::Phone => "phone",
+                CategoryError::Version => "version",
+                _ => "status",
+            };
+            errors.push(field, err);
+            errors
+        })
+    }
+
+    /// Convierte cada campo a su value object y acumula los fallos.
+    fn fields(&self, errors: &mut UserValidationErrors) -> Option<ValidFields> {
+        fn check<T>(errors: &mut UserValidationErrors, field: &'static str, result: Result<T, UserDomainError>) -> Option<T> {
+            result.map_err(|err| errors.push(field, err)).ok()
+        }
+
+        let email = match self.email.as_deref() {
+            Some(raw) => check(errors, "email", Email::try_from(raw)),
+            None => check(errors, "email", Err((CategoryError::Email, TypeError::Missing).into())),
+        };
+        let username = check(errors, "username", self.username.as_deref().map(Username::try_from).transpose());
+        let phone = check(errors, "phone", self.phone.as_deref().map(str::parse::<Phone>).transpose());
+        let external_id = check(errors, "external_id", self.external_id.as_deref().map(ExternalId::try_from).transpose());
+        let status = check(errors, "status", self.status.as_deref().map(UserStatus::try_from).transpose());
+
+        Some(ValidFields {
+            email: email?,
+            username: username?,
+            phone: phone?,
+            external_id: external_id?,
+            status: status?.unwrap_or(UserStatus::Pending),
+        })
+    }
+}
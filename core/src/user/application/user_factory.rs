@@ -1,42 +1,39 @@
 use uuid::Uuid;
-use std::convert::TryFrom;
-use crate::user::{self, domain::{
-    Email, ExternalId, Phone, User, UserStatus, ValidationError
-}};
+
+use crate::user::application::UserBuilder;
+use crate::user::domain::{User, UserDomainError};
 
 pub struct UserFactory;
 
 impl UserFactory {
+    /// Reconstruye un `User` existente a partir de datos crudos (sin emitir eventos).
+    ///
+    /// `version` es la del usuario persistido (al menos 1). Devuelve sólo el
+    /// primer error; para obtenerlos todos, usar `UserBuilder`.
     pub fn create(
-    id: Uuid,
-    email_raw: &str,
-    username_raw: Option<&str>,
-    phone_raw: Option<&str>,
-    external_id_raw: Option<&str>,
-    status_raw: Option<&str>,
-    ) -> Result<User, ValidationError> {
-        let email = Email::try_from(email_raw)?;
-
-        let username = match username_raw {
-            Some(v) => Some(user::domain::Username::try_from(v)?),
-            None => None,
-        };
-
-        let phone = match phone_raw {
-            Some(v) => Some(Phone::try_from(v)?),
-            None => None,
-        };
-
-        let external_id = match external_id_raw {
-            Some(v) => Some(ExternalId::try_from(v)?),
-            None => None,
-        };
+        id: Uuid,
+        version: u64,
+        email_raw: &str,
+        username_raw: Option<&str>,
+        phone_raw: Option<&str>,
+        external_id_raw: Option<&str>,
+        status_raw: Option<&str>,
+    ) -> Result<User, UserDomainError> {
+        let mut builder = UserBuilder::new().id(id).version(version).email(email_raw);
 
-        let status = match status_raw {
-            Some(v) => Some(UserStatus::try_from(v)?),
-            None => None,
-        };
+        if let Some(raw) = username_raw {
+            builder = builder.username(raw);
+        }
+        if let Some(raw) = phone_raw {
+            builder = builder.phone(raw);
+        }
+        if let Some(raw) = external_id_raw {
+            builder = builder.external_id(raw);
+        }
+        if let Some(raw) = status_raw {
+            builder = builder.status(raw);
+        }
 
-        Ok(User::new(id, email, username, phone, status.unwrap_or(UserStatus::Pending), external_id))
+        builder.build_restored().map_err(|errors| errors.first().cloned().expect("al menos un error"))
     }
 }
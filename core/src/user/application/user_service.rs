@@ -0,0 +1,625 @@
+use std::net::IpAddr;
+use std::sync::{Arc, OnceLock};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::user::application::commands::*;
+use crate::user::application::UserApplicationError;
+use crate::user::domain::{
+    aggregates::{LoadOptions, UserAggregate},
+    entities::{User, UserRole},
+    repositories::{RepositoryError, UnitOfWork, UnitOfWorkFactory},
+    services::{
+        AccessToken, AccessTokenClaims, AccessTokenCodec, AuthenticationService, BreachedPasswordSource, Clock,
+        LockoutPolicy, LoginThrottle, PasswordHasher, PasswordHistoryPolicy, PasswordResetNotifier, PasswordResetPolicy,
+        PasswordResetService,
+        RegistrationPolicy, RegistrationProfile, RegistrationRequest, SessionPolicy, SessionService, SystemClock,
+        UserRegistrationService,
+    },
+    validations::{CategoryError, TypeError, UserDomainError},
+    vo::{ConsentType, Email, Locale, PasswordPolicy, Phone, PlainPassword, RoleName, Timezone, UserId, Username},
+};
+
+/// Contraseña del hash señuelo de `verify_credentials`.
+const DECOY_PASSWORD: &str = "decoy-password-for-unknown-accounts";
+
+/// Sesión abierta por `UserService::login`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuedSession {
+    pub user_id: Uuid,
+    pub session_id: Uuid,
+    pub access_token: AccessToken,
+    pub session_expires_at: DateTime<Utc>,
+}
+
+/// Manejador de comandos del contexto `User`.
+///
+/// Cada comando sigue el mismo ciclo dentro de una unidad de trabajo: cargar
+/// el agregado, invocar el método de dominio, guardarlo y confirmar, lo que
+/// publica sus eventos.
+pub struct UserService {
+    uow: Arc<dyn UnitOfWorkFactory>,
+    hasher: Arc<dyn PasswordHasher>,
+    registration: UserRegistrationService,
+    lockout: LockoutPolicy,
+    throttle: Option<Arc<dyn LoginThrottle>>,
+    reset: PasswordResetPolicy,
+    notifier: Option<Arc<dyn PasswordResetNotifier>>,
+    history: PasswordHistoryPolicy,
+    breached: Option<Arc<dyn BreachedPasswordSource>>,
+    sessions: SessionPolicy,
+    tokens: Option<Arc<dyn AccessTokenCodec>>,
+    clock: Arc<dyn Clock>,
+    decoy_hash: OnceLock<String>,
+}
+
+impl UserService {
+    pub fn new(uow: Arc<dyn UnitOfWorkFactory>, hasher: Arc<dyn PasswordHasher>) -> Self {
+        let registration = UserRegistrationService::new(uow.clone(), hasher.clone());
+        Self {
+            uow,
+            hasher,
+            registration,
+            lockout: LockoutPolicy::default(),
+            throttle: None,
+            reset: PasswordResetPolicy::default(),
+            notifier: None,
+            history: PasswordHistoryPolicy::default(),
+            breached: None,
+            sessions: SessionPolicy::default(),
+            tokens: None,
+            clock: Arc::new(SystemClock),
+            decoy_hash: OnceLock::new(),
+        }
+    }
+
+    pub fn with_registration_policy(mut self, policy: RegistrationPolicy) -> Self {
+        self.registration = self.registration.with_policy(policy);
+        self
+    }
+
+    /// Requisitos de las contraseñas nuevas (alta, cambio y restablecimiento).
+    /// Forma parte de la política de alta: aplicarlo después de
+    /// `with_registration_policy`.
+    pub fn with_password_policy(mut self, policy: PasswordPolicy) -> Self {
+        let registration = RegistrationPolicy { password: policy, ..self.registration.policy().clone() };
+        self.registration = self.registration.with_policy(registration);
+        self
+    }
+
+    /// Rechaza contraseñas nuevas que aparezcan en `source`.
+    pub fn with_breached_passwords(mut self, source: Arc<dyn BreachedPasswordSource>) -> Self {
+        self.registration = self.registration.with_breached_passwords(source.clone());
+        self.breached = Some(source);
+        self
+    }
+
+    pub fn with_lockout_policy(mut self, policy: LockoutPolicy) -> Self {
+        self.lockout = policy;
+        self
+    }
+
+    pub fn with_password_reset_policy(mut self, policy: PasswordResetPolicy) -> Self {
+        self.reset = policy;
+        self
+    }
+
+    /// Entrega de los tokens de restablecimiento; sin ella
+    /// `request_password_reset` falla con `reset_token.not_supported`.
+    pub fn with_password_reset_notifier(mut self, notifier: Arc<dyn PasswordResetNotifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    pub fn with_password_history_policy(mut self, policy: PasswordHistoryPolicy) -> Self {
+        self.history = policy;
+        self
+    }
+
+    pub fn with_session_policy(mut self, policy: SessionPolicy) -> Self {
+        self.sessions = policy;
+        self
+    }
+
+    /// Firma de los tokens de acceso; sin ella `login` y la validación de
+    /// tokens fallan con `session.not_supported`.
+    pub fn with_access_tokens(mut self, codec: Arc<dyn AccessTokenCodec>) -> Self {
+        self.tokens = Some(codec);
+        self
+    }
+
+    /// Hora usada para bloqueos, restablecimientos, historial y sesiones.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Activa los contadores por IP de `authenticate`; sin él sólo se
+    /// bloquean cuentas.
+    pub fn with_login_throttle(mut self, throttle: Arc<dyn LoginThrottle>) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Despacha un comando serializado; devuelve el id del usuario afectado.
+    pub async fn handle(&self, command: UserCommand) -> Result<Uuid, UserApplicationError> {
+        match command {
+            UserCommand::RegisterUser(cmd) => self.register_user(cmd).await,
+            UserCommand::VerifyEmail(cmd) => self.verify_email(cmd).await,
+            UserCommand::ChangeEmail(cmd) => self.change_email(cmd).await,
+            UserCommand::AssignUsername(cmd) => self.assign_username(cmd).await,
+            UserCommand::AssignPhone(cmd) => self.assign_phone(cmd).await,
+            UserCommand::VerifyPhone(cmd) => self.verify_phone(cmd).await,
+            UserCommand::ActivateUser(cmd) => self.activate_user(cmd).await,
+            UserCommand::SuspendUser(cmd) => self.suspend_user(cmd).await,
+            UserCommand::DeleteUser(cmd) => self.delete_user(cmd).await,
+            UserCommand::AssignRole(cmd) => self.assign_role(cmd).await,
+            UserCommand::RevokeRole(cmd) => self.revoke_role(cmd).await,
+            UserCommand::ChangePassword(cmd) => self.change_password(cmd).await,
+            UserCommand::UnlockUser(cmd) => self.unlock_user(cmd).await,
+        }
+    }
+
+    pub async fn register_user(&self, cmd: RegisterUser) -> Result<Uuid, UserApplicationError> {
+        let consents = cmd
+            .consents
+            .iter()
+            .map(|raw| ConsentType::try_from(raw.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ip_address = cmd
+            .ip_address
+            .as_deref()
+            .map(|raw| {
+                raw.parse::<IpAddr>()
+                    .map_err(|_| UserDomainError::from((CategoryError::Consent, TypeError::Format { format: "ip".into() })))
+            })
+            .transpose()?;
+
+        let has_profile = cmd.first_name.is_some()
+            || cmd.last_name.is_some()
+            || cmd.display_name.is_some()
+            || cmd.locale.is_some()
+            || cmd.timezone.is_some();
+        let profile = if has_profile {
+            Some(RegistrationProfile {
+                first_name: cmd.first_name,
+                last_name: cmd.last_name,
+                display_name: cmd.display_name,
+                locale: cmd.locale.as_deref().map(Locale::try_from).transpose()?,
+                timezone: cmd.timezone.as_deref().map(Timezone::try_from).transpose()?,
+            })
+        } else {
+            None
+        };
+
+        let aggregate = self
+            .registration
+            .register_user(RegistrationRequest {
+                email: cmd.email,
+                username: cmd.username,
+                password: cmd.password,
+                consents,
+                profile,
+                ip_address,
+                user_agent: cmd.user_agent,
+            })
+            .await?;
+
+        Ok(aggregate.id())
+    }
+
+    pub async fn verify_email(&self, cmd: VerifyEmail) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.verify_email()?)).await
+    }
+
+    pub async fn change_email(&self, cmd: ChangeEmail) -> Result<Uuid, UserApplicationError> {
+        let email = Email::try_from(cmd.new_email.as_str())?;
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.update_email(email)?)).await
+    }
+
+    pub async fn assign_username(&self, cmd: AssignUsername) -> Result<Uuid, UserApplicationError> {
+        let username = Username::try_from(cmd.username.as_str())?;
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.assign_username(username)?)).await
+    }
+
+    pub async fn assign_phone(&self, cmd: AssignPhone) -> Result<Uuid, UserApplicationError> {
+        let phone = cmd.phone.parse::<Phone>()?;
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.assign_phone(phone)?)).await
+    }
+
+    pub async fn verify_phone(&self, cmd: VerifyPhone) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.verify_phone()?)).await
+    }
+
+    pub async fn activate_user(&self, cmd: ActivateUser) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.activate()?)).await
+    }
+
+    pub async fn suspend_user(&self, cmd: SuspendUser) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| Ok(aggregate.user.suspend()?)).await
+    }
+
+    /// Borrado lógico; un token de restablecimiento pendiente deja de valer.
+    pub async fn delete_user(&self, cmd: DeleteUser) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| {
+            aggregate.user.delete()?;
+            if let Some(password) = aggregate.password.as_mut() {
+                password.clear_reset_token();
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Asigna un rol por nombre. Una asignación revocada o vencida del mismo
+    /// rol se reactiva en lugar de duplicarse.
+    pub async fn assign_role(&self, cmd: AssignRole) -> Result<Uuid, UserApplicationError> {
+        let name = RoleName::try_from(cmd.role.as_str())?;
+        let uow = self.uow.begin().await?;
+        let role = uow
+            .roles()
+            .get_by_name(&name)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "role", id: name.to_string() })?;
+
+        Self::apply(uow, cmd.user_id, |aggregate| {
+            let uid = aggregate.id();
+            match aggregate.roles.iter_mut().find(|assigned| assigned.role_id == role.role_id) {
+                Some(assigned) if assigned.is_valid() => {
+                    return Err(UserDomainError::from((CategoryError::UserRole, TypeError::Unchanged { value: name.to_string() })).into());
+                }
+                Some(assigned) => {
+                    // Reutiliza la fila; `UserRole::new` valida la nueva expiración
+                    let renewed = UserRole::new(assigned.user_role_id, uid, role.role_id, cmd.granted_by, cmd.expires_at, None, None)?;
+                    *assigned = renewed;
+                }
+                None => {
+                    aggregate.add_role(UserRole::new(Uuid::new_v4(), uid, role.role_id, cmd.granted_by, cmd.expires_at, None, None)?);
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn revoke_role(&self, cmd: RevokeRole) -> Result<Uuid, UserApplicationError> {
+        let name = RoleName::try_from(cmd.role.as_str())?;
+        let uow = self.uow.begin().await?;
+        let role = uow
+            .roles()
+            .get_by_name(&name)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "role", id: name.to_string() })?;
+
+        Self::apply(uow, cmd.user_id, |aggregate| {
+            let assigned = aggregate
+                .roles
+                .iter_mut()
+                .find(|assigned| assigned.role_id == role.role_id && assigned.is_active)
+                .ok_or_else(|| UserDomainError::from((CategoryError::UserRole, TypeError::Missing)))?;
+            assigned.revoke();
+            Ok(())
+        })
+        .await
+    }
+
+    /// Cambia la contraseña tras comprobar la actual.
+    pub async fn change_password(&self, cmd: ChangePassword) -> Result<Uuid, UserApplicationError> {
+        let hasher = self.hasher.clone();
+
+        self.update(cmd.user_id, |aggregate| {
+            let password = aggregate
+                .password
+                .as_mut()
+                .ok_or_else(|| UserDomainError::from((CategoryError::Password, TypeError::Missing)))?;
+            if !hasher.verify(&cmd.current_password, &password.password_hash)? {
+                return Err(UserDomainError::from((CategoryError::Password, TypeError::Mismatch)).into());
+            }
+            let now = self.clock.now();
+            self.history.check_min_age(password, now)?;
+            let new_password = self.new_password(&cmd.new_password, &aggregate.user)?;
+            self.history.replace(password, &new_password, hasher.as_ref(), now)?;
+            aggregate.user.record_password_changed(false);
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn unlock_user(&self, cmd: UnlockUser) -> Result<Uuid, UserApplicationError> {
+        self.update(cmd.user_id, |aggregate| {
+            let password = aggregate
+                .password
+                .as_mut()
+                .ok_or_else(|| UserDomainError::from((CategoryError::Password, TypeError::Missing)))?;
+            Ok(AuthenticationService::unlock(&mut aggregate.user, password)?)
+        })
+        .await
+    }
+
+    /// Verifica email y contraseña y devuelve el id del usuario.
+    ///
+    /// - Un email desconocido responde igual que una contraseña incorrecta
+    ///   (`password.mismatch`).
+    /// - Una cuenta o IP bloqueada responde `password.locked`, con la hora de
+    ///   desbloqueo en `until`.
+    /// - Una cuenta suspendida o borrada responde `status.invalid_status` sólo
+    ///   si la contraseña es correcta; si no, `password.mismatch`, para no
+    ///   revelar el estado de la cuenta.
+    /// - Si el hash guardado usa un algoritmo o parámetros antiguos, se
+    ///   recalcula con el hasher actual.
+    ///
+    /// Los intentos fallidos también se guardan: cuentan para el bloqueo. Si
+    /// otro intento sobre la misma cuenta se guardó a la vez, falla con
+    /// `repository.concurrency` en lugar de perder ese fallo.
+    pub async fn authenticate(&self, cmd: AuthenticateUser) -> Result<Uuid, UserApplicationError> {
+        let ip = cmd
+            .ip_address
+            .as_deref()
+            .map(|raw| {
+                raw.parse::<IpAddr>()
+                    .map_err(|_| UserDomainError::from((CategoryError::Session, TypeError::Format { format: "ip".into() })))
+            })
+            .transpose()?;
+        let throttle = self.throttle.as_deref().zip(ip);
+        let now = self.clock.now();
+
+        if let Some((throttle, ip)) = throttle {
+            self.lockout.check_ip(throttle, ip, now)?;
+        }
+
+        let result = self.verify_credentials(&cmd, now).await;
+        if let (Err(err), Some((throttle, ip))) = (&result, throttle)
+            && err.as_domain().is_some_and(|err| {
+                *err.category() == CategoryError::Password
+                    && matches!(err.detail(), TypeError::Mismatch | TypeError::Locked { .. })
+            })
+        {
+            self.lockout.register_ip_failure(throttle, ip, now);
+        }
+        result
+    }
+
+    async fn verify_credentials(&self, cmd: &AuthenticateUser, now: DateTime<Utc>) -> Result<Uuid, UserApplicationError> {
+        let mismatch = || UserApplicationError::from(UserDomainError::from((CategoryError::Password, TypeError::Mismatch)));
+        let email = Email::try_from(cmd.email.as_str()).map_err(|_| mismatch())?;
+
+        let mut uow = self.uow.begin().await?;
+        let Some(user) = uow.users().get_by_email(&email).await? else {
+            self.verify_decoy(&cmd.password);
+            return Err(mismatch());
+        };
+        let user_id = user.id().as_uuid();
+        let mut aggregate = Self::load(uow.as_ref(), user_id).await?;
+        let Some(password) = aggregate.password.as_mut() else {
+            self.verify_decoy(&cmd.password);
+            return Err(mismatch());
+        };
+
+        let before = password.clone();
+        let result = AuthenticationService::authenticate_with_password(
+            &mut aggregate.user,
+            &cmd.password,
+            password,
+            self.hasher.as_ref(),
+            &self.lockout,
+            now,
+        );
+        let changed = *password != before || !aggregate.user.pending_events().is_empty();
+
+        if changed {
+            uow.save_aggregate(&mut aggregate).await?;
+            uow.commit().await?;
+        }
+        result.map(|_| user_id).map_err(Into::into)
+    }
+
+    /// Emite un token de restablecimiento y lo entrega con el
+    /// `PasswordResetNotifier` antes de confirmar.
+    ///
+    /// Para no revelar qué emails existen, responde `Ok` también cuando el
+    /// email no está registrado, la cuenta está borrada o se superó el límite
+    /// de solicitudes; en esos casos no se emite nada. Si la entrega falla
+    /// (`reset_token.invalid_state`) la solicitud se descarta entera, así que
+    /// puede repetirse enseguida.
+    pub async fn request_password_reset(&self, cmd: RequestPasswordReset) -> Result<(), UserApplicationError> {
+        let notifier = self
+            .notifier
+            .as_deref()
+            .ok_or_else(|| UserDomainError::from((CategoryError::ResetToken, TypeError::NotSupported)))?;
+        let email = Email::try_from(cmd.email.as_str())?;
+
+        let mut uow = self.uow.begin().await?;
+        let Some(user) = uow.users().get_by_email(&email).await? else {
+            return Ok(());
+        };
+        let mut aggregate = Self::load(uow.as_ref(), user.id().as_uuid()).await?;
+        let Some(password) = aggregate.password.as_mut() else {
+            return Ok(());
+        };
+
+        let now = self.clock.now();
+        let Ok(token) = PasswordResetService::request(&mut aggregate.user, password, &self.reset, now) else {
+            return Ok(());
+        };
+        let expires_at = now + self.reset.token_ttl;
+        uow.save_aggregate(&mut aggregate).await?;
+        if notifier.send_reset_token(aggregate.user.email(), &token, expires_at).await.is_err() {
+            let state = "delivery_failed".to_string();
+            return Err(UserDomainError::from((CategoryError::ResetToken, TypeError::InvalidState { state })).into());
+        }
+        uow.commit().await?;
+        Ok(())
+    }
+
+    /// Cambia la contraseña con un token de `request_password_reset` y cierra
+    /// todas las sesiones del usuario.
+    pub async fn reset_password(&self, cmd: ResetPassword) -> Result<Uuid, UserApplicationError> {
+        let invalid = || UserApplicationError::from(UserDomainError::from((CategoryError::ResetToken, TypeError::Mismatch)));
+        let email = Email::try_from(cmd.email.as_str()).map_err(|_| invalid())?;
+
+        let mut uow = self.uow.begin().await?;
+        let Some(user) = uow.users().get_by_email(&email).await? else {
+            return Err(invalid());
+        };
+        let user_id = user.id().as_uuid();
+        let mut aggregate = uow
+            .aggregates()
+            .load(user.id(), LoadOptions::all())
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "user", id: user_id.to_string() })?;
+        let new_password = self.new_password(&cmd.new_password, &aggregate.user)?;
+        let Some(password) = aggregate.password.as_mut() else {
+            return Err(invalid());
+        };
+
+        PasswordResetService::reset(
+            &mut aggregate.user,
+            password,
+            &mut aggregate.sessions,
+            &cmd.token,
+            &new_password,
+            self.hasher.as_ref(),
+            &self.history,
+            self.clock.now(),
+        )?;
+        uow.save_aggregate(&mut aggregate).await?;
+        uow.commit().await?;
+        Ok(user_id)
+    }
+
+    /// Autentica como `authenticate` y abre una sesión con su token de acceso.
+    ///
+    /// Las cuentas suspendidas o borradas no pueden abrir sesión
+    /// (`status.invalid_status`).
+    pub async fn login(&self, cmd: AuthenticateUser) -> Result<IssuedSession, UserApplicationError> {
+        let tokens = self.tokens()?;
+        let (ip_address, user_agent) = (cmd.ip_address.clone(), cmd.user_agent.clone());
+        let user_id = self.authenticate(cmd).await?;
+        let now = self.clock.now();
+
+        let mut uow = self.uow.begin().await?;
+        let mut aggregate = Self::load_with_sessions(uow.as_ref(), user_id).await?;
+        let session = SessionService::open(&aggregate.user, &self.sessions, ip_address, user_agent, now)?;
+        let access_token = SessionService::issue(&session, tokens, &self.sessions, now)?;
+        let issued = IssuedSession {
+            user_id,
+            session_id: session.session_id,
+            access_token,
+            session_expires_at: session.expires_at,
+        };
+
+        aggregate.sessions.push(session);
+        uow.save_aggregate(&mut aggregate).await?;
+        uow.commit().await?;
+        Ok(issued)
+    }
+
+    /// Verifica firma, caducidad y vigencia de un token de acceso y devuelve
+    /// sus claims.
+    ///
+    /// Falla con `session.invalid_format` si el token no es válido,
+    /// `session.expired` si caducó y `session.revoked` si su sesión se cerró o
+    /// sus tokens se invalidaron.
+    pub async fn validate_access_token(&self, token: &str) -> Result<AccessTokenClaims, UserApplicationError> {
+        let claims = self.tokens()?.decode(token)?;
+        let uow = self.uow.begin().await?;
+        let aggregate = Self::load_with_sessions(uow.as_ref(), claims.sub).await.map_err(|err| match err {
+            UserApplicationError::Repository(RepositoryError::NotFound { .. }) => {
+                UserDomainError::from((CategoryError::Session, TypeError::Revoked)).into()
+            }
+            other => other,
+        })?;
+
+        SessionService::verify(&claims, &aggregate.user, &aggregate.sessions, self.clock.now())?;
+        Ok(claims)
+    }
+
+    /// Cierra la sesión del token; sus tokens dejan de valer.
+    pub async fn logout(&self, token: &str) -> Result<Uuid, UserApplicationError> {
+        let claims = self.validate_access_token(token).await?;
+
+        let mut uow = self.uow.begin().await?;
+        let mut aggregate = Self::load_with_sessions(uow.as_ref(), claims.sub).await?;
+        if let Some(session) = aggregate.sessions.iter_mut().find(|session| session.session_id == claims.sid) {
+            SessionService::logout(session);
+        }
+        uow.save_aggregate(&mut aggregate).await?;
+        uow.commit().await?;
+        Ok(claims.sub)
+    }
+
+    /// Cierra todas las sesiones del usuario del token; devuelve cuántas.
+    pub async fn logout_everywhere(&self, token: &str) -> Result<usize, UserApplicationError> {
+        let claims = self.validate_access_token(token).await?;
+
+        let mut uow = self.uow.begin().await?;
+        let mut aggregate = Self::load_with_sessions(uow.as_ref(), claims.sub).await?;
+        let closed = SessionService::logout_everywhere(&mut aggregate.sessions);
+        uow.save_aggregate(&mut aggregate).await?;
+        uow.commit().await?;
+        Ok(closed)
+    }
+
+    /// Verifica `password` contra un hash del hasher configurado para que una
+    /// cuenta inexistente tarde lo mismo que una contraseña incorrecta. El hash
+    /// se calcula en el primer uso.
+    fn verify_decoy(&self, password: &str) {
+        let decoy = self.decoy_hash.get_or_init(|| self.hasher.hash(DECOY_PASSWORD).unwrap_or_default());
+        let _ = self.hasher.verify(password, decoy);
+    }
+
+    fn tokens(&self) -> Result<&dyn AccessTokenCodec, UserDomainError> {
+        self.tokens
+            .as_deref()
+            .ok_or_else(|| (CategoryError::Session, TypeError::NotSupported).into())
+    }
+
+    /// Valida una contraseña nueva de `user` contra la política y, si está
+    /// configurada, la lista de contraseñas filtradas.
+    fn new_password(&self, raw: &str, user: &User) -> Result<PlainPassword, UserDomainError> {
+        let personal: Vec<&str> = std::iter::once(user.email().as_str()).chain(user.username().map(Username::as_str)).collect();
+        let password = PlainPassword::new(raw, &self.registration.policy().password, &personal)?;
+        if let Some(breached) = &self.breached {
+            breached.check(&password)?;
+        }
+        Ok(password)
+    }
+
+    async fn load(uow: &dyn UnitOfWork, user_id: Uuid) -> Result<UserAggregate, UserApplicationError> {
+        uow.aggregates()
+            .load(&UserId::from_uuid(user_id), LoadOptions::lazy())
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "user", id: user_id.to_string() }.into())
+    }
+
+    /// Como `load`, con las sesiones.
+    async fn load_with_sessions(uow: &dyn UnitOfWork, user_id: Uuid) -> Result<UserAggregate, UserApplicationError> {
+        uow.aggregates()
+            .load(&UserId::from_uuid(user_id), LoadOptions { sessions: true, activity_logs: false })
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "user", id: user_id.to_string() }.into())
+    }
+
+    /// Abre una unidad de trabajo y aplica `change` en ella.
+    async fn update<F>(&self, user_id: Uuid, change: F) -> Result<Uuid, UserApplicationError>
+    where
+        F: FnOnce(&mut UserAggregate) -> Result<(), UserApplicationError>,
+    {
+        let uow = self.uow.begin().await?;
+        Self::apply(uow, user_id, change).await
+    }
+
+    /// Carga, aplica `change`, guarda y confirma. Si algo falla, la unidad se
+    /// suelta sin confirmar y no se escribe nada.
+    async fn apply<F>(mut uow: Box<dyn UnitOfWork>, user_id: Uuid, change: F) -> Result<Uuid, UserApplicationError>
+    where
+        F: FnOnce(&mut UserAggregate) -> Result<(), UserApplicationError>,
+    {
+        let mut aggregate = Self::load(uow.as_ref(), user_id).await?;
+        change(&mut aggregate)?;
+        uow.save_aggregate(&mut aggregate).await?;
+        uow.commit().await?;
+        Ok(user_id)
+    }
+}
@@ -1 +1,3 @@
+pub mod user_aggregate;
 
+pub use user_aggregate::{LoadOptions, UserAggregate};
@@ -1,5 +1,4 @@
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
 
 use crate::user::domain::entities::{
     user::User,
@@ -14,6 +13,34 @@ use crate::user::domain::entities::{
     user_activity_log::UserActivityLog,
 };
 
+/// Colecciones grandes que pueden cargarse de forma diferida.
+///
+/// Una colección no cargada no se compara al guardar: sólo se insertan o
+/// actualizan los elementos añadidos en memoria, nunca se borra nada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadOptions {
+    pub sessions: bool,
+    pub activity_logs: bool,
+}
+
+impl LoadOptions {
+    /// Carga el agregado completo.
+    pub fn all() -> Self {
+        Self { sessions: true, activity_logs: true }
+    }
+
+    /// Omite `sessions` y `activity_logs`.
+    pub fn lazy() -> Self {
+        Self { sessions: false, activity_logs: false }
+    }
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// Aggregate root que representa un Usuario completo con sus datos relacionados.
 /// Todas las operaciones deben realizarse a través de este objeto para mantener
 /// la consistencia en el dominio.
@@ -29,6 +56,8 @@ pub struct UserAggregate {
     pub subscriptions: Vec<UserSubscription>,
     pub gdpr_consents: Vec<UserGdprConsent>,
     pub activity_logs: Vec<UserActivityLog>,
+    loaded: LoadOptions,
+    row_version: u64,
 }
 
 impl UserAggregate {
@@ -45,12 +74,35 @@ impl UserAggregate {
             subscriptions: Vec::new(),
             gdpr_consents: Vec::new(),
             activity_logs: Vec::new(),
+            loaded: LoadOptions::all(),
+            row_version: 0,
         }
     }
 
+    /// Qué colecciones diferidas contienen el estado completo.
+    /// Un agregado nuevo no tiene nada pendiente de cargar.
+    pub fn loaded(&self) -> LoadOptions {
+        self.loaded
+    }
+
+    pub(crate) fn set_loaded(&mut self, loaded: LoadOptions) {
+        self.loaded = loaded;
+    }
+
+    /// Versión de fila con la que se cargó o guardó por última vez; `0` si
+    /// nunca se persistió. A diferencia de `User::version` también avanza
+    /// cuando sólo cambian colecciones hijas.
+    pub fn row_version(&self) -> u64 {
+        self.row_version
+    }
+
+    pub(crate) fn set_row_version(&mut self, row_version: u64) {
+        self.row_version = row_version;
+    }
+
     /// Devuelve el ID único del usuario.
     pub fn id(&self) -> Uuid {
-        self.user.user_id
+        self.user.id().as_uuid()
     }
 
     // --------------------------
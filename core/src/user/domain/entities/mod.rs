@@ -1,3 +1,25 @@
 pub mod user;
+pub mod user_snapshot;
+pub mod role;
+pub mod user_activity_log;
+pub mod user_auth_method;
+pub mod user_gdpr_consent;
+pub mod user_mfa;
+pub mod user_password;
+pub mod user_profile;
+pub mod user_role;
+pub mod user_session;
+pub mod user_subscription;
 
 pub use user::User;
+pub use user_snapshot::UserSnapshot;
+pub use role::Role;
+pub use user_activity_log::UserActivityLog;
+pub use user_auth_method::UserAuthMethod;
+pub use user_gdpr_consent::UserGdprConsent;
+pub use user_mfa::{MfaType, UserMfa};
+pub use user_password::UserPassword;
+pub use user_profile::UserProfile;
+pub use user_role::UserRole;
+pub use user_session::UserSession;
+pub use user_subscription::UserSubscription;
@@ -1,6 +1,7 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use crate::user::domain::vo::{RoleName, ValidationError};
+use crate::user::domain::vo::RoleName;
+use crate::user::domain::validations::UserDomainError;
 
 /// Representa un rol dentro del dominio.
 /// Tiene identidad propia (role_id) y atributos relevantes.
@@ -17,6 +18,7 @@ pub struct Role {
 
 impl Role {
     /// Constructor seguro: valida los campos obligatorios
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         role_id: Uuid,
         name_raw: &str,
@@ -25,7 +27,7 @@ impl Role {
         permissions: Vec<String>,
         is_system: bool,
         created_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         Ok(Self {
             role_id,
             name: RoleName::try_from(name_raw)?, // VO se encarga de validar
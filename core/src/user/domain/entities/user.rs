@@ -7,6 +7,7 @@ use crate::user::domain::vo::{
     UserStatus,
     OccurredAt,
 };
+use crate::user::domain::entities::UserSnapshot;
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
@@ -24,8 +25,21 @@ use crate::user::domain::events::{
     UserDeleted,
     UserUsernameAssigned,
     UserExternalIdLinked,
+    UserExternalIdUnlinked,
+    UserPhoneUnassigned,
+    UserLockedOut,
+    UserUnlocked,
+    UserPasswordChanged,
+    UserPasswordResetRequested,
 };
 
+/// Raíz del contexto `User`.
+///
+/// - Los comandos (`update_email`, `activate`, ...) validan invariantes y
+///   registran un `UserDomainEvent`; el cambio de estado lo hace siempre `apply`.
+/// - `from_events` reconstruye el usuario reproduciendo su historial con el mismo `apply`.
+/// - `version` cuenta los eventos aplicados y sirve para concurrencia optimista.
+#[derive(Debug)]
 pub struct User {
     id: UserId,
     external_id: Option<ExternalId>,
@@ -38,39 +52,192 @@ pub struct User {
     created_at: OccurredAt,
     updated_at: OccurredAt,
     deleted_at: Option<OccurredAt>,
-    pending_events: Vec<Box<UserDomainEvent>>,
+    version: u64,
+    pending_events: Vec<UserDomainEvent>,
 }
 
 impl User {
     pub fn register(email: Email) -> User {
-        let now = OccurredAt::now();
-        let mut user: User = Self {
-            id: UserId::new(),
+        let event = UserRegistered::new(UserId::new(), email);
+        let mut user = Self::from_registered(&event);
+
+        user.record_event(UserDomainEvent::Registered(event));
+
+        user
+    }
+
+    /// Estado inicial definido por `UserRegistered` (versión 0, sin eventos aplicados).
+    fn from_registered(event: &UserRegistered) -> User {
+        Self {
+            id: event.user_id().clone(),
             external_id: None,
             username: None,
-            email,
+            email: event.email().clone(),
             email_verified: false,
             phone: None,
             phone_verified: false,
             status: UserStatus::Pending,
-            created_at: now.clone(),
-            updated_at: now.clone(),
+            created_at: event.occurred_at().clone(),
+            updated_at: event.occurred_at().clone(),
             deleted_at: None,
+            version: 0,
             pending_events: Vec::new(),
+        }
+    }
+
+    /// Reconstruye un usuario reproduciendo su historial completo.
+    /// El primer evento debe ser `UserRegistered` y todos deben pertenecer al mismo usuario.
+    pub fn from_events<I>(events: I) -> Result<User, UserDomainError>
+    where
+        I: IntoIterator<Item = UserDomainEvent>,
+    {
+        let mut events = events.into_iter();
+
+        let mut user = match events.next() {
+            Some(UserDomainEvent::Registered(event)) => Self::from_registered(&event),
+            Some(_) => return Err((CategoryError::Event, TypeError::Missing).into()),
+            None => return Err((CategoryError::Event, TypeError::Empty).into()),
         };
+        user.version = 1;
 
-        let event = UserRegistered::new(user.id.clone(), user.email.clone());
+        for event in events {
+            if event.user_id() != &user.id {
+                return Err((CategoryError::Event, TypeError::Mismatch).into());
+            }
 
-        user.record_event(UserDomainEvent::Registered(event));
+            if matches!(event, UserDomainEvent::Registered(_)) {
+                return Err((CategoryError::Event, TypeError::NotSupported).into());
+            }
 
-        user
+            user.apply(&event);
+            user.version += 1;
+        }
+
+        Ok(user)
+    }
+
+    /// Reconstruye un usuario persistido sin emitir eventos.
+    ///
+    /// Falla con `version.missing` si `version` es 0: todo usuario guardado
+    /// tiene al menos su alta, y en versión 0 los repositorios lo tratarían
+    /// como nuevo.
+    pub fn restore(snapshot: UserSnapshot) -> Result<User, UserDomainError> {
+        if snapshot.version == 0 {
+            return Err((CategoryError::Version, TypeError::Missing).into());
+        }
+
+        if snapshot.phone_verified && snapshot.phone.is_none() {
+            return Err((CategoryError::Phone, TypeError::Missing).into());
+        }
+
+        if snapshot.status.is_deleted() != snapshot.deleted_at.is_some() {
+            return Err((CategoryError::Status, TypeError::InvalidStatus { status: snapshot.status }).into());
+        }
+
+        Ok(Self {
+            id: snapshot.id,
+            external_id: snapshot.external_id,
+            username: snapshot.username,
+            email: snapshot.email,
+            email_verified: snapshot.email_verified,
+            phone: snapshot.phone,
+            phone_verified: snapshot.phone_verified,
+            status: snapshot.status,
+            created_at: snapshot.created_at,
+            updated_at: snapshot.updated_at,
+            deleted_at: snapshot.deleted_at,
+            version: snapshot.version,
+            pending_events: Vec::new(),
+        })
     }
 
-    fn record_event(&mut self, event: UserDomainEvent) {
-        self.pending_events.push(Box::new(event));
+    /// Copia del estado actual, lista para persistir o serializar.
+    /// No incluye los eventos pendientes.
+    pub fn snapshot(&self) -> UserSnapshot {
+        UserSnapshot {
+            id: self.id.clone(),
+            external_id: self.external_id.clone(),
+            username: self.username.clone(),
+            email: self.email.clone(),
+            email_verified: self.email_verified,
+            phone: self.phone.clone(),
+            phone_verified: self.phone_verified,
+            status: self.status.clone(),
+            created_at: self.created_at.clone(),
+            updated_at: self.updated_at.clone(),
+            deleted_at: self.deleted_at.clone(),
+            version: self.version,
+        }
+    }
+
+    /// Aplica un evento al estado. No valida: los eventos son hechos ya aceptados.
+    fn apply(&mut self, event: &UserDomainEvent) {
+        match event {
+            UserDomainEvent::Registered(e) => {
+                self.email = e.email().clone();
+                self.status = UserStatus::Pending;
+                self.created_at = e.occurred_at().clone();
+            }
+            UserDomainEvent::EmailUpdated(e) => {
+                self.email = e.new_email().clone();
+                self.email_verified = false;
+                self.status = UserStatus::Pending;
+            }
+            UserDomainEvent::EmailVerified(_) => {
+                self.email_verified = true;
+            }
+            UserDomainEvent::PhoneAssigned(e) => {
+                self.phone = Some(e.phone().clone());
+                self.phone_verified = false;
+            }
+            UserDomainEvent::PhoneVerified(_) => {
+                self.phone_verified = true;
+            }
+            UserDomainEvent::PhoneUnassigned(_) => {
+                self.phone = None;
+                self.phone_verified = false;
+            }
+            UserDomainEvent::UsernameAssigned(e) => {
+                self.username = Some(e.username().clone());
+            }
+            UserDomainEvent::ExternalIdLinked(e) => {
+                self.external_id = Some(e.external_id().clone());
+            }
+            UserDomainEvent::ExternalIdUnlinked(_) => {
+                self.external_id = None;
+            }
+            UserDomainEvent::Activated(e) => {
+                self.status = e.user_status().clone();
+            }
+            UserDomainEvent::Suspended(e) => {
+                self.status = e.user_status().clone();
+            }
+            UserDomainEvent::Deleted(e) => {
+                self.status = e.user_status().clone();
+                self.deleted_at = Some(e.occurred_at().clone());
+            }
+            // El estado del bloqueo vive en `UserPassword`; aquí sólo queda constancia.
+            UserDomainEvent::LockedOut(_) | UserDomainEvent::Unlocked(_) => {}
+            // Igual con las credenciales: el hash y el token están en `UserPassword`.
+            UserDomainEvent::PasswordResetRequested(_) | UserDomainEvent::PasswordChanged(_) => {}
+        }
+
+        self.updated_at = event.occurred_at();
     }
 
-    pub fn take_events(&mut self) -> Vec<Box<UserDomainEvent>> {
+    fn record_event(&mut self, mut event: UserDomainEvent) {
+        self.apply(&event);
+        self.version += 1;
+        event.metadata_mut().set_aggregate_version(self.version);
+        self.pending_events.push(event);
+    }
+
+    /// Eventos registrados que aún no se han persistido.
+    pub fn pending_events(&self) -> &[UserDomainEvent] {
+        &self.pending_events
+    }
+
+    pub fn take_events(&mut self) -> Vec<UserDomainEvent> {
         std::mem::take(&mut self.pending_events)
     }
 
@@ -118,28 +285,63 @@ impl User {
         self.deleted_at.as_ref()
     }
 
+    /// Versión del agregado: número de eventos aplicados, incluidos los pendientes.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Versión que tenía el agregado al cargarse (antes de los eventos pendientes).
+    pub fn persisted_version(&self) -> u64 {
+        self.version - self.pending_events.len() as u64
+    }
+
+    /// Rechaza cambios sobre usuarios eliminados o suspendidos.
+    fn ensure_mutable(&self, category: CategoryError) -> Result<(), UserDomainError> {
+        if matches!(self.status, UserStatus::Deleted | UserStatus::Suspended) {
+            return Err((category, TypeError::InvalidStatus { status: self.status.clone() }).into());
+        }
+
+        Ok(())
+    }
+
+    /// Vincula (o reemplaza) el identificador del proveedor externo.
+    /// La unicidad global del valor la garantiza el repositorio.
     pub fn link_external_id(&mut self, external_id: ExternalId) -> Result<(), UserDomainError> {
-        self.updated_at = OccurredAt::now();
+        self.ensure_mutable(CategoryError::ExternalId)?;
+
+        if self.external_id.as_ref() == Some(&external_id) {
+            return Err((CategoryError::ExternalId, TypeError::Unchanged { value: format!("external id '{}' is already linked", external_id) }).into());
+        }
 
         let event = UserExternalIdLinked::new(self.id.clone(), external_id);
-        self.record_event(UserDomainEvent::ExternalIdLinkend(event));
+        self.record_event(UserDomainEvent::ExternalIdLinked(event));
+
+        Ok(())
+    }
+
+    pub fn unlink_external_id(&mut self) -> Result<(), UserDomainError> {
+        self.ensure_mutable(CategoryError::ExternalId)?;
+
+        let Some(external_id) = self.external_id.clone() else {
+            return Err((CategoryError::ExternalId, TypeError::Missing).into());
+        };
+
+        let event = UserExternalIdUnlinked::new(self.id.clone(), external_id);
+        self.record_event(UserDomainEvent::ExternalIdUnlinked(event));
 
         Ok(())
     }
 
     pub fn update_email(&mut self, new_email: Email) -> Result<(), UserDomainError> {
+        self.ensure_mutable(CategoryError::Email)?;
+
         let old_email = self.email.clone();
 
         if old_email == new_email {
             return Err((CategoryError::Email,TypeError::Unchanged {value: format!("new email '{}' is equal to old email '{}'", new_email, old_email),},).into());
         }
 
-        self.email = new_email;
-        self.email_verified = false;
-        self.status = UserStatus::Pending;
-        self.updated_at = OccurredAt::now();
-
-        let event = UserEmailUpdated::new(self.id.clone(), old_email, self.email.clone());
+        let event = UserEmailUpdated::new(self.id.clone(), old_email, new_email);
         self.record_event(UserDomainEvent::EmailUpdated(event));
 
         Ok(())
@@ -151,12 +353,7 @@ impl User {
             return Err((CategoryError::Email, TypeError::AlreadyVerified).into());
         }
 
-        if matches!(self.status, UserStatus::Deleted | UserStatus::Suspended) {
-            return Err((CategoryError::Email, TypeError::InvalidStatus { status: self.status().clone() }).into());
-        }
-
-        self.email_verified = true;
-        self.updated_at = OccurredAt::now();
+        self.ensure_mutable(CategoryError::Email)?;
 
         let event = UserEmailVerified::new(self.id.clone(), self.email.clone());
         self.record_event(UserDomainEvent::EmailVerified(event));
@@ -169,11 +366,10 @@ impl User {
             return Err((CategoryError::Phone, TypeError::AlreadyVerified).into());
         }
 
+        self.ensure_mutable(CategoryError::Phone)?;
+
         match self.phone.clone() {
             Some(phone) => {
-                self.phone_verified = true;
-                self.updated_at = OccurredAt::now();
-
                 let event = UserPhoneVerified::new(self.id.clone(), phone);
                 self.record_event(UserDomainEvent::PhoneVerified(event));
 
@@ -186,10 +382,7 @@ impl User {
     pub fn activate(&mut self) -> Result<(), UserDomainError> {
         match self.status {
             UserStatus::Pending | UserStatus::Suspended => {
-                self.status = UserStatus::Active;
-                self.updated_at = OccurredAt::now();
-
-                let event = UserActivated::new(self.id.clone(), self.status.clone());
+                let event = UserActivated::new(self.id.clone(), UserStatus::Active);
                 self.record_event(UserDomainEvent::Activated(event));
 
                 Ok(())
@@ -201,10 +394,7 @@ impl User {
     pub fn suspend(&mut self) -> Result<(), UserDomainError> {
         match self.status {
             UserStatus::Active => {
-                self.status = UserStatus::Suspended;
-                self.updated_at = OccurredAt::now();
-
-                let event = UserSuspended::new(self.id.clone(), self.status.clone());
+                let event = UserSuspended::new(self.id.clone(), UserStatus::Suspended);
                 self.record_event(UserDomainEvent::Suspended(event));
 
                 Ok(())
@@ -216,11 +406,7 @@ impl User {
     pub fn delete(&mut self) -> Result<(), UserDomainError> {
         match self.status {
             UserStatus::Active | UserStatus::Pending | UserStatus::Suspended => {
-                self.status = UserStatus::Deleted;
-                self.deleted_at = Some(OccurredAt::now());
-                self.updated_at = OccurredAt::now();
-
-                let event = UserDeleted::new(self.id.clone(), self.status.clone());
+                let event = UserDeleted::new(self.id.clone(), UserStatus::Deleted);
                 self.record_event(UserDomainEvent::Deleted(event));
 
                 Ok(())
@@ -229,8 +415,38 @@ impl User {
         }
     }
 
+    /// Registra que la contraseña quedó bloqueada hasta `until` tras
+    /// `failed_attempts` fallos. Lo invoca `AuthenticationService`.
+    pub fn record_lockout(&mut self, until: OccurredAt, failed_attempts: u32) {
+        let event = UserLockedOut::new(self.id.clone(), until, failed_attempts);
+        self.record_event(UserDomainEvent::LockedOut(event));
+    }
+
+    /// Registra que se levantó el bloqueo de la contraseña.
+    pub fn record_unlock(&mut self) {
+        let event = UserUnlocked::new(self.id.clone());
+        self.record_event(UserDomainEvent::Unlocked(event));
+    }
+
+    /// Registra la emisión de un token de restablecimiento.
+    pub fn record_password_reset_requested(&mut self, expires_at: OccurredAt) {
+        let event = UserPasswordResetRequested::new(self.id.clone(), expires_at);
+        self.record_event(UserDomainEvent::PasswordResetRequested(event));
+    }
+
+    /// Registra un cambio de contraseña.
+    pub fn record_password_changed(&mut self, via_reset: bool) {
+        let event = UserPasswordChanged::new(self.id.clone(), via_reset);
+        self.record_event(UserDomainEvent::PasswordChanged(event));
+    }
+
+    /// Asigna el nombre de usuario. La unicidad global la garantiza el repositorio.
     pub fn assign_username(&mut self, username: Username) -> Result<(), UserDomainError> {
-        self.updated_at = OccurredAt::now();
+        self.ensure_mutable(CategoryError::Username)?;
+
+        if self.username.as_ref() == Some(&username) {
+            return Err((CategoryError::Username, TypeError::Unchanged { value: format!("username '{}' is already assigned", username) }).into());
+        }
 
         let event = UserUsernameAssigned::new(self.id.clone(), username);
         self.record_event(UserDomainEvent::UsernameAssigned(event));
@@ -238,13 +454,30 @@ impl User {
         Ok(())
     }
 
+    /// Asigna un teléfono nuevo; queda pendiente de verificación.
     pub fn assign_phone(&mut self, phone: Phone) -> Result<(), UserDomainError> {
-        self.phone_verified = false;
-        self.updated_at = OccurredAt::now();
+        self.ensure_mutable(CategoryError::Phone)?;
+
+        if self.phone.as_ref() == Some(&phone) {
+            return Err((CategoryError::Phone, TypeError::Unchanged { value: format!("phone '{}' is already assigned", phone) }).into());
+        }
 
         let event = UserPhoneAssigned::new(self.id.clone(), phone);
         self.record_event(UserDomainEvent::PhoneAssigned(event));
 
         Ok(())
     }
+
+    pub fn unassign_phone(&mut self) -> Result<(), UserDomainError> {
+        self.ensure_mutable(CategoryError::Phone)?;
+
+        let Some(phone) = self.phone.clone() else {
+            return Err((CategoryError::Phone, TypeError::Missing).into());
+        };
+
+        let event = UserPhoneUnassigned::new(self.id.clone(), phone);
+        self.record_event(UserDomainEvent::PhoneUnassigned(event));
+
+        Ok(())
+    }
 }
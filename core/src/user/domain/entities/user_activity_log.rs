@@ -2,7 +2,7 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde_json::Value as JsonValue;
 
-use crate::user::domain::vo::{ValidationError};
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Representa un log de actividad de usuario.
 /// Tiene identidad propia (log_id) y siempre se relaciona con un `user_id`.
@@ -21,6 +21,7 @@ pub struct UserActivityLog {
 
 impl UserActivityLog {
     /// Constructor seguro: solo asegura que `action_type` no esté vacío.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         log_id: Uuid,
         user_id: Uuid,
@@ -31,10 +32,9 @@ impl UserActivityLog {
         success: bool,
         error_details: Option<JsonValue>,
         created_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         if action_type.trim().is_empty() {
-            return Err(ValidationError::InvalidConsentType);
-            // ⚠️ Cambia este error por uno específico si quieres (ej. `InvalidActionType`)
+            return Err((CategoryError::ActivityLog, TypeError::Empty).into());
         }
 
         Ok(Self {
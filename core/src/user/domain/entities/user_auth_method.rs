@@ -1,7 +1,8 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
-use crate::user::domain::vo::{AuthType, ValidationError};
+use crate::user::domain::vo::AuthType;
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Representa un método de autenticación asociado a un usuario.
 /// Ejemplo: password, google, microsoft, etc.
@@ -20,6 +21,7 @@ pub struct UserAuthMethod {
 
 impl UserAuthMethod {
     /// Constructor seguro para crear un nuevo método de autenticación
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         auth_method_id: Uuid,
         user_id: Uuid,
@@ -30,15 +32,12 @@ impl UserAuthMethod {
         is_verified: bool,
         created_at: Option<DateTime<Utc>>,
         last_used_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Reglas de negocio simples:
-        // 1. Si el auth_type es externo (ej: Google), debe haber provider y provider_user_id
-        if matches!(auth_type, AuthType::Google | AuthType::Microsoft | AuthType::Oidc | AuthType::Saml) {
-            if provider.is_none() || provider_user_id.is_none() {
-                return Err(ValidationError::InvalidExternalIdEmpty);
+        // 1. Si el auth_type es externo (OIDC/SAML), debe haber provider y provider_user_id
+        if matches!(auth_type, AuthType::Oidc | AuthType::Saml) && (provider.is_none() || provider_user_id.is_none()) {
+            return Err((CategoryError::AuthMethod, TypeError::Missing).into());
         }
-}
-
 
         Ok(Self {
             auth_method_id,
@@ -2,7 +2,8 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use std::net::IpAddr;
 
-use crate::user::domain::vo::{ConsentType, ValidationError};
+use crate::user::domain::vo::ConsentType;
+use crate::user::domain::validations::UserDomainError;
 
 /// Representa un consentimiento GDPR otorgado por un usuario.
 /// Ejemplo: marketing, analytics, términos y condiciones, etc.
@@ -21,6 +22,7 @@ pub struct UserGdprConsent {
 
 impl UserGdprConsent {
     /// Crea un consentimiento validado
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         consent_id: Uuid,
         user_id: Uuid,
@@ -31,7 +33,7 @@ impl UserGdprConsent {
         user_agent: Option<&str>,
         expires_at: Option<DateTime<Utc>>,
         created_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Regla: Un consentimiento debe tener siempre tipo válido (lo valida el VO).
         // Regla: Si `consent_given` es false, aún así puede registrarse (rechazo explícito).
         Ok(Self {
@@ -1,7 +1,9 @@
 use uuid::Uuid;
+use std::convert::TryFrom;
+use std::str::FromStr;
 use chrono::{DateTime, Utc};
 
-use crate::user::domain::vo::{ValidationError};
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Tipos válidos de MFA.
 /// Si prefieres, esto puede ser un VO en `vo/mfa_type.rs`
@@ -14,13 +16,19 @@ pub enum MfaType {
 }
 
 impl MfaType {
-    pub fn from_str(value: &str) -> Result<Self, ValidationError> {
-        match value.to_lowercase().as_str() {
+    pub(crate) fn new(value: &str) -> Result<Self, UserDomainError> {
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err((CategoryError::Mfa, TypeError::Empty).into());
+        }
+
+        match trimmed.to_ascii_lowercase().as_str() {
             "totp" => Ok(MfaType::Totp),
             "sms" => Ok(MfaType::Sms),
             "email" => Ok(MfaType::Email),
             "webauthn" => Ok(MfaType::WebAuthn),
-            _ => Err(ValidationError::InvalidAuthType), // o crear un ValidationError específico para MFA
+            _ => Err((CategoryError::Mfa, TypeError::NotSupported).into()),
         }
     }
 
@@ -34,6 +42,22 @@ impl MfaType {
     }
 }
 
+impl TryFrom<&str> for MfaType {
+    type Error = UserDomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        MfaType::new(value)
+    }
+}
+
+impl FromStr for MfaType {
+    type Err = UserDomainError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::try_from(value)
+    }
+}
+
 /// Representa un método MFA configurado para un usuario.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UserMfa {
@@ -51,6 +75,7 @@ pub struct UserMfa {
 
 impl UserMfa {
     /// Crea una nueva configuración MFA validada
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         mfa_id: Uuid,
         user_id: Uuid,
@@ -62,11 +87,11 @@ impl UserMfa {
         is_verified: bool,
         created_at: Option<DateTime<Utc>>,
         last_used_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Ejemplo de regla de negocio:
         // TOTP requiere secret_encrypted, SMS/Email no necesariamente
         if matches!(mfa_type, MfaType::Totp) && secret_encrypted.is_none() {
-            return Err(ValidationError::InvalidAuthType);
+            return Err((CategoryError::Mfa, TypeError::Missing).into());
         }
 
         Ok(Self {
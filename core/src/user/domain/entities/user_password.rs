@@ -1,7 +1,7 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
-use crate::user::domain::vo::ValidationError;
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Representa las credenciales de un usuario.
 /// En un sistema real, el hash debe generarse usando librerías seguras
@@ -12,16 +12,29 @@ pub struct UserPassword {
     pub user_id: Uuid,
     pub password_hash: String,
     pub password_salt: Option<String>,
+    /// Hash del token de restablecimiento vigente, nunca el token en claro.
     pub reset_token: Option<String>,
     pub reset_token_expires: Option<DateTime<Utc>>,
+    /// Última solicitud de restablecimiento; se conserva al usar el token
+    /// para limitar las solicitudes por cuenta.
+    pub reset_requested_at: Option<DateTime<Utc>>,
     pub failed_attempts: i32,
+    /// Último intento fallido; marca el inicio de la ventana de bloqueo.
+    pub last_failed_at: Option<DateTime<Utc>>,
     pub locked_until: Option<DateTime<Utc>>,
+    /// Hashes anteriores, del más reciente al más antiguo (ver
+    /// `PasswordHistoryPolicy`).
+    pub password_history: Vec<String>,
+    /// Último cambio de contraseña hecho por el usuario; `None` si conserva
+    /// la del alta.
+    pub password_changed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl UserPassword {
     /// Crea un nuevo `UserPassword` validado.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         password_id: Uuid,
         user_id: Uuid,
@@ -33,10 +46,10 @@ impl UserPassword {
         locked_until: Option<DateTime<Utc>>,
         created_at: Option<DateTime<Utc>>,
         updated_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Validación de seguridad: el hash no puede estar vacío
         if password_hash.trim().is_empty() {
-            return Err(ValidationError::InvalidAuthType); // si quieres, crea un `InvalidPassword`
+            return Err((CategoryError::Password, TypeError::Empty).into());
         }
 
         Ok(Self {
@@ -46,8 +59,12 @@ impl UserPassword {
             password_salt,
             reset_token,
             reset_token_expires,
+            reset_requested_at: None,
             failed_attempts: failed_attempts.unwrap_or(0),
+            last_failed_at: None,
             locked_until,
+            password_history: Vec::new(),
+            password_changed_at: None,
             created_at: created_at.unwrap_or_else(Utc::now),
             updated_at: updated_at.unwrap_or_else(Utc::now),
         })
@@ -55,12 +72,19 @@ impl UserPassword {
 
     /// Incrementa contador de intentos fallidos.
     pub fn register_failed_attempt(&mut self) {
+        self.register_failed_attempt_at(Utc::now());
+    }
+
+    /// Incrementa contador de intentos fallidos con la hora del intento.
+    pub fn register_failed_attempt_at(&mut self, at: DateTime<Utc>) {
         self.failed_attempts += 1;
+        self.last_failed_at = Some(at);
     }
 
     /// Resetea intentos fallidos después de un login exitoso.
     pub fn reset_failed_attempts(&mut self) {
         self.failed_attempts = 0;
+        self.last_failed_at = None;
     }
 
     /// Marca la contraseña como bloqueada hasta una fecha dada.
@@ -68,7 +92,18 @@ impl UserPassword {
         self.locked_until = Some(until);
     }
 
-    /// Genera un token de reseteo de contraseña.
+    /// Quita el bloqueo sin tocar el contador de intentos.
+    pub fn unlock(&mut self) {
+        self.locked_until = None;
+    }
+
+    /// Indica si el bloqueo sigue vigente en `now`.
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+
+    /// Guarda el hash de un token de reseteo de contraseña (ver
+    /// `PasswordResetService`).
     pub fn set_reset_token(&mut self, token: String, expires_at: DateTime<Utc>) {
         self.reset_token = Some(token);
         self.reset_token_expires = Some(expires_at);
@@ -81,9 +116,9 @@ impl UserPassword {
     }
 
     /// Actualiza el hash de la contraseña.
-    pub fn update_password(&mut self, new_hash: String, new_salt: Option<String>) -> Result<(), ValidationError> {
+    pub fn update_password(&mut self, new_hash: String, new_salt: Option<String>) -> Result<(), UserDomainError> {
         if new_hash.trim().is_empty() {
-            return Err(ValidationError::InvalidAuthType);
+            return Err((CategoryError::Password, TypeError::Empty).into());
         }
 
         self.password_hash = new_hash;
@@ -92,4 +127,18 @@ impl UserPassword {
 
         Ok(())
     }
+
+    /// Sustituye el hash por el de una contraseña nueva, guardando el actual
+    /// en el historial, que se recorta a `keep` entradas.
+    ///
+    /// `update_password` no toca el historial: sirve para recalcular el hash
+    /// de la misma contraseña.
+    pub fn rotate_password(&mut self, new_hash: String, keep: usize, at: DateTime<Utc>) -> Result<(), UserDomainError> {
+        let previous = self.password_hash.clone();
+        self.update_password(new_hash, None)?;
+        self.password_history.insert(0, previous);
+        self.password_history.truncate(keep);
+        self.password_changed_at = Some(at);
+        Ok(())
+    }
 }
@@ -5,8 +5,8 @@ use crate::user::domain::vo::{
     Gender,
     Locale,
     Timezone,
-    ValidationError,
 };
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Perfil del usuario: datos adicionales y de presentación.
 ///
@@ -30,6 +30,7 @@ pub struct UserProfile {
 
 impl UserProfile {
     /// Crea un nuevo perfil de usuario validado.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         profile_id: Uuid,
         user_id: Uuid,
@@ -43,15 +44,9 @@ impl UserProfile {
         locale: Option<Locale>,
         timezone: Option<Timezone>,
         created_at: Option<DateTime<Utc>>,
-    ) -> Result<Self, ValidationError> {
-        // Validación mínima de display_name
+    ) -> Result<Self, UserDomainError> {
         if let Some(name) = &display_name {
-            if name.trim().is_empty() {
-                return Err(ValidationError::InvalidUsername); // puedes hacer un `InvalidDisplayName`
-            }
-            if name.len() < 6 || name.len() > 30 {
-                return Err(ValidationError::InvalidUsername);
-            }
+            Self::validate_display_name(name)?;
         }
 
         Ok(Self {
@@ -64,23 +59,36 @@ impl UserProfile {
             bio,
             birth_date,
             gender,
-            locale: locale.unwrap_or(Locale::default()),
-            timezone: timezone.unwrap_or(Timezone::default()),
+            locale: locale.unwrap_or_default(),
+            timezone: timezone.unwrap_or_default(),
             created_at: created_at.unwrap_or_else(Utc::now),
         })
     }
 
     /// Actualiza el nombre para mostrar.
-    pub fn update_display_name(&mut self, name: String) -> Result<(), ValidationError> {
-        if name.trim().is_empty(){
-            return Err(ValidationError::InvalidUsername);
-        } else if name.len() < 6 || name.len() > 30 {
-            return Err(ValidationError::InvalidUsername);
-        }
+    pub fn update_display_name(&mut self, name: String) -> Result<(), UserDomainError> {
+        Self::validate_display_name(&name)?;
         self.display_name = Some(name);
         Ok(())
     }
 
+    /// Validación mínima de display_name.
+    fn validate_display_name(name: &str) -> Result<(), UserDomainError> {
+        const MIN_DISPLAY_NAME_LEN: usize = 6;
+        const MAX_DISPLAY_NAME_LEN: usize = 30;
+
+        if name.trim().is_empty() {
+            return Err((CategoryError::DisplayName, TypeError::Empty).into());
+        }
+        if name.len() < MIN_DISPLAY_NAME_LEN {
+            return Err((CategoryError::DisplayName, TypeError::TooShort { short: MIN_DISPLAY_NAME_LEN as u16 }).into());
+        }
+        if name.len() > MAX_DISPLAY_NAME_LEN {
+            return Err((CategoryError::DisplayName, TypeError::TooLong { long: MAX_DISPLAY_NAME_LEN as u32 }).into());
+        }
+        Ok(())
+    }
+
     /// Actualiza el avatar del perfil.
     pub fn update_avatar(&mut self, url: String) {
         self.avatar_url = Some(url);
@@ -1,7 +1,7 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
-use crate::user::domain::vo::ValidationError;
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Relación entre un usuario y un rol dentro del sistema.
 ///
@@ -28,12 +28,10 @@ impl UserRole {
         expires_at: Option<DateTime<Utc>>,
         granted_at: Option<DateTime<Utc>>,
         is_active: Option<bool>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Validación: expiración no puede estar en el pasado
-        if let Some(exp) = expires_at {
-            if exp < Utc::now() {
-                return Err(ValidationError::InvalidUserStatus); // O define un error más específico
-            }
+        if let Some(exp) = expires_at && exp < Utc::now() {
+            return Err((CategoryError::UserRole, TypeError::Expired).into());
         }
 
         Ok(Self {
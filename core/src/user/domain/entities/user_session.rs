@@ -2,7 +2,7 @@ use uuid::Uuid;
 use chrono::{DateTime, Utc};
 use serde_json::Value as JsonValue;
 
-use crate::user::domain::vo::ValidationError;
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Representa una sesión de usuario en el sistema.
 ///
@@ -34,9 +34,24 @@ impl UserSession {
         ip_address: Option<String>,
         user_agent: Option<String>,
         device_info: Option<JsonValue>,
-    ) -> Result<Self, ValidationError> {
-        if expires_at <= Utc::now() {
-            return Err(ValidationError::InvalidUserStatus); // Puedes definir un error más específico tipo InvalidSession
+    ) -> Result<Self, UserDomainError> {
+        Self::new_at(session_id, user_id, refresh_token, expires_at, ip_address, user_agent, device_info, Utc::now())
+    }
+
+    /// Igual que `new`, pero abierta en `now` (ver `Clock`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_at(
+        session_id: Uuid,
+        user_id: Uuid,
+        refresh_token: Option<String>,
+        expires_at: DateTime<Utc>,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+        device_info: Option<JsonValue>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, UserDomainError> {
+        if expires_at <= now {
+            return Err((CategoryError::Session, TypeError::Expired).into());
         }
 
         Ok(Self {
@@ -49,7 +64,7 @@ impl UserSession {
             user_agent,
             device_info,
             is_active: true,
-            created_at: Utc::now(),
+            created_at: now,
             last_activity_at: None,
         })
     }
@@ -61,7 +76,12 @@ impl UserSession {
 
     /// Verifica si la sesión sigue siendo válida.
     pub fn is_valid(&self) -> bool {
-        self.is_active && self.expires_at > Utc::now()
+        self.is_valid_at(Utc::now())
+    }
+
+    /// Verifica si la sesión sigue siendo válida en `now`.
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        self.is_active && self.expires_at > now
     }
 
     /// Actualiza la última actividad de la sesión.
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    ExternalId,
+    Username,
+    Email,
+    Phone,
+    UserStatus,
+    OccurredAt,
+};
+
+/// Estado persistible de `User`.
+///
+/// - Lo producen `User::snapshot()` y lo consume `User::restore()`.
+/// - Usado por los repositorios (SeaORM, memoria) y para serializar al usuario.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserSnapshot {
+    pub id: UserId,
+    pub external_id: Option<ExternalId>,
+    pub username: Option<Username>,
+    pub email: Email,
+    pub email_verified: bool,
+    pub phone: Option<Phone>,
+    pub phone_verified: bool,
+    pub status: UserStatus,
+    pub created_at: OccurredAt,
+    pub updated_at: OccurredAt,
+    pub deleted_at: Option<OccurredAt>,
+    /// Versión del agregado para concurrencia optimista; al menos 1.
+    pub version: u64,
+}
@@ -1,7 +1,8 @@
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
-use crate::user::domain::vo::{SubscriptionTier, SubscriptionStatus, ValidationError};
+use crate::user::domain::vo::{SubscriptionTier, SubscriptionStatus};
+use crate::user::domain::validations::{UserDomainError, CategoryError, TypeError};
 
 /// Representa una suscripción de usuario en el sistema.
 ///
@@ -24,6 +25,7 @@ pub struct UserSubscription {
 
 impl UserSubscription {
     /// Crea una nueva suscripción validando fechas y estado.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         subscription_id: Uuid,
         user_id: Uuid,
@@ -33,12 +35,10 @@ impl UserSubscription {
         expires_at: Option<DateTime<Utc>>,
         auto_renew: bool,
         payment_method: Option<String>,
-    ) -> Result<Self, ValidationError> {
+    ) -> Result<Self, UserDomainError> {
         // Validar que expires_at sea posterior a starts_at si existe
-        if let Some(exp) = expires_at {
-            if exp <= starts_at {
-                return Err(ValidationError::InvalidSubscriptionStatus);
-            }
+        if let Some(exp) = expires_at && exp <= starts_at {
+            return Err((CategoryError::Subscription, TypeError::InvalidRange { start: starts_at.to_rfc3339(), end: exp.to_rfc3339() }).into());
         }
 
         Ok(Self {
@@ -74,9 +74,9 @@ impl UserSubscription {
     }
 
     /// Renueva la suscripción extendiendo la fecha de expiración.
-    pub fn renew(&mut self, new_expires_at: DateTime<Utc>) -> Result<(), ValidationError> {
+    pub fn renew(&mut self, new_expires_at: DateTime<Utc>) -> Result<(), UserDomainError> {
         if new_expires_at <= Utc::now() {
-            return Err(ValidationError::InvalidSubscriptionStatus);
+            return Err((CategoryError::Subscription, TypeError::Expired).into());
         }
         self.expires_at = Some(new_expires_at);
         self.status = SubscriptionStatus::Active;
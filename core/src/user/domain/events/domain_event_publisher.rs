@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::user::domain::events::UserDomainEvent;
+
+/// Trait genérico para publicar eventos de dominio.
+/// Esto permite que el dominio sea independiente de la infraestructura.
+#[async_trait]
+pub trait DomainEventPublisher: Send + Sync {
+    async fn publish(&self, event: &UserDomainEvent) -> anyhow::Result<()>;
+}
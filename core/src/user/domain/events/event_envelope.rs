@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::user::domain::vo::{OccurredAt, UserId};
+
+/// Sobre JSON estable con el que los eventos salen del dominio
+/// (outbox, brokers, feed de actividad del frontend).
+///
+/// ```json
+/// {
+///   "event_id": "…",
+///   "type": "user_email_updated",
+///   "aggregate_id": "…",
+///   "aggregate_version": 3,
+///   "schema_version": 1,
+///   "occurred_at": "2025-01-01T00:00:00+00:00",
+///   "payload": { "user_id": "…", "old_email": "…", "new_email": "…" }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserEventEnvelope {
+    pub event_id: Uuid,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub aggregate_id: UserId,
+    pub aggregate_version: u64,
+    pub schema_version: u16,
+    pub occurred_at: OccurredAt,
+    pub payload: JsonValue,
+}
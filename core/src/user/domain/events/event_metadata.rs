@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::user::domain::vo::OccurredAt;
+
+/// Metadatos comunes a todos los eventos del agregado `User`.
+///
+/// No forman parte del `payload`: viajan en el sobre (`UserEventEnvelope`).
+/// La versión del agregado la asigna `User` al registrar el evento; mientras
+/// el evento no pertenezca a un flujo vale `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventMetadata {
+    event_id: Uuid,
+    aggregate_version: u64,
+    occurred_at: OccurredAt,
+}
+
+impl EventMetadata {
+    pub fn new() -> Self {
+        Self {
+            event_id: Uuid::new_v4(),
+            aggregate_version: 0,
+            occurred_at: OccurredAt::now(),
+        }
+    }
+
+    pub(crate) fn restore(event_id: Uuid, aggregate_version: u64, occurred_at: OccurredAt) -> Self {
+        Self { event_id, aggregate_version, occurred_at }
+    }
+
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    pub fn aggregate_version(&self) -> u64 {
+        self.aggregate_version
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        &self.occurred_at
+    }
+
+    pub(crate) fn set_aggregate_version(&mut self, version: u64) {
+        self.aggregate_version = version;
+    }
+}
+
+impl Default for EventMetadata {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,23 +1,41 @@
+pub mod domain_event_publisher;
+pub mod event_envelope;
+pub mod event_metadata;
 pub mod user_registered;
 pub mod user_email_updated;
 pub mod user_email_verified;
 pub mod user_phone_assigned;
 pub mod user_phone_verified;
+pub mod user_phone_unassigned;
 pub mod user_username_assigned;
 pub mod user_external_id_linked;
+pub mod user_external_id_unlinked;
 pub mod user_activated;
 pub mod user_suspended;
 pub mod user_deleted;
+pub mod user_locked_out;
+pub mod user_password_changed;
+pub mod user_password_reset_requested;
+pub mod user_unlocked;
 pub mod user_event;
 
+pub use domain_event_publisher::DomainEventPublisher;
+pub use event_envelope::UserEventEnvelope;
+pub use event_metadata::EventMetadata;
 pub use user_registered::UserRegistered;
 pub use user_email_updated::UserEmailUpdated;
 pub use user_email_verified::UserEmailVerified;
 pub use user_phone_assigned::UserPhoneAssigned;
 pub use user_phone_verified::UserPhoneVerified;
+pub use user_phone_unassigned::UserPhoneUnassigned;
 pub use user_username_assigned::UserUsernameAssigned;
 pub use user_external_id_linked::UserExternalIdLinked;
+pub use user_external_id_unlinked::UserExternalIdUnlinked;
 pub use user_activated::UserActivated;
 pub use user_suspended::UserSuspended;
 pub use user_deleted::UserDeleted;
+pub use user_locked_out::UserLockedOut;
+pub use user_password_changed::UserPasswordChanged;
+pub use user_password_reset_requested::UserPasswordResetRequested;
+pub use user_unlocked::UserUnlocked;
 pub use user_event::UserDomainEvent;
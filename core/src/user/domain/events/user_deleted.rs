@@ -1,13 +1,19 @@
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::vo::{
     UserId,
     UserStatus,
     OccurredAt,
 };
 
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserDeleted {
     user_id: UserId,
     user_status: UserStatus,
-    occurred_at: OccurredAt,
+    #[serde(skip)]
+    metadata: EventMetadata,
 }
 
 impl UserDeleted {
@@ -15,7 +21,27 @@ impl UserDeleted {
         Self {
             user_id,
             user_status,
-            occurred_at: OccurredAt::now(),
+            metadata: EventMetadata::new(),
         }
     }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn user_status(&self) -> &UserStatus {
+        &self.user_status
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
 }
@@ -1,14 +1,20 @@
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::vo::{
     UserId,
     Email,
     OccurredAt,
 };
 
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserEmailUpdated {
     user_id: UserId,
     old_email: Email,
     new_email: Email,
-    occurred_at: OccurredAt,
+    #[serde(skip)]
+    metadata: EventMetadata,
 }
 
 impl UserEmailUpdated {
@@ -17,7 +23,31 @@ impl UserEmailUpdated {
             user_id,
             old_email,
             new_email,
-            occurred_at: OccurredAt::now(),
+            metadata: EventMetadata::new(),
         }
     }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn old_email(&self) -> &Email {
+        &self.old_email
+    }
+
+    pub fn new_email(&self) -> &Email {
+        &self.new_email
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
 }
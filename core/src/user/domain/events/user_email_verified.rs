@@ -1,13 +1,19 @@
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::vo::{
     UserId,
     Email,
     OccurredAt,
 };
 
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserEmailVerified {
     user_id: UserId,
     email: Email,
-    occurred_at: OccurredAt,
+    #[serde(skip)]
+    metadata: EventMetadata,
 }
 
 impl UserEmailVerified {
@@ -15,7 +21,27 @@ impl UserEmailVerified {
         Self {
             user_id,
             email,
-            occurred_at: OccurredAt::now(),
+            metadata: EventMetadata::new(),
         }
     }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
 }
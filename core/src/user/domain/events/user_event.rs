@@ -1,59 +1,249 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
 use super::{
     UserActivated,
     UserDeleted,
     UserEmailUpdated,
     UserEmailVerified,
     UserExternalIdLinked,
+    UserExternalIdUnlinked,
+    UserLockedOut,
+    UserPasswordChanged,
+    UserPasswordResetRequested,
     UserPhoneAssigned,
+    UserPhoneUnassigned,
     UserPhoneVerified,
     UserRegistered,
     UserSuspended,
+    UserUnlocked,
     UserUsernameAssigned,
+    EventMetadata,
+    UserEventEnvelope,
 };
 
-use crate::user::domain::vo::OccurredAt;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+use crate::user::domain::vo::{OccurredAt, UserId};
 
+#[derive(Debug, Clone)]
 pub enum UserDomainEvent {
     Activated(UserActivated),
     Deleted(UserDeleted),
     EmailUpdated(UserEmailUpdated),
     EmailVerified(UserEmailVerified),
-    ExternalIdLinkend(UserExternalIdLinked),
+    ExternalIdLinked(UserExternalIdLinked),
+    ExternalIdUnlinked(UserExternalIdUnlinked),
+    LockedOut(UserLockedOut),
+    PasswordChanged(UserPasswordChanged),
+    PasswordResetRequested(UserPasswordResetRequested),
     PhoneAssigned(UserPhoneAssigned),
+    PhoneUnassigned(UserPhoneUnassigned),
     PhoneVerified(UserPhoneVerified),
     Registered(UserRegistered),
     Suspended(UserSuspended),
-    UsernameAssigned(UserUsernameAssigned)
+    Unlocked(UserUnlocked),
+    UsernameAssigned(UserUsernameAssigned),
 }
 
 impl UserDomainEvent {
+    /// Versión del esquema de los `payload` que emite esta librería.
+    /// Se incrementa cuando cambia la forma de algún evento.
+    pub const SCHEMA_VERSION: u16 = 1;
+
+    /// Nombre estable del evento; es el `type` del sobre JSON.
     pub fn event_name(&self) -> &'static str {
         match self {
             Self::Activated(_) => "user_activated",
             Self::Deleted(_) => "user_deleted",
             Self::EmailUpdated(_) => "user_email_updated",
             Self::EmailVerified(_) => "user_email_verified",
-            Self::ExternalIdLinkend(_) => "user_external_id_linkend",
+            Self::ExternalIdLinked(_) => "user_external_id_linked",
+            Self::ExternalIdUnlinked(_) => "user_external_id_unlinked",
+            Self::LockedOut(_) => "user_locked_out",
+            Self::PasswordChanged(_) => "user_password_changed",
+            Self::PasswordResetRequested(_) => "user_password_reset_requested",
             Self::PhoneAssigned(_) => "user_phone_assigned",
+            Self::PhoneUnassigned(_) => "user_phone_unassigned",
             Self::PhoneVerified(_) => "user_phone_verified",
             Self::Registered(_) => "user_registered",
             Self::Suspended(_) => "user_suspended",
-            Self::UsernameAssigned(_) => "username_assigned",
+            Self::Unlocked(_) => "user_unlocked",
+            Self::UsernameAssigned(_) => "user_username_assigned",
+        }
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        match self {
+            Self::Activated(e) => e.metadata(),
+            Self::Deleted(e) => e.metadata(),
+            Self::EmailUpdated(e) => e.metadata(),
+            Self::EmailVerified(e) => e.metadata(),
+            Self::ExternalIdLinked(e) => e.metadata(),
+            Self::ExternalIdUnlinked(e) => e.metadata(),
+            Self::LockedOut(e) => e.metadata(),
+            Self::PasswordChanged(e) => e.metadata(),
+            Self::PasswordResetRequested(e) => e.metadata(),
+            Self::PhoneAssigned(e) => e.metadata(),
+            Self::PhoneUnassigned(e) => e.metadata(),
+            Self::PhoneVerified(e) => e.metadata(),
+            Self::Registered(e) => e.metadata(),
+            Self::Suspended(e) => e.metadata(),
+            Self::Unlocked(e) => e.metadata(),
+            Self::UsernameAssigned(e) => e.metadata(),
+        }
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        match self {
+            Self::Activated(e) => e.metadata_mut(),
+            Self::Deleted(e) => e.metadata_mut(),
+            Self::EmailUpdated(e) => e.metadata_mut(),
+            Self::EmailVerified(e) => e.metadata_mut(),
+            Self::ExternalIdLinked(e) => e.metadata_mut(),
+            Self::ExternalIdUnlinked(e) => e.metadata_mut(),
+            Self::LockedOut(e) => e.metadata_mut(),
+            Self::PasswordChanged(e) => e.metadata_mut(),
+            Self::PasswordResetRequested(e) => e.metadata_mut(),
+            Self::PhoneAssigned(e) => e.metadata_mut(),
+            Self::PhoneUnassigned(e) => e.metadata_mut(),
+            Self::PhoneVerified(e) => e.metadata_mut(),
+            Self::Registered(e) => e.metadata_mut(),
+            Self::Suspended(e) => e.metadata_mut(),
+            Self::Unlocked(e) => e.metadata_mut(),
+            Self::UsernameAssigned(e) => e.metadata_mut(),
         }
     }
 
+    pub fn event_id(&self) -> Uuid {
+        self.metadata().event_id()
+    }
+
+    pub fn aggregate_version(&self) -> u64 {
+        self.metadata().aggregate_version()
+    }
+
+    pub fn schema_version(&self) -> u16 {
+        Self::SCHEMA_VERSION
+    }
+
     pub fn occurred_at(&self) -> OccurredAt {
+        self.metadata().occurred_at().clone()
+    }
+
+    pub fn user_id(&self) -> &UserId {
         match self {
-            Self::Activated(_) => OccurredAt::now(),
-            Self::Deleted(_) => OccurredAt::now(),
-            Self::EmailUpdated(_) => OccurredAt::now(),
-            Self::EmailVerified(_) => OccurredAt::now(),
-            Self::ExternalIdLinkend(_) => OccurredAt::now(),
-            Self::PhoneAssigned(_) => OccurredAt::now(),
-            Self::PhoneVerified(_) => OccurredAt::now(),
-            Self::Registered(_) => OccurredAt::now(),
-            Self::Suspended(_) => OccurredAt::now(),
-            Self::UsernameAssigned(_) => OccurredAt::now(),
+            Self::Activated(e) => e.user_id(),
+            Self::Deleted(e) => e.user_id(),
+            Self::EmailUpdated(e) => e.user_id(),
+            Self::EmailVerified(e) => e.user_id(),
+            Self::ExternalIdLinked(e) => e.user_id(),
+            Self::ExternalIdUnlinked(e) => e.user_id(),
+            Self::LockedOut(e) => e.user_id(),
+            Self::PasswordChanged(e) => e.user_id(),
+            Self::PasswordResetRequested(e) => e.user_id(),
+            Self::PhoneAssigned(e) => e.user_id(),
+            Self::PhoneUnassigned(e) => e.user_id(),
+            Self::PhoneVerified(e) => e.user_id(),
+            Self::Registered(e) => e.user_id(),
+            Self::Suspended(e) => e.user_id(),
+            Self::Unlocked(e) => e.user_id(),
+            Self::UsernameAssigned(e) => e.user_id(),
         }
     }
+
+    pub fn to_envelope(&self) -> UserEventEnvelope {
+        let payload = match self {
+            Self::Activated(e) => Self::encode(e),
+            Self::Deleted(e) => Self::encode(e),
+            Self::EmailUpdated(e) => Self::encode(e),
+            Self::EmailVerified(e) => Self::encode(e),
+            Self::ExternalIdLinked(e) => Self::encode(e),
+            Self::ExternalIdUnlinked(e) => Self::encode(e),
+            Self::LockedOut(e) => Self::encode(e),
+            Self::PasswordChanged(e) => Self::encode(e),
+            Self::PasswordResetRequested(e) => Self::encode(e),
+            Self::PhoneAssigned(e) => Self::encode(e),
+            Self::PhoneUnassigned(e) => Self::encode(e),
+            Self::PhoneVerified(e) => Self::encode(e),
+            Self::Registered(e) => Self::encode(e),
+            Self::Suspended(e) => Self::encode(e),
+            Self::Unlocked(e) => Self::encode(e),
+            Self::UsernameAssigned(e) => Self::encode(e),
+        };
+
+        UserEventEnvelope {
+            event_id: self.event_id(),
+            event_type: self.event_name().to_string(),
+            aggregate_id: self.user_id().clone(),
+            aggregate_version: self.aggregate_version(),
+            schema_version: self.schema_version(),
+            occurred_at: self.occurred_at(),
+            payload,
+        }
+    }
+
+    /// Reconstruye el evento desde su sobre, conservando id, versión y fecha.
+    pub fn from_envelope(envelope: UserEventEnvelope) -> Result<Self, UserDomainError> {
+        if envelope.schema_version == 0 || envelope.schema_version > Self::SCHEMA_VERSION {
+            return Err((CategoryError::Event, TypeError::InvalidState {
+                state: format!("schema_version={}", envelope.schema_version),
+            }).into());
+        }
+
+        let payload = envelope.payload;
+        let mut event = match envelope.event_type.as_str() {
+            "user_activated" => Self::Activated(Self::decode(payload)?),
+            "user_deleted" => Self::Deleted(Self::decode(payload)?),
+            "user_email_updated" => Self::EmailUpdated(Self::decode(payload)?),
+            "user_email_verified" => Self::EmailVerified(Self::decode(payload)?),
+            "user_external_id_linked" => Self::ExternalIdLinked(Self::decode(payload)?),
+            "user_external_id_unlinked" => Self::ExternalIdUnlinked(Self::decode(payload)?),
+            "user_locked_out" => Self::LockedOut(Self::decode(payload)?),
+            "user_password_changed" => Self::PasswordChanged(Self::decode(payload)?),
+            "user_password_reset_requested" => Self::PasswordResetRequested(Self::decode(payload)?),
+            "user_phone_assigned" => Self::PhoneAssigned(Self::decode(payload)?),
+            "user_phone_unassigned" => Self::PhoneUnassigned(Self::decode(payload)?),
+            "user_phone_verified" => Self::PhoneVerified(Self::decode(payload)?),
+            "user_registered" => Self::Registered(Self::decode(payload)?),
+            "user_suspended" => Self::Suspended(Self::decode(payload)?),
+            "user_unlocked" => Self::Unlocked(Self::decode(payload)?),
+            "user_username_assigned" => Self::UsernameAssigned(Self::decode(payload)?),
+            _ => return Err((CategoryError::Event, TypeError::NotSupported).into()),
+        };
+
+        if event.user_id() != &envelope.aggregate_id {
+            return Err((CategoryError::Event, TypeError::Mismatch).into());
+        }
+
+        *event.metadata_mut() = EventMetadata::restore(
+            envelope.event_id,
+            envelope.aggregate_version,
+            envelope.occurred_at,
+        );
+
+        Ok(event)
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        // El sobre sólo contiene cadenas y números: su serialización no falla.
+        serde_json::to_value(self.to_envelope()).unwrap_or(JsonValue::Null)
+    }
+
+    pub fn from_json(value: JsonValue) -> Result<Self, UserDomainError> {
+        let envelope: UserEventEnvelope = serde_json::from_value(value)
+            .map_err(|_| UserDomainError::from((CategoryError::Event, TypeError::Format { format: "json".into() })))?;
+
+        Self::from_envelope(envelope)
+    }
+
+    fn encode<T: Serialize>(payload: &T) -> JsonValue {
+        serde_json::to_value(payload).unwrap_or(JsonValue::Null)
+    }
+
+    fn decode<T: DeserializeOwned>(payload: JsonValue) -> Result<T, UserDomainError> {
+        serde_json::from_value(payload)
+            .map_err(|_| (CategoryError::Event, TypeError::Format { format: "json".into() }).into())
+    }
 }
@@ -1,13 +1,19 @@
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::vo::{
     UserId,
     ExternalId,
     OccurredAt,
 };
 
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserExternalIdLinked {
     user_id: UserId,
     external_id: ExternalId,
-    occurred_at: OccurredAt,
+    #[serde(skip)]
+    metadata: EventMetadata,
 }
 
 impl UserExternalIdLinked {
@@ -15,7 +21,27 @@ impl UserExternalIdLinked {
         Self {
             user_id,
             external_id,
-            occurred_at: OccurredAt::now(),
+            metadata: EventMetadata::new(),
         }
     }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn external_id(&self) -> &ExternalId {
+        &self.external_id
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
 }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    ExternalId,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserExternalIdUnlinked {
+    user_id: UserId,
+    external_id: ExternalId,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserExternalIdUnlinked {
+    pub fn new(user_id: UserId, external_id: ExternalId) -> Self {
+        Self {
+            user_id,
+            external_id,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn external_id(&self) -> &ExternalId {
+        &self.external_id
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+/// La contraseña del usuario quedó bloqueada por intentos fallidos.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserLockedOut {
+    user_id: UserId,
+    locked_until: OccurredAt,
+    failed_attempts: u32,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserLockedOut {
+    pub fn new(user_id: UserId, locked_until: OccurredAt, failed_attempts: u32) -> Self {
+        Self {
+            user_id,
+            locked_until,
+            failed_attempts,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn locked_until(&self) -> &OccurredAt {
+        &self.locked_until
+    }
+
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+/// Se cambió la contraseña, con la actual o con un token de restablecimiento.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordChanged {
+    user_id: UserId,
+    /// `true` si se cambió con un token de restablecimiento.
+    via_reset: bool,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserPasswordChanged {
+    pub fn new(user_id: UserId, via_reset: bool) -> Self {
+        Self {
+            user_id,
+            via_reset,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn via_reset(&self) -> bool {
+        self.via_reset
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+/// Se emitió un token para restablecer la contraseña.
+///
+/// No lleva el token: lo entrega `PasswordResetNotifier` antes de confirmar.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPasswordResetRequested {
+    user_id: UserId,
+    expires_at: OccurredAt,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserPasswordResetRequested {
+    pub fn new(user_id: UserId, expires_at: OccurredAt) -> Self {
+        Self {
+            user_id,
+            expires_at,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn expires_at(&self) -> &OccurredAt {
+        &self.expires_at
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    Phone,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPhoneUnassigned {
+    user_id: UserId,
+    phone: Phone,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserPhoneUnassigned {
+    pub fn new(user_id: UserId, phone: Phone) -> Self {
+        Self {
+            user_id,
+            phone,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn phone(&self) -> &Phone {
+        &self.phone
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::vo::{
+    UserId,
+    OccurredAt,
+};
+
+use super::EventMetadata;
+
+/// Se levantó el bloqueo de la contraseña, por caducidad o manualmente.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserUnlocked {
+    user_id: UserId,
+    #[serde(skip)]
+    metadata: EventMetadata,
+}
+
+impl UserUnlocked {
+    pub fn new(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            metadata: EventMetadata::new(),
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn occurred_at(&self) -> &OccurredAt {
+        self.metadata.occurred_at()
+    }
+
+    pub fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+
+    pub(crate) fn metadata_mut(&mut self) -> &mut EventMetadata {
+        &mut self.metadata
+    }
+}
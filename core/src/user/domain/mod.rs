@@ -1,10 +1,14 @@
 // Módulo de dominio del agregado User
+pub mod aggregates;
 pub mod entities;
 pub mod events;
+pub mod repositories;
+pub mod services;
 pub mod vo;
 pub mod validations;
 
 
+pub use aggregates::*;
 pub use entities::*;
 pub use events::*;
 pub use vo::*;
@@ -0,0 +1,72 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::user::domain::validations::UserDomainError;
+
+/// Errores de la capa de persistencia del contexto `User`.
+///
+/// - `Concurrency`: otra escritura modificó el flujo/agregado desde que se leyó
+///   (`expected` no coincide con la versión `actual`).
+/// - `Domain`: un registro almacenado no pudo reconstruirse en sus value objects.
+/// - `Infrastructure`: fallo del motor (SeaORM, conexión, etc.). Conserva el error
+///   original como `source` para no perder el diagnóstico.
+#[derive(Debug)]
+pub enum RepositoryError {
+    NotFound { entity: &'static str, id: String },
+    Conflict { entity: &'static str, field: &'static str, value: String },
+    Concurrency { stream_id: String, expected: u64, actual: u64 },
+    Domain(UserDomainError),
+    Infrastructure(Box<dyn StdError + Send + Sync>),
+}
+
+impl RepositoryError {
+    pub fn infrastructure<E>(err: E) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        RepositoryError::Infrastructure(Box::new(err))
+    }
+
+    /// Código estable y legible por máquinas, p. ej. `user.repository.not_found`.
+    pub fn code(&self) -> String {
+        match self {
+            RepositoryError::NotFound { .. } => "user.repository.not_found".into(),
+            RepositoryError::Conflict { .. } => "user.repository.conflict".into(),
+            RepositoryError::Concurrency { .. } => "user.repository.concurrency".into(),
+            RepositoryError::Domain(err) => err.code(),
+            RepositoryError::Infrastructure(_) => "user.repository.infrastructure".into(),
+        }
+    }
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::NotFound { entity, id } => write!(f, "{entity} '{id}' not found"),
+            RepositoryError::Conflict { entity, field, value } => {
+                write!(f, "{entity}.{field} '{value}' already exists")
+            }
+            RepositoryError::Concurrency { stream_id, expected, actual } => {
+                write!(f, "stream '{stream_id}' is at version {actual}, expected {expected}")
+            }
+            RepositoryError::Domain(err) => write!(f, "{err}"),
+            RepositoryError::Infrastructure(err) => write!(f, "persistence failure: {err}"),
+        }
+    }
+}
+
+impl StdError for RepositoryError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            RepositoryError::Domain(err) => Some(err),
+            RepositoryError::Infrastructure(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<UserDomainError> for RepositoryError {
+    fn from(err: UserDomainError) -> Self {
+        RepositoryError::Domain(err)
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::user::domain::{
+    events::UserEventEnvelope,
+    repositories::RepositoryError,
+    vo::UserId,
+};
+
+/// Almacén de eventos de sólo anexado, con un flujo (`stream_id`) por agregado.
+///
+/// Trabaja con el sobre serializado de los eventos (`type` + `payload`), de modo
+/// que el almacenamiento no depende de los structs concretos de cada evento.
+/// Las versiones empiezan en 1; un flujo inexistente está en la versión 0.
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Anexa `events` si el flujo sigue en `expected_version`; si no, falla con
+    /// `RepositoryError::Concurrency`. El almacén asigna las versiones
+    /// `expected_version + 1 ..` y devuelve la nueva versión del flujo.
+    async fn append(&self, stream_id: &str, expected_version: u64, events: &[UserEventEnvelope]) -> Result<u64, RepositoryError>;
+
+    /// Eventos del flujo con versión `>= from_version`, en orden.
+    async fn read_stream(&self, stream_id: &str, from_version: u64) -> Result<Vec<UserEventEnvelope>, RepositoryError>;
+
+    /// Versión actual del flujo (0 si no existe).
+    async fn stream_version(&self, stream_id: &str) -> Result<u64, RepositoryError>;
+}
+
+/// Identificador del flujo de eventos de un usuario.
+pub fn user_stream_id(user_id: &UserId) -> String {
+    format!("user-{user_id}")
+}
@@ -0,0 +1,20 @@
+pub mod errors;
+pub mod event_store;
+pub mod outbox_repository;
+pub mod role_repository;
+pub mod unit_of_work;
+pub mod user_aggregate_repository;
+pub mod user_query_repository;
+pub mod user_repository;
+
+pub use errors::RepositoryError;
+pub use event_store::{user_stream_id, EventStore};
+pub use outbox_repository::{OutboxMessage, OutboxRepository};
+pub use role_repository::RoleRepository;
+pub use unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+pub use user_aggregate_repository::{AggregateChanges, CollectionChanges, UserAggregateRepository};
+pub use user_query_repository::{
+    CursorPosition, SortDirection, SortKey, UserCursor, UserFilter, UserPage, UserQuery, UserQueryRepository,
+    UserSort, UserSortField, UserSummary,
+};
+pub use user_repository::UserRepository;
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::user::domain::{
+    events::{UserDomainEvent, UserEventEnvelope},
+    repositories::RepositoryError,
+    validations::UserDomainError,
+    vo::{OccurredAt, UserId},
+};
+
+/// Fila de la bandeja de salida (outbox) tal como se almacenó.
+///
+/// Se conserva en bruto para que una fila corrupta no impida leer las demás:
+/// la decodificación a `UserDomainEvent` ocurre en `to_event`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxMessage {
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub aggregate_version: u64,
+    pub event_type: String,
+    pub schema_version: u16,
+    pub occurred_at: DateTime<Utc>,
+    pub payload: JsonValue,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxMessage {
+    /// Mensaje pendiente listo para insertar, publicable de inmediato.
+    pub fn pending(event: &UserDomainEvent) -> Self {
+        let envelope = event.to_envelope();
+
+        Self {
+            event_id: envelope.event_id,
+            aggregate_id: envelope.aggregate_id.as_uuid(),
+            aggregate_version: envelope.aggregate_version,
+            event_type: envelope.event_type,
+            schema_version: envelope.schema_version,
+            occurred_at: envelope.occurred_at.value(),
+            payload: envelope.payload,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: envelope.occurred_at.value(),
+            published_at: None,
+        }
+    }
+
+    pub fn envelope(&self) -> UserEventEnvelope {
+        UserEventEnvelope {
+            event_id: self.event_id,
+            event_type: self.event_type.clone(),
+            aggregate_id: UserId::from_uuid(self.aggregate_id),
+            aggregate_version: self.aggregate_version,
+            schema_version: self.schema_version,
+            occurred_at: OccurredAt::from_datetime(self.occurred_at),
+            payload: self.payload.clone(),
+        }
+    }
+
+    pub fn to_event(&self) -> Result<UserDomainEvent, UserDomainError> {
+        UserDomainEvent::from_envelope(self.envelope())
+    }
+}
+
+/// Lado de lectura/marcado de la bandeja de salida que usa el relay.
+///
+/// La escritura no forma parte del contrato: cada adaptador la expone sobre su
+/// propia transacción para que los eventos se guarden junto con el agregado.
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// Mensajes sin publicar, con menos de `max_attempts` intentos y cuyo
+    /// `next_attempt_at` ya venció, en orden de ocurrencia.
+    ///
+    /// Omite los mensajes de un agregado con otro anterior que espera su
+    /// reintento, para no entregar sus eventos desordenados.
+    async fn pending(&self, now: DateTime<Utc>, max_attempts: u32, limit: u64) -> Result<Vec<OutboxMessage>, RepositoryError>;
+
+    /// Marca el mensaje como publicado. Es idempotente: devuelve `false` si ya lo estaba.
+    async fn mark_published(&self, event_id: Uuid, published_at: DateTime<Utc>) -> Result<bool, RepositoryError>;
+
+    /// Registra un intento fallido y programa el siguiente.
+    async fn mark_failed(&self, event_id: Uuid, error: &str, next_attempt_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+}
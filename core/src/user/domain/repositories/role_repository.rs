@@ -1,25 +1,28 @@
+use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::user::domain::{
     entities::role::Role,
-    vo::ValidationError,
+    repositories::RepositoryError,
+    vo::RoleName,
 };
 
 /// Contrato de repositorio para Roles.
 /// Encapsula el acceso a la persistencia sin exponer detalles de la DB.
-pub trait RoleRepository {
+#[async_trait]
+pub trait RoleRepository: Send + Sync {
     /// Obtiene un rol por su ID.
-    fn get_by_id(&self, id: Uuid) -> Result<Option<Role>, ValidationError>;
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Role>, RepositoryError>;
 
     /// Obtiene un rol por su nombre (ej: "admin").
-    fn get_by_name(&self, name: &str) -> Result<Option<Role>, ValidationError>;
+    async fn get_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError>;
 
     /// Lista todos los roles.
-    fn list_all(&self) -> Result<Vec<Role>, ValidationError>;
+    async fn list_all(&self) -> Result<Vec<Role>, RepositoryError>;
 
     /// Guarda (crea/actualiza) un rol.
-    fn save(&mut self, role: &Role) -> Result<(), ValidationError>;
+    async fn save(&self, role: &Role) -> Result<(), RepositoryError>;
 
     /// Elimina un rol por su ID.
-    fn delete(&mut self, id: Uuid) -> Result<(), ValidationError>;
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
 }
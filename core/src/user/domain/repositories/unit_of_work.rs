@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+
+use crate::user::domain::aggregates::UserAggregate;
+use crate::user::domain::events::UserDomainEvent;
+use crate::user::domain::repositories::{RepositoryError, RoleRepository, UserAggregateRepository, UserRepository};
+
+/// Transacción de negocio del contexto `User`.
+///
+/// Los repositorios que expone escriben todos en la misma transacción: nada es
+/// visible fuera hasta `commit`. Los eventos registrados con `record_events`
+/// se publican sólo después de confirmar y los entregados se marcan como
+/// publicados en la bandeja de salida. Un fallo al publicarlos no se propaga:
+/// ese evento y los siguientes siguen pendientes y los entrega `OutboxRelay`.
+///
+/// Soltar la unidad sin confirmar equivale a `rollback`.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn users(&self) -> &dyn UserRepository;
+    fn roles(&self) -> &dyn RoleRepository;
+    fn aggregates(&self) -> &dyn UserAggregateRepository;
+
+    /// Encola eventos para publicarlos tras el `commit`.
+    fn record_events(&mut self, events: Vec<UserDomainEvent>);
+
+    /// Guarda el agregado, encola sus eventos pendientes y le anota la nueva
+    /// versión de fila.
+    async fn save_aggregate(&mut self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError> {
+        let changes = self.aggregates().save(aggregate).await?;
+        aggregate.set_row_version(changes.row_version);
+        let events = aggregate.user.take_events();
+        self.record_events(events);
+        Ok(())
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
+}
+
+/// Abre unidades de trabajo; es lo que reciben los servicios.
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, RepositoryError>;
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+use crate::user::domain::{
+    aggregates::{LoadOptions, UserAggregate},
+    repositories::RepositoryError,
+    vo::UserId,
+};
+
+/// Cambios aplicados a una colección hija al guardar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CollectionChanges {
+    pub inserted: usize,
+    pub updated: usize,
+    pub deleted: usize,
+}
+
+impl CollectionChanges {
+    pub fn is_empty(&self) -> bool {
+        self.inserted + self.updated + self.deleted == 0
+    }
+}
+
+/// Resumen de un guardado, por colección (`profile`, `sessions`, ...), y la
+/// versión de fila en que quedó el agregado.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AggregateChanges {
+    pub collections: BTreeMap<&'static str, CollectionChanges>,
+    pub row_version: u64,
+}
+
+impl AggregateChanges {
+    pub fn get(&self, collection: &str) -> CollectionChanges {
+        self.collections.get(collection).copied().unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.collections.values().all(CollectionChanges::is_empty)
+    }
+}
+
+/// Persistencia del agregado `UserAggregate` completo (raíz + colecciones hijas).
+#[async_trait]
+pub trait UserAggregateRepository: Send + Sync {
+    /// Carga la raíz y sus colecciones; `options` decide si se incluyen
+    /// `sessions` y `activity_logs`.
+    async fn load(&self, id: &UserId, options: LoadOptions) -> Result<Option<UserAggregate>, RepositoryError>;
+
+    /// Completa `sessions` en un agregado cargado de forma diferida.
+    async fn load_sessions(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError>;
+
+    /// Completa `activity_logs` en un agregado cargado de forma diferida.
+    async fn load_activity_logs(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError>;
+
+    /// Guarda raíz y colecciones en una sola transacción, insertando, actualizando
+    /// o borrando sólo las filas que cambiaron.
+    ///
+    /// La fila debe seguir en `aggregate.row_version()`, aunque la raíz no
+    /// tenga eventos; si no, falla con `Concurrency`. Si hay eventos pendientes
+    /// o cambia alguna colección pasa a la siguiente versión de fila.
+    /// `User::version` no cambia por esto: sigue siendo el número de eventos.
+    async fn save(&self, aggregate: &UserAggregate) -> Result<AggregateChanges, RepositoryError>;
+}
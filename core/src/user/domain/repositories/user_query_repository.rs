@@ -0,0 +1,263 @@
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::user::domain::{
+    repositories::RepositoryError,
+    validations::{CategoryError, TypeError, UserDomainError},
+    vo::{Email, OccurredAt, RoleName, SubscriptionTier, UserId, UserStatus, Username},
+};
+
+/// Campo por el que se ordena el listado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserSortField {
+    #[default]
+    CreatedAt,
+    Email,
+}
+
+impl UserSortField {
+    fn as_str(&self) -> &'static str {
+        match self {
+            UserSortField::CreatedAt => "created_at",
+            UserSortField::Email => "email",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+/// Orden del listado. Por defecto, los más recientes primero.
+/// El id del usuario desempata siempre, en la misma dirección.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UserSort {
+    pub field: UserSortField,
+    pub direction: SortDirection,
+}
+
+impl UserSort {
+    pub fn new(field: UserSortField, direction: SortDirection) -> Self {
+        Self { field, direction }
+    }
+
+    /// Valor de ordenación de una fila.
+    pub(crate) fn key_of(&self, summary: &UserSummary) -> SortKey {
+        match self.field {
+            UserSortField::CreatedAt => SortKey::CreatedAt(summary.created_at.value()),
+            UserSortField::Email => SortKey::Email(summary.email.as_str().to_string()),
+        }
+    }
+}
+
+/// Criterios de búsqueda; los campos vacíos no filtran.
+///
+/// - `role`: asignación activa y no vencida del rol con ese nombre.
+/// - `subscription_tier`: suscripción en estado `active` de ese plan.
+/// - `created_from` / `created_to`: intervalo semiabierto `[from, to)`.
+/// - `text`: subcadena, sin distinguir mayúsculas, del email, el username o el
+///   nombre para mostrar del perfil.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserFilter {
+    pub statuses: Vec<UserStatus>,
+    pub email_verified: Option<bool>,
+    pub phone_verified: Option<bool>,
+    pub role: Option<RoleName>,
+    pub subscription_tier: Option<SubscriptionTier>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub text: Option<String>,
+}
+
+impl UserFilter {
+    pub fn validate(&self) -> Result<(), UserDomainError> {
+        if let (Some(from), Some(to)) = (self.created_from, self.created_to) && from > to {
+            return Err((CategoryError::Query, TypeError::InvalidRange { start: from.to_rfc3339(), end: to.to_rfc3339() }).into());
+        }
+        Ok(())
+    }
+
+    /// Texto de búsqueda normalizado, o `None` si está vacío.
+    pub fn search_text(&self) -> Option<String> {
+        self.text.as_deref().map(str::trim).filter(|text| !text.is_empty()).map(str::to_lowercase)
+    }
+}
+
+/// Petición de una página del listado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserQuery {
+    pub filter: UserFilter,
+    pub sort: UserSort,
+    pub limit: u64,
+    pub after: Option<UserCursor>,
+}
+
+impl UserQuery {
+    pub const DEFAULT_LIMIT: u64 = 20;
+    pub const MAX_LIMIT: u64 = 100;
+
+    pub fn new(filter: UserFilter) -> Self {
+        Self { filter, sort: UserSort::default(), limit: Self::DEFAULT_LIMIT, after: None }
+    }
+
+    pub fn sorted_by(mut self, field: UserSortField, direction: SortDirection) -> Self {
+        self.sort = UserSort::new(field, direction);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Continúa a partir del cursor de la página anterior.
+    pub fn after(mut self, cursor: UserCursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Tamaño de página efectivo, entre 1 y `MAX_LIMIT`.
+    pub fn page_size(&self) -> u64 {
+        self.limit.clamp(1, Self::MAX_LIMIT)
+    }
+
+    /// Valida el filtro y decodifica el cursor contra el orden pedido.
+    pub fn position(&self) -> Result<Option<CursorPosition>, UserDomainError> {
+        self.filter.validate()?;
+        self.after.as_ref().map(|cursor| cursor.decode(&self.sort)).transpose()
+    }
+}
+
+impl Default for UserQuery {
+    fn default() -> Self {
+        Self::new(UserFilter::default())
+    }
+}
+
+/// Fila del modelo de lectura: lo necesario para un listado de back-office.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSummary {
+    pub id: UserId,
+    pub email: Email,
+    pub username: Option<Username>,
+    pub display_name: Option<String>,
+    pub status: UserStatus,
+    pub email_verified: bool,
+    pub phone_verified: bool,
+    pub created_at: OccurredAt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserPage {
+    pub items: Vec<UserSummary>,
+    /// `None` en la última página.
+    pub next_cursor: Option<UserCursor>,
+}
+
+/// Valor de ordenación de una fila, comparable en memoria.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortKey {
+    CreatedAt(DateTime<Utc>),
+    Email(String),
+}
+
+/// Posición decodificada de un cursor: última fila vista.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorPosition {
+    pub key: SortKey,
+    pub id: Uuid,
+}
+
+/// Cursor opaco de paginación por clave (keyset).
+///
+/// Guarda el valor de ordenación y el id de la última fila devuelta, así que
+/// las inserciones posteriores no desplazan ni duplican resultados. Sólo es
+/// válido con el mismo orden con el que se generó.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserCursor(String);
+
+impl UserCursor {
+    const VERSION: &'static str = "v1";
+
+    pub fn encode(sort: &UserSort, summary: &UserSummary) -> Self {
+        let key = match sort.key_of(summary) {
+            SortKey::CreatedAt(at) => at.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            SortKey::Email(email) => email,
+        };
+        let raw = [Self::VERSION, sort.field.as_str(), sort.direction.as_str(), &summary.id.as_string(), &key].join("|");
+
+        Self(raw.bytes().map(|b| format!("{b:02x}")).collect())
+    }
+
+    pub fn decode(&self, sort: &UserSort) -> Result<CursorPosition, UserDomainError> {
+        let invalid = || UserDomainError::from((CategoryError::Query, TypeError::Format { format: "cursor".into() }));
+
+        let bytes = (0..self.0.len())
+            .step_by(2)
+            .map(|i| self.0.get(i..i + 2).and_then(|pair| u8::from_str_radix(pair, 16).ok()))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(invalid)?;
+        let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        // El email puede contener `|`, así que la clave va al final
+        let parts: Vec<&str> = raw.splitn(5, '|').collect();
+        let [version, field, direction, id, key] = parts[..] else {
+            return Err(invalid());
+        };
+        if version != Self::VERSION {
+            return Err(invalid());
+        }
+        if field != sort.field.as_str() || direction != sort.direction.as_str() {
+            return Err((CategoryError::Query, TypeError::Mismatch).into());
+        }
+
+        let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+        let key = match sort.field {
+            UserSortField::CreatedAt => {
+                SortKey::CreatedAt(DateTime::parse_from_rfc3339(key).map_err(|_| invalid())?.with_timezone(&Utc))
+            }
+            UserSortField::Email => SortKey::Email(key.to_string()),
+        };
+
+        Ok(CursorPosition { key, id })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for UserCursor {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for UserCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Lado de consulta del contexto `User`: listados filtrados y paginados para
+/// back-office, separados del repositorio transaccional del agregado.
+#[async_trait]
+pub trait UserQueryRepository: Send + Sync {
+    /// Devuelve una página; falla con `user.query.*` si el filtro o el cursor
+    /// no son válidos.
+    async fn search(&self, query: &UserQuery) -> Result<UserPage, RepositoryError>;
+}
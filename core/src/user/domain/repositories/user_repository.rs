@@ -1,31 +1,41 @@
-use uuid::Uuid;
+use async_trait::async_trait;
 
 use crate::user::domain::{
     entities::user::User,
-    vo::ValidationError,
+    repositories::RepositoryError,
+    vo::{Email, UserId, Username},
 };
 
 /// Contrato de repositorio para la entidad/agregado User.
 /// Define cómo interactuar con la persistencia sin exponer detalles de la base de datos.
-pub trait UserRepository {
-    /// Busca un usuario por su UUID.
-    fn get_by_id(&self, id: Uuid) -> Result<Option<User>, ValidationError>;
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Busca un usuario por su id.
+    async fn get_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError>;
 
     /// Busca un usuario por su email.
-    fn get_by_email(&self, email: &str) -> Result<Option<User>, ValidationError>;
+    async fn get_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError>;
 
     /// Busca un usuario por su nombre de usuario.
-    fn get_by_username(&self, username: &str) -> Result<Option<User>, ValidationError>;
+    async fn get_by_username(&self, username: &Username) -> Result<Option<User>, RepositoryError>;
 
     /// Verifica si un email ya existe (para reglas de unicidad).
-    fn exists_by_email(&self, email: &str) -> Result<bool, ValidationError>;
+    async fn exists_by_email(&self, email: &Email) -> Result<bool, RepositoryError>;
 
     /// Verifica si un username ya existe.
-    fn exists_by_username(&self, username: &str) -> Result<bool, ValidationError>;
+    async fn exists_by_username(&self, username: &Username) -> Result<bool, RepositoryError>;
 
-    /// Guarda (crea o actualiza) un usuario.
-    fn save(&mut self, user: &User) -> Result<(), ValidationError>;
+    /// Lista todos los usuarios, incluidos los eliminados lógicamente.
+    async fn list_all(&self) -> Result<Vec<User>, RepositoryError>;
 
-    /// Elimina lógicamente un usuario (soft delete).
-    fn soft_delete(&mut self, id: Uuid) -> Result<(), ValidationError>;
+    /// Guarda (crea o actualiza) un usuario junto con sus eventos pendientes.
+    /// Falla con `RepositoryError::Concurrency` si la fila cambió desde que se leyó
+    /// y con `RepositoryError::Conflict` si viola una regla de unicidad.
+    async fn save(&self, user: &User) -> Result<(), RepositoryError>;
+
+    /// Elimina lógicamente un usuario (soft delete) a través de `User::delete`.
+    async fn soft_delete(&self, id: &UserId) -> Result<(), RepositoryError>;
+
+    /// Borra definitivamente un usuario (p. ej. derecho al olvido).
+    async fn delete(&self, id: &UserId) -> Result<(), RepositoryError>;
 }
@@ -1,9 +1,20 @@
+use chrono::{DateTime, Utc};
+
 use crate::user::domain::{
     entities::{user::User, user_auth_method::UserAuthMethod, user_password::UserPassword},
-    vo::{AuthType, ValidationError},
+    services::{lockout_policy::locked_error, LockoutPolicy, PasswordHasher},
+    vo::{AuthType, OccurredAt},
+    validations::{UserDomainError, CategoryError, TypeError},
 };
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
-use std::convert::TryFrom;
+
+/// Resultado de una verificación de contraseña correcta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordCheck {
+    Valid,
+    /// El hash usaba un algoritmo o parámetros antiguos y se recalculó; hay que
+    /// guardar el `UserPassword`.
+    Rehashed,
+}
 
 /// Servicio de dominio encargado de autenticar usuarios.
 /// Aquí no se manejan tokens JWT ni sesiones (eso iría en application/infrastructure),
@@ -11,38 +22,104 @@ use std::convert::TryFrom;
 pub struct AuthenticationService;
 
 impl AuthenticationService {
-    /// Autenticación mediante contraseña.
+    /// Autenticación mediante contraseña, aplicando `policy` en `now`.
+    ///
+    /// - En una cuenta suspendida o borrada sólo se verifica la contraseña, sin
+    ///   contar el intento: si es incorrecta falla con `password.mismatch`, como
+    ///   una cuenta activa, y sólo si es correcta con `status.invalid_status`.
+    /// - Con un bloqueo vigente falla con `password.locked` sin comprobar la
+    ///   contraseña ni contar el intento.
+    /// - Una contraseña incorrecta cuenta como fallo y falla con
+    ///   `password.mismatch`, o con `password.locked` si ese fallo bloquea la
+    ///   cuenta (registra `UserLockedOut`).
+    /// - Si es correcta, reinicia el contador y, si `hasher` indica que el hash
+    ///   está desactualizado, lo recalcula.
+    ///
+    /// En todos los casos `user` y `stored_password` pueden cambiar y deben
+    /// guardarse, también cuando devuelve error.
     pub fn authenticate_with_password(
-        user: &User,
+        user: &mut User,
         password: &str,
-        stored_password: &UserPassword,
-    ) -> Result<(), ValidationError> {
-        let parsed_hash = PasswordHash::new(stored_password.password_hash.as_str())
-            .map_err(|_| ValidationError::InvalidPassword)?;
-
-        Argon2::default()
-            .verify_password(password.as_bytes(), &parsed_hash)
-            .map_err(|_| ValidationError::InvalidPassword)
+        stored_password: &mut UserPassword,
+        hasher: &dyn PasswordHasher,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<PasswordCheck, UserDomainError> {
+        if user.status().is_suspended() || user.status().is_deleted() {
+            if !hasher.verify(password, &stored_password.password_hash)? {
+                return Err((CategoryError::Password, TypeError::Mismatch).into());
+            }
+            return Err((CategoryError::Status, TypeError::InvalidStatus { status: user.status().clone() }).into());
+        }
+        if let Some(until) = stored_password.locked_until.filter(|_| stored_password.is_locked(now)) {
+            return Err(locked_error(until));
+        }
+
+        let expired_lock = stored_password.locked_until;
+        if expired_lock.is_some() {
+            stored_password.unlock();
+            user.record_unlock();
+        }
+        if stored_password.failed_attempts > 0 && policy.window_expired(stored_password.last_failed_at, expired_lock, now) {
+            stored_password.reset_failed_attempts();
+        }
+
+        if !hasher.verify(password, &stored_password.password_hash)? {
+            stored_password.register_failed_attempt_at(now);
+            let attempts = u32::try_from(stored_password.failed_attempts).unwrap_or(0);
+
+            if let Some(duration) = policy.lock_duration(attempts) {
+                let until = now + duration;
+                stored_password.lock_until(until);
+                user.record_lockout(OccurredAt::from_datetime(until), attempts);
+                return Err(locked_error(until));
+            }
+            return Err((CategoryError::Password, TypeError::Mismatch).into());
+        }
+
+        if stored_password.failed_attempts > 0 {
+            stored_password.reset_failed_attempts();
+        }
+
+        if hasher.needs_rehash(&stored_password.password_hash) {
+            stored_password.update_password(hasher.hash(password)?, None)?;
+            return Ok(PasswordCheck::Rehashed);
+        }
+
+        Ok(PasswordCheck::Valid)
+    }
+
+    /// Levanta manualmente el bloqueo de la contraseña y reinicia el contador.
+    pub fn unlock(user: &mut User, stored_password: &mut UserPassword) -> Result<(), UserDomainError> {
+        if stored_password.locked_until.is_none() {
+            return Err((CategoryError::Password, TypeError::Unchanged { value: "unlocked".into() }).into());
+        }
+
+        stored_password.unlock();
+        stored_password.reset_failed_attempts();
+        user.record_unlock();
+        Ok(())
     }
 
     /// Autenticación mediante proveedor externo (ej: Google, Microsoft).
     pub fn authenticate_with_provider(
-        user: &User,
+        _user: &User,
         auth_methods: &[UserAuthMethod],
         auth_type: AuthType,
         provider_user_id: &str,
-    ) -> Result<(), ValidationError> {
+    ) -> Result<(), UserDomainError> {
         // Filtrar solo los métodos activos de ese tipo
         let method = auth_methods.iter().find(|m| m.auth_type == auth_type);
 
         match method {
             Some(m) if m.provider_user_id.as_deref() == Some(provider_user_id) => Ok(()),
-            _ => Err(ValidationError::InvalidAuthType),
+            Some(_) => Err((CategoryError::AuthMethod, TypeError::Mismatch).into()),
+            None => Err((CategoryError::AuthMethod, TypeError::Missing).into()),
         }
     }
 
     /// Verifica si el usuario tiene un método de autenticación válido.
-    pub fn supports_auth_type(user: &User, methods: &[UserAuthMethod], auth_type: AuthType) -> bool {
+    pub fn supports_auth_type(_user: &User, methods: &[UserAuthMethod], auth_type: AuthType) -> bool {
         methods.iter().any(|m| m.auth_type == auth_type)
     }
 }
@@ -0,0 +1,32 @@
+use crate::user::domain::{
+    vo::PlainPassword,
+    validations::{UserDomainError, CategoryError, TypeError},
+};
+
+/// Consulta de contraseñas filtradas por rangos de SHA-1 (k-anonimato).
+///
+/// El dominio sólo entrega los 5 primeros caracteres del hash; el adaptador
+/// devuelve los sufijos conocidos con ese prefijo y la comparación se hace
+/// aquí, así que la contraseña nunca sale completa aunque la fuente sea
+/// remota.
+pub trait BreachedPasswordSource: Send + Sync {
+    /// Sufijos (35 caracteres hexadecimales en mayúsculas) y número de
+    /// apariciones de los hashes que empiezan por `prefix`.
+    fn range(&self, prefix: &str) -> Result<Vec<(String, u32)>, UserDomainError>;
+
+    /// Veces que aparece `password` en la fuente; 0 si no aparece.
+    fn occurrences(&self, password: &PlainPassword) -> Result<u32, UserDomainError> {
+        let hash = password.sha1_hex();
+        let (prefix, suffix) = hash.split_at(5);
+        let found = self.range(prefix)?.into_iter().find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix));
+        Ok(found.map_or(0, |(_, count)| count.max(1)))
+    }
+
+    /// Falla con `password.breached` si `password` aparece en la fuente.
+    fn check(&self, password: &PlainPassword) -> Result<(), UserDomainError> {
+        if self.occurrences(password)? > 0 {
+            return Err((CategoryError::Password, TypeError::Breached).into());
+        }
+        Ok(())
+    }
+}
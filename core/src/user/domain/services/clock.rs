@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+
+/// Fuente de la hora actual, inyectable para poder probar caducidades.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Reloj del sistema.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
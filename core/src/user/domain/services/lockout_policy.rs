@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Utc};
+use std::net::IpAddr;
+
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Reglas contra ataques de fuerza bruta al iniciar sesión.
+///
+/// - Por cuenta: al llegar a `max_failed_attempts` fallos la contraseña se
+///   bloquea `base_lock_duration`; cada fallo posterior duplica el bloqueo,
+///   hasta `max_lock_duration`.
+/// - Ventana deslizante: el contador vuelve a cero si pasa `failure_window`
+///   desde el último fallo (o desde el fin del último bloqueo).
+/// - Por IP: `max_failed_attempts_per_ip` fallos dentro de `failure_window`,
+///   sobre cualquier cuenta, bloquean esa IP durante `ip_lock_duration`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    pub max_failed_attempts: u32,
+    pub base_lock_duration: Duration,
+    pub max_lock_duration: Duration,
+    pub failure_window: Duration,
+    pub max_failed_attempts_per_ip: u32,
+    pub ip_lock_duration: Duration,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failed_attempts: 5,
+            base_lock_duration: Duration::minutes(5),
+            max_lock_duration: Duration::hours(24),
+            failure_window: Duration::minutes(15),
+            max_failed_attempts_per_ip: 20,
+            ip_lock_duration: Duration::minutes(15),
+        }
+    }
+}
+
+impl LockoutPolicy {
+    /// Duración del bloqueo tras `failed_attempts` fallos seguidos, o `None`
+    /// si todavía no corresponde bloquear.
+    pub fn lock_duration(&self, failed_attempts: u32) -> Option<Duration> {
+        let threshold = self.max_failed_attempts.max(1);
+        if failed_attempts < threshold {
+            return None;
+        }
+
+        let doublings = (failed_attempts - threshold).min(30);
+        let duration = self.base_lock_duration.checked_mul(1 << doublings).unwrap_or(self.max_lock_duration);
+        Some(duration.min(self.max_lock_duration))
+    }
+
+    /// Indica si el contador de fallos ha caducado en `now`.
+    pub fn window_expired(
+        &self,
+        last_failed_at: Option<DateTime<Utc>>,
+        locked_until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> bool {
+        match last_failed_at.max(locked_until) {
+            Some(since) => now - since > self.failure_window,
+            None => true,
+        }
+    }
+
+    /// Falla con `password.locked` si la IP está bloqueada.
+    pub fn check_ip(&self, throttle: &dyn LoginThrottle, ip: IpAddr, now: DateTime<Utc>) -> Result<(), UserDomainError> {
+        match throttle.blocked_until(ip) {
+            Some(until) if until > now => Err(locked_error(until)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Anota un fallo desde `ip` y la bloquea si supera el umbral.
+    pub fn register_ip_failure(&self, throttle: &dyn LoginThrottle, ip: IpAddr, now: DateTime<Utc>) {
+        let failures = throttle.record_failure(ip, now, now - self.failure_window);
+        if self.max_failed_attempts_per_ip > 0 && failures >= self.max_failed_attempts_per_ip {
+            throttle.block(ip, now + self.ip_lock_duration);
+        }
+    }
+}
+
+/// Contadores de intentos fallidos por IP.
+///
+/// Son independientes de la cuenta atacada, así que no se guardan en el
+/// agregado; el adaptador decide dónde (memoria, caché compartida, ...).
+pub trait LoginThrottle: Send + Sync {
+    /// Anota un fallo en `at` y devuelve cuántos hay desde `since`.
+    fn record_failure(&self, ip: IpAddr, at: DateTime<Utc>, since: DateTime<Utc>) -> u32;
+
+    fn block(&self, ip: IpAddr, until: DateTime<Utc>);
+
+    fn blocked_until(&self, ip: IpAddr) -> Option<DateTime<Utc>>;
+
+    /// Olvida fallos y bloqueos de la IP.
+    fn clear(&self, ip: IpAddr);
+}
+
+/// `password.locked`, con la hora de desbloqueo en `until`.
+pub(crate) fn locked_error(until: DateTime<Utc>) -> UserDomainError {
+    (CategoryError::Password, TypeError::Locked { until: until.to_rfc3339() }).into()
+}
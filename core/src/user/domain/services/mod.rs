@@ -0,0 +1,25 @@
+pub mod authentication_service;
+pub mod breached_passwords;
+pub mod clock;
+pub mod lockout_policy;
+pub mod password_hasher;
+pub mod password_history;
+pub mod password_reset_notifier;
+pub mod password_reset_service;
+pub mod session_service;
+pub mod subscription_service;
+pub mod user_registration_service;
+
+pub use authentication_service::{AuthenticationService, PasswordCheck};
+pub use breached_passwords::BreachedPasswordSource;
+pub use clock::{Clock, SystemClock};
+pub use lockout_policy::{LockoutPolicy, LoginThrottle};
+pub use password_hasher::PasswordHasher;
+pub use password_history::PasswordHistoryPolicy;
+pub use password_reset_notifier::PasswordResetNotifier;
+pub use password_reset_service::{PasswordResetPolicy, PasswordResetService};
+pub use session_service::{AccessToken, AccessTokenClaims, AccessTokenCodec, SessionPolicy, SessionService};
+pub use subscription_service::SubscriptionService;
+pub use user_registration_service::{
+    RegistrationError, RegistrationPolicy, RegistrationProfile, RegistrationRequest, UserRegistrationService,
+};
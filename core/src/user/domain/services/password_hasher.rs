@@ -0,0 +1,24 @@
+use crate::user::domain::validations::UserDomainError;
+
+/// Puerto para derivar y verificar hashes de contraseña.
+///
+/// El dominio sólo maneja la cadena codificada (PHC `$argon2id$...` o MCF
+/// `$2b$...`); el algoritmo y sus parámetros los decide el adaptador.
+pub trait PasswordHasher: Send + Sync {
+    /// Deriva un hash nuevo con sal aleatoria.
+    fn hash(&self, password: &str) -> Result<String, UserDomainError>;
+
+    /// `Ok(false)` si la contraseña no coincide; `Err` si el hash es ilegible.
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError>;
+
+    /// `true` si `hash` no usa el algoritmo o los parámetros actuales y conviene
+    /// recalcularlo en el próximo inicio de sesión correcto.
+    fn needs_rehash(&self, _hash: &str) -> bool {
+        false
+    }
+
+    /// Si este adaptador sabe verificar `hash`.
+    fn recognizes(&self, _hash: &str) -> bool {
+        true
+    }
+}
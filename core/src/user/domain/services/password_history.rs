@@ -0,0 +1,80 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::user::domain::{
+    entities::user_password::UserPassword,
+    services::PasswordHasher,
+    vo::PlainPassword,
+    validations::{UserDomainError, CategoryError, TypeError},
+};
+
+/// Reglas de rotación de contraseñas.
+///
+/// - `remember`: la contraseña nueva no puede coincidir con la actual ni con
+///   las anteriores hasta sumar `remember`; 0 lo desactiva.
+/// - `min_age`: tiempo mínimo entre dos cambios hechos por el usuario, para
+///   que no pueda encadenar `remember` cambios y volver a la de siempre. No
+///   se aplica al restablecer con token.
+///
+/// Cada tenant configura la suya en su `UserService`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHistoryPolicy {
+    pub remember: usize,
+    pub min_age: Duration,
+}
+
+impl Default for PasswordHistoryPolicy {
+    fn default() -> Self {
+        Self {
+            remember: 5,
+            min_age: Duration::zero(),
+        }
+    }
+}
+
+impl PasswordHistoryPolicy {
+    /// Falla con `password.rate_limited` si la contraseña se cambió hace
+    /// menos de `min_age`.
+    pub fn check_min_age(&self, password: &UserPassword, now: DateTime<Utc>) -> Result<(), UserDomainError> {
+        let Some(changed_at) = password.password_changed_at else {
+            return Ok(());
+        };
+        if now < changed_at + self.min_age {
+            let retry_after = (changed_at + self.min_age).to_rfc3339();
+            return Err((CategoryError::Password, TypeError::RateLimited { retry_after }).into());
+        }
+        Ok(())
+    }
+
+    /// Falla con `password.reused` si `new_password` coincide con alguna de
+    /// las contraseñas recordadas.
+    ///
+    /// Verifica un hash por cada entrada del historial. Un hash que el hasher
+    /// ya no reconoce cuenta como distinto.
+    pub fn check_reuse(
+        &self,
+        password: &UserPassword,
+        new_password: &PlainPassword,
+        hasher: &dyn PasswordHasher,
+    ) -> Result<(), UserDomainError> {
+        let reused = std::iter::once(&password.password_hash)
+            .chain(&password.password_history)
+            .take(self.remember)
+            .any(|hash| hasher.verify(new_password.as_str(), hash).unwrap_or(false));
+        if reused {
+            return Err((CategoryError::Password, TypeError::Reused { count: self.remember as u32 }).into());
+        }
+        Ok(())
+    }
+
+    /// Comprueba la reutilización y sustituye el hash, recordando el anterior.
+    pub fn replace(
+        &self,
+        password: &mut UserPassword,
+        new_password: &PlainPassword,
+        hasher: &dyn PasswordHasher,
+        now: DateTime<Utc>,
+    ) -> Result<(), UserDomainError> {
+        self.check_reuse(password, new_password, hasher)?;
+        password.rotate_password(hasher.hash(new_password.as_str())?, self.remember.saturating_sub(1), now)
+    }
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::user::domain::vo::Email;
+
+/// Entrega al usuario el token de restablecimiento en claro.
+///
+/// Se invoca antes de confirmar la solicitud: si falla, no se guarda el
+/// token ni empieza a contar el intervalo entre solicitudes.
+#[async_trait]
+pub trait PasswordResetNotifier: Send + Sync {
+    async fn send_reset_token(&self, email: &Email, token: &str, expires_at: DateTime<Utc>) -> anyhow::Result<()>;
+}
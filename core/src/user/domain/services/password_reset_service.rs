@@ -0,0 +1,131 @@
+use chrono::{DateTime, Duration, Utc};
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+
+use crate::user::domain::{
+    entities::{user::User, user_password::UserPassword, user_session::UserSession},
+    services::{PasswordHasher, PasswordHistoryPolicy, SessionService},
+    vo::{OccurredAt, PlainPassword},
+    validations::{UserDomainError, CategoryError, TypeError},
+};
+
+/// Reglas del restablecimiento de contraseña.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordResetPolicy {
+    /// Vigencia de cada token.
+    pub token_ttl: Duration,
+    /// Tiempo mínimo entre dos solicitudes para la misma cuenta.
+    pub min_request_interval: Duration,
+}
+
+impl Default for PasswordResetPolicy {
+    fn default() -> Self {
+        Self {
+            token_ttl: Duration::hours(1),
+            min_request_interval: Duration::minutes(5),
+        }
+    }
+}
+
+/// Servicio de dominio del restablecimiento de contraseña.
+///
+/// Los tokens son 32 bytes aleatorios en hexadecimal. `UserPassword` sólo
+/// guarda su SHA-256: el token en claro sólo lo devuelve `request`, para
+/// que la aplicación lo entregue con un `PasswordResetNotifier`.
+pub struct PasswordResetService;
+
+impl PasswordResetService {
+    /// Emite un token nuevo, que sustituye al anterior, y lo devuelve en claro.
+    ///
+    /// Falla con `reset_token.rate_limited` si la cuenta pidió otro hace menos
+    /// de `min_request_interval`.
+    pub fn request(
+        user: &mut User,
+        password: &mut UserPassword,
+        policy: &PasswordResetPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<String, UserDomainError> {
+        if user.status().is_deleted() {
+            return Err((CategoryError::Status, TypeError::InvalidStatus { status: user.status().clone() }).into());
+        }
+        if let Some(requested_at) = password.reset_requested_at
+            && now < requested_at + policy.min_request_interval
+        {
+            let retry_after = (requested_at + policy.min_request_interval).to_rfc3339();
+            return Err((CategoryError::ResetToken, TypeError::RateLimited { retry_after }).into());
+        }
+
+        let token = Self::generate_token();
+        let expires_at = now + policy.token_ttl;
+        password.set_reset_token(Self::hash_token(&token), expires_at);
+        password.reset_requested_at = Some(now);
+        user.record_password_reset_requested(OccurredAt::from_datetime(expires_at));
+
+        Ok(token)
+    }
+
+    /// Cambia la contraseña con un token válido.
+    ///
+    /// El token queda consumido, se levanta cualquier bloqueo por intentos
+    /// fallidos y se cierran todas las sesiones. Devuelve cuántas sesiones
+    /// activas se cerraron.
+    ///
+    /// La contraseña nueva no puede repetir las recordadas en `history`, pero
+    /// no se exige su `min_age`. Una cuenta borrada falla con
+    /// `status.invalid_status` aunque conserve un token.
+    #[allow(clippy::too_many_arguments)]
+    pub fn reset(
+        user: &mut User,
+        password: &mut UserPassword,
+        sessions: &mut [UserSession],
+        token: &str,
+        new_password: &PlainPassword,
+        hasher: &dyn PasswordHasher,
+        history: &PasswordHistoryPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<usize, UserDomainError> {
+        if user.status().is_deleted() {
+            return Err((CategoryError::Status, TypeError::InvalidStatus { status: user.status().clone() }).into());
+        }
+        let (Some(stored), Some(expires_at)) = (password.reset_token.as_deref(), password.reset_token_expires) else {
+            return Err((CategoryError::ResetToken, TypeError::Missing).into());
+        };
+        if Self::hash_token(token) != stored {
+            return Err((CategoryError::ResetToken, TypeError::Mismatch).into());
+        }
+        if expires_at <= now {
+            return Err((CategoryError::ResetToken, TypeError::Expired).into());
+        }
+        history.replace(password, new_password, hasher, now)?;
+        password.clear_reset_token();
+        password.reset_failed_attempts();
+        if password.locked_until.is_some() {
+            password.unlock();
+            user.record_unlock();
+        }
+
+        let revoked = SessionService::logout_everywhere(sessions);
+        user.record_password_changed(true);
+        Ok(revoked)
+    }
+
+    /// SHA-256 en hexadecimal; basta un hash rápido porque el token tiene
+    /// 256 bits de entropía.
+    pub fn hash_token(token: &str) -> String {
+        Self::to_hex(&Sha256::digest(token.as_bytes()))
+    }
+
+    fn generate_token() -> String {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        Self::to_hex(&bytes)
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+    }
+}
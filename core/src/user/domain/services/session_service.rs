@@ -0,0 +1,160 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use uuid::Uuid;
+
+use crate::user::domain::{
+    entities::{user::User, user_session::UserSession},
+    validations::{UserDomainError, CategoryError, TypeError},
+};
+
+/// Contenido firmado de un token de acceso.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    /// Usuario.
+    pub sub: Uuid,
+    /// Sesión que emitió el token.
+    pub sid: Uuid,
+    /// `access_token_version` de la sesión al emitirlo.
+    pub ver: i32,
+    /// Emisión y caducidad, en segundos Unix.
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Firma y verificación de tokens de acceso (JWT u otro formato).
+pub trait AccessTokenCodec: Send + Sync {
+    fn encode(&self, claims: &AccessTokenClaims) -> Result<String, UserDomainError>;
+
+    /// Comprueba la firma y devuelve los claims, sin mirar fechas: la
+    /// caducidad la decide `SessionService` con el `Clock` inyectado.
+    fn decode(&self, token: &str) -> Result<AccessTokenClaims, UserDomainError>;
+}
+
+/// Duraciones de sesiones y tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionPolicy {
+    pub access_token_ttl: Duration,
+    pub session_ttl: Duration,
+}
+
+impl Default for SessionPolicy {
+    fn default() -> Self {
+        Self {
+            access_token_ttl: Duration::minutes(15),
+            session_ttl: Duration::days(30),
+        }
+    }
+}
+
+/// Token de acceso emitido; `Debug` no muestra el token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Debug for AccessToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AccessToken")
+            .field("token", &"<redacted>")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Servicio de dominio de sesiones y tokens de acceso.
+///
+/// Cada token lleva el id de su sesión y la `access_token_version` vigente al
+/// emitirlo. Cerrar la sesión o llamar a `invalidate_tokens` deja inservibles
+/// los tokens ya emitidos aunque su firma y su caducidad sigan siendo válidas.
+pub struct SessionService;
+
+impl SessionService {
+    /// Abre una sesión tras un inicio de sesión correcto.
+    pub fn open(
+        user: &User,
+        policy: &SessionPolicy,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<UserSession, UserDomainError> {
+        if user.status().is_suspended() || user.status().is_deleted() {
+            return Err((CategoryError::Status, TypeError::InvalidStatus { status: user.status().clone() }).into());
+        }
+        let user_id = user.id().as_uuid();
+        UserSession::new_at(Uuid::new_v4(), user_id, None, now + policy.session_ttl, ip_address, user_agent, None, now)
+    }
+
+    /// Emite un token de acceso para `session`; no dura más que la sesión.
+    pub fn issue(
+        session: &UserSession,
+        codec: &dyn AccessTokenCodec,
+        policy: &SessionPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<AccessToken, UserDomainError> {
+        if !session.is_valid_at(now) {
+            return Err(Self::revoked());
+        }
+
+        let expires_at = (now + policy.access_token_ttl).min(session.expires_at);
+        let claims = AccessTokenClaims {
+            sub: session.user_id,
+            sid: session.session_id,
+            ver: session.access_token_version,
+            iat: now.timestamp(),
+            exp: expires_at.timestamp(),
+        };
+        Ok(AccessToken { token: codec.encode(&claims)?, expires_at })
+    }
+
+    /// Comprueba unos claims ya verificados contra el estado de `user` y sus
+    /// `sessions` en `now`.
+    ///
+    /// - `session.expired` si el token caducó.
+    /// - `session.revoked` si la sesión no existe, se cerró o caducó, si la
+    ///   versión del token ya no es la vigente, o si la cuenta está
+    ///   suspendida o borrada.
+    pub fn verify(
+        claims: &AccessTokenClaims,
+        user: &User,
+        sessions: &[UserSession],
+        now: DateTime<Utc>,
+    ) -> Result<(), UserDomainError> {
+        if claims.exp <= now.timestamp() {
+            return Err((CategoryError::Session, TypeError::Expired).into());
+        }
+        if user.id().as_uuid() != claims.sub || user.status().is_suspended() || user.status().is_deleted() {
+            return Err(Self::revoked());
+        }
+
+        let current = sessions
+            .iter()
+            .find(|session| session.session_id == claims.sid && session.user_id == claims.sub)
+            .is_some_and(|session| session.is_valid_at(now) && session.access_token_version == claims.ver);
+        if !current {
+            return Err(Self::revoked());
+        }
+        Ok(())
+    }
+
+    /// Cierra la sesión e invalida sus tokens.
+    pub fn logout(session: &mut UserSession) {
+        session.terminate();
+        session.invalidate_tokens();
+    }
+
+    /// Cierra todas las sesiones activas; devuelve cuántas.
+    pub fn logout_everywhere(sessions: &mut [UserSession]) -> usize {
+        let mut closed = 0;
+        for session in sessions.iter_mut().filter(|session| session.is_active) {
+            Self::logout(session);
+            closed += 1;
+        }
+        closed
+    }
+
+    fn revoked() -> UserDomainError {
+        (CategoryError::Session, TypeError::Revoked).into()
+    }
+}
@@ -1,8 +1,9 @@
-use chrono::{DateTime, Duration, Utc};
+use chrono::{Duration, Utc};
 
 use crate::user::domain::{
     entities::user_subscription::UserSubscription,
-    vo::{SubscriptionStatus, SubscriptionTier, ValidationError},
+    vo::{SubscriptionStatus, SubscriptionTier},
+    validations::{UserDomainError, CategoryError, TypeError},
 };
 
 /// Servicio de dominio para la gestión de suscripciones de usuario.
@@ -13,7 +14,7 @@ impl SubscriptionService {
     pub fn create_subscription(
         tier: SubscriptionTier,
         duration_days: i64,
-    ) -> Result<UserSubscription, ValidationError> {
+    ) -> Result<UserSubscription, UserDomainError> {
         let now = Utc::now();
         let expires_at = now + Duration::days(duration_days);
 
@@ -35,9 +36,9 @@ impl SubscriptionService {
     pub fn renew_subscription(
         subscription: &mut UserSubscription,
         duration_days: i64,
-    ) -> Result<(), ValidationError> {
+    ) -> Result<(), UserDomainError> {
         if !subscription.auto_renew {
-            return Err(ValidationError::InvalidSubscriptionStatus);
+            return Err((CategoryError::Subscription, TypeError::InvalidState { state: "auto_renew_disabled".into() }).into());
         }
 
         let now = Utc::now();
@@ -76,9 +77,9 @@ impl SubscriptionService {
     pub fn change_tier(
         subscription: &mut UserSubscription,
         new_tier: SubscriptionTier,
-    ) -> Result<(), ValidationError> {
+    ) -> Result<(), UserDomainError> {
         if subscription.status != SubscriptionStatus::Active {
-            return Err(ValidationError::InvalidSubscriptionStatus);
+            return Err((CategoryError::Subscription, TypeError::InvalidState { state: subscription.status.as_str().into() }).into());
         }
 
         subscription.tier = new_tier;
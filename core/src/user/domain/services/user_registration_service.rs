@@ -1,58 +1,247 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::Arc;
+
 use uuid::Uuid;
-use chrono::Utc;
 
 use crate::user::domain::{
-    entities::user::User,
-    vo::{Email, Username, ValidationError},
-    repositories::user_repository::UserRepository,
-    events::user_registered::UserRegisteredEvent,
+    aggregates::UserAggregate,
+    entities::{User, UserAuthMethod, UserGdprConsent, UserPassword, UserProfile, UserRole},
+    repositories::{RepositoryError, UnitOfWorkFactory},
+    services::{BreachedPasswordSource, PasswordHasher},
+    validations::UserDomainError,
+    vo::{AuthType, ConsentType, Email, Locale, PasswordPolicy, PlainPassword, RoleName, Timezone, Username},
 };
 
+/// Datos de perfil opcionales capturados en el alta.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegistrationProfile {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub display_name: Option<String>,
+    pub locale: Option<Locale>,
+    pub timezone: Option<Timezone>,
+}
+
+/// Solicitud de alta con los datos crudos del formulario.
+/// `consents` lista los consentimientos que el usuario aceptó.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegistrationRequest {
+    pub email: String,
+    pub username: Option<String>,
+    pub password: String,
+    pub consents: Vec<ConsentType>,
+    pub profile: Option<RegistrationProfile>,
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+/// Reglas configurables del alta.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistrationPolicy {
+    /// Rol asignado a todo usuario nuevo; debe existir en el repositorio.
+    pub default_role: RoleName,
+    pub required_consents: Vec<ConsentType>,
+    pub password: PasswordPolicy,
+}
+
+impl Default for RegistrationPolicy {
+    fn default() -> Self {
+        Self {
+            default_role: RoleName::new("customer").expect("rol por defecto válido"),
+            required_consents: vec![ConsentType::TermsOfService, ConsentType::PrivacyPolicy],
+            password: PasswordPolicy::default(),
+        }
+    }
+}
+
+/// Motivos por los que un alta puede fallar.
+#[derive(Debug)]
+pub enum RegistrationError {
+    /// Un dato del formulario no es válido (email, username, perfil, contraseña).
+    InvalidField(UserDomainError),
+    EmailTaken(Email),
+    UsernameTaken(Username),
+    MissingConsent(ConsentType),
+    /// El rol por defecto de la política no existe: error de configuración.
+    DefaultRoleMissing(RoleName),
+    Repository(RepositoryError),
+}
+
+impl RegistrationError {
+    /// Código estable y legible por máquinas, p. ej. `user.registration.email_taken`.
+    pub fn code(&self) -> String {
+        match self {
+            RegistrationError::InvalidField(err) => err.code(),
+            RegistrationError::EmailTaken(_) => "user.registration.email_taken".into(),
+            RegistrationError::UsernameTaken(_) => "user.registration.username_taken".into(),
+            RegistrationError::MissingConsent(_) => "user.registration.missing_consent".into(),
+            RegistrationError::DefaultRoleMissing(_) => "user.registration.default_role_missing".into(),
+            RegistrationError::Repository(err) => err.code(),
+        }
+    }
+}
+
+impl fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::InvalidField(err) => write!(f, "{err}"),
+            RegistrationError::EmailTaken(email) => write!(f, "email '{email}' is already registered"),
+            RegistrationError::UsernameTaken(username) => write!(f, "username '{username}' is already taken"),
+            RegistrationError::MissingConsent(consent) => write!(f, "consent '{consent}' is required"),
+            RegistrationError::DefaultRoleMissing(role) => write!(f, "default role '{role}' does not exist"),
+            RegistrationError::Repository(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl StdError for RegistrationError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            RegistrationError::InvalidField(err) => Some(err),
+            RegistrationError::Repository(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<UserDomainError> for RegistrationError {
+    fn from(err: UserDomainError) -> Self {
+        RegistrationError::InvalidField(err)
+    }
+}
+
+impl From<RepositoryError> for RegistrationError {
+    fn from(err: RepositoryError) -> Self {
+        RegistrationError::Repository(err)
+    }
+}
+
 /// Servicio de dominio para el registro de usuarios.
-pub struct UserRegistrationService<'a, R: UserRepository> {
-    repository: &'a mut R,
+///
+/// Valida el formulario, comprueba unicidad, deriva el hash de la contraseña,
+/// exige los consentimientos obligatorios y asigna el rol por defecto. Las
+/// comprobaciones y la escritura ocurren dentro de una misma unidad de
+/// trabajo; los eventos se publican al confirmarla.
+pub struct UserRegistrationService {
+    uow: Arc<dyn UnitOfWorkFactory>,
+    hasher: Arc<dyn PasswordHasher>,
+    policy: RegistrationPolicy,
+    breached: Option<Arc<dyn BreachedPasswordSource>>,
 }
 
-impl<'a, R: UserRepository> UserRegistrationService<'a, R> {
-    pub fn new(repository: &'a mut R) -> Self {
-        Self { repository }
+impl UserRegistrationService {
+    pub fn new(uow: Arc<dyn UnitOfWorkFactory>, hasher: Arc<dyn PasswordHasher>) -> Self {
+        Self { uow, hasher, policy: RegistrationPolicy::default(), breached: None }
+    }
+
+    pub fn with_policy(mut self, policy: RegistrationPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Rechaza contraseñas que aparezcan en `source`.
+    pub fn with_breached_passwords(mut self, source: Arc<dyn BreachedPasswordSource>) -> Self {
+        self.breached = Some(source);
+        self
+    }
+
+    pub fn policy(&self) -> &RegistrationPolicy {
+        &self.policy
     }
 
-    /// Registra un nuevo usuario validando email y username.
-    pub fn register_user(
-        &mut self,
-        email_raw: &str,
-        username_raw: &str,
-    ) -> Result<User, ValidationError> {
-        let email = Email::try_from(email_raw)?;
-        let username = Username::try_from(username_raw)?;
+    /// Registra un nuevo usuario y devuelve el agregado persistido.
+    ///
+    /// Primero se valida todo lo que no requiere E/S, para no consultar el
+    /// repositorio ni derivar el hash de un formulario inválido.
+    pub async fn register_user(&self, request: RegistrationRequest) -> Result<UserAggregate, RegistrationError> {
+        let email = Email::try_from(request.email.as_str())?;
+        let username = request.username.as_deref().map(Username::try_from).transpose()?;
+
+        let personal: Vec<&str> = std::iter::once(email.as_str()).chain(username.as_ref().map(Username::as_str)).collect();
+        let password = PlainPassword::new(&request.password, &self.policy.password, &personal)?;
+        if let Some(missing) = self.policy.required_consents.iter().find(|c| !request.consents.contains(c)) {
+            return Err(RegistrationError::MissingConsent(missing.clone()));
+        }
+
+        if let Some(breached) = &self.breached {
+            breached.check(&password)?;
+        }
+
+        let mut user = User::register(email.clone());
+        if let Some(username) = username.clone() {
+            user.assign_username(username)?;
+        }
+
+        let mut aggregate = UserAggregate::new(user);
+        let uid = aggregate.id();
+
+        if let Some(profile) = request.profile {
+            aggregate.set_profile(UserProfile::new(
+                Uuid::new_v4(),
+                uid,
+                profile.first_name,
+                profile.last_name,
+                profile.display_name,
+                None,
+                None,
+                None,
+                None,
+                profile.locale,
+                profile.timezone,
+                None,
+            )?);
+        }
+
+        let mut granted: Vec<ConsentType> = Vec::new();
+        for consent in request.consents {
+            if granted.contains(&consent) {
+                continue;
+            }
+            granted.push(consent.clone());
+            aggregate.add_gdpr_consent(UserGdprConsent::new(
+                Uuid::new_v4(),
+                uid,
+                consent,
+                true,
+                None,
+                request.ip_address,
+                request.user_agent.as_deref(),
+                None,
+                None,
+            )?);
+        }
+
+        // Soltar `uow` en cualquier salida temprana deshace la transacción
+        let mut uow = self.uow.begin().await?;
 
-        // Verificar unicidad en repositorio
-        if self.repository.exists_by_email(&email)? {
-            return Err(ValidationError::InvalidEmail);
+        // Comprobación temprana; el índice único del repositorio cubre las carreras
+        if uow.users().exists_by_email(&email).await? {
+            return Err(RegistrationError::EmailTaken(email));
         }
-        if self.repository.exists_by_username(&username)? {
-            return Err(ValidationError::InvalidUsernameFormat);
+        if let Some(username) = &username && uow.users().exists_by_username(username).await? {
+            return Err(RegistrationError::UsernameTaken(username.clone()));
         }
 
-        let user = User {
-            user_id: Uuid::new_v4(),
-            email,
-            username: Some(username),
-            external_id: None,
-            phone: None,
-            status: Default::default(), // pending por defecto
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            deleted_at: None,
-        };
+        let role = uow
+            .roles()
+            .get_by_name(&self.policy.default_role)
+            .await?
+            .ok_or_else(|| RegistrationError::DefaultRoleMissing(self.policy.default_role.clone()))?;
+        aggregate.add_role(UserRole::new(Uuid::new_v4(), uid, role.role_id, None, None, None, None)?);
 
-        self.repository.save(&user)?;
+        let hash = self.hasher.hash(password.as_str())?;
+        aggregate.password = Some(UserPassword::new(Uuid::new_v4(), uid, hash, None, None, None, None, None, None, None)?);
+        aggregate.add_auth_method(UserAuthMethod::new(Uuid::new_v4(), uid, AuthType::Password, None, None, true, false, None, None)?);
 
-        // Publicar evento de dominio
-        let _event = UserRegisteredEvent::new(user.user_id, user.email.clone());
-        // Aquí podrías enviarlo a un bus de eventos
+        uow.save_aggregate(&mut aggregate).await.map_err(|err| match (err, username) {
+            (RepositoryError::Conflict { field: "email", .. }, _) => RegistrationError::EmailTaken(email),
+            (RepositoryError::Conflict { field: "username", .. }, Some(username)) => RegistrationError::UsernameTaken(username),
+            (other, _) => RegistrationError::Repository(other),
+        })?;
+        uow.commit().await?;
 
-        Ok(user)
+        Ok(aggregate)
     }
 }
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
 use std::fmt;
 
-use crate::user::domain::vo::UserStatus;
+use crate::user::domain::vo::{Locale, UserStatus};
+use crate::user::domain::validations::messages::ErrorMessages;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CategoryError {
@@ -21,6 +23,57 @@ pub enum CategoryError {
     SubscriptionStatus,
     ConsentType,
     OccurredAt,
+    Password,
+    Session,
+    Mfa,
+    Subscription,
+    ActivityLog,
+    Consent,
+    DisplayName,
+    AuthMethod,
+    UserRole,
+    Event,
+    Query,
+    ResetToken,
+    Version,
+}
+
+impl CategoryError {
+    /// Segmento estable usado en los códigos de error (`user.<categoria>.<detalle>`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            CategoryError::Id => "id",
+            CategoryError::ExternalId => "external_id",
+            CategoryError::Username => "username",
+            CategoryError::Email => "email",
+            CategoryError::Phone => "phone",
+            CategoryError::PhoneNumber => "phone_number",
+            CategoryError::PhoneCountryCode => "phone_country_code",
+            CategoryError::Status => "status",
+            CategoryError::Role => "role",
+            CategoryError::Locale => "locale",
+            CategoryError::Timezone => "timezone",
+            CategoryError::Gender => "gender",
+            CategoryError::AuthType => "auth_type",
+            CategoryError::SubscriptionTier => "subscription_tier",
+            CategoryError::SubscriptionStatus => "subscription_status",
+            CategoryError::ConsentType => "consent_type",
+            CategoryError::OccurredAt => "occurred_at",
+            CategoryError::Password => "password",
+            CategoryError::Session => "session",
+            CategoryError::Mfa => "mfa",
+            CategoryError::Subscription => "subscription",
+            CategoryError::ActivityLog => "activity_log",
+            CategoryError::Consent => "consent",
+            CategoryError::DisplayName => "display_name",
+            CategoryError::AuthMethod => "auth_method",
+            CategoryError::UserRole => "user_role",
+            CategoryError::Event => "event",
+            CategoryError::Query => "query",
+            CategoryError::ResetToken => "reset_token",
+            CategoryError::Version => "version",
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,6 +91,92 @@ pub enum TypeError {
     AlreadyVerified,
     InvalidStatus { status: UserStatus,},
     Transition { from: UserStatus, to: UserStatus, },
+    Expired,
+    Mismatch,
+    InvalidState { state: String,},
+    InvalidRange { start: String, end: String, },
+    Locked { until: String, },
+    RateLimited { retry_after: String, },
+    MissingCharacterClass { class: String, },
+    PersonalData,
+    TooWeak { strength: String, },
+    Breached,
+    Reused { count: u32, },
+    Revoked,
+}
+
+impl TypeError {
+    /// Segmento estable usado en los códigos de error (`user.<categoria>.<detalle>`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            TypeError::Empty => "empty",
+            TypeError::Missing => "missing",
+            TypeError::NotSupported => "not_supported",
+            TypeError::Format { .. } => "invalid_format",
+            TypeError::TooShort { .. } => "too_short",
+            TypeError::TooLong { .. } => "too_long",
+            TypeError::Unchanged { .. } => "unchanged",
+            TypeError::Characters { .. } => "invalid_characters",
+            TypeError::StartsWithChar { .. } => "starts_with",
+            TypeError::EndsWithChar { .. } => "ends_with",
+            TypeError::AlreadyVerified => "already_verified",
+            TypeError::InvalidStatus { .. } => "invalid_status",
+            TypeError::Transition { .. } => "invalid_transition",
+            TypeError::Expired => "expired",
+            TypeError::Mismatch => "mismatch",
+            TypeError::InvalidState { .. } => "invalid_state",
+            TypeError::InvalidRange { .. } => "invalid_range",
+            TypeError::Locked { .. } => "locked",
+            TypeError::RateLimited { .. } => "rate_limited",
+            TypeError::MissingCharacterClass { .. } => "missing_character_class",
+            TypeError::PersonalData => "contains_personal_data",
+            TypeError::TooWeak { .. } => "too_weak",
+            TypeError::Breached => "breached",
+            TypeError::Reused { .. } => "reused",
+            TypeError::Revoked => "revoked",
+        }
+    }
+
+    /// Parámetros estructurados del detalle (`short`, `long`, `format`, ...),
+    /// usados para interpolar los mensajes localizados.
+    pub fn params(&self) -> BTreeMap<&'static str, String> {
+        let mut params = BTreeMap::new();
+
+        match self {
+            TypeError::Format { format } => { params.insert("format", format.clone()); }
+            TypeError::TooShort { short } => { params.insert("short", short.to_string()); }
+            TypeError::TooLong { long } => { params.insert("long", long.to_string()); }
+            TypeError::Unchanged { value } | TypeError::Characters { value } => { params.insert("value", value.clone()); }
+            TypeError::StartsWithChar { start } => { params.insert("start", start.clone()); }
+            TypeError::EndsWithChar { end } => { params.insert("end", end.clone()); }
+            TypeError::InvalidStatus { status } => { params.insert("status", status.as_str().to_string()); }
+            TypeError::Transition { from, to } => {
+                params.insert("from", from.as_str().to_string());
+                params.insert("to", to.as_str().to_string());
+            }
+            TypeError::InvalidState { state } => { params.insert("state", state.clone()); }
+            TypeError::InvalidRange { start, end } => {
+                params.insert("start", start.clone());
+                params.insert("end", end.clone());
+            }
+            TypeError::Locked { until } => { params.insert("until", until.clone()); }
+            TypeError::RateLimited { retry_after } => { params.insert("retry_after", retry_after.clone()); }
+            TypeError::MissingCharacterClass { class } => { params.insert("class", class.clone()); }
+            TypeError::TooWeak { strength } => { params.insert("strength", strength.clone()); }
+            TypeError::Reused { count } => { params.insert("count", count.to_string()); }
+            TypeError::Empty
+            | TypeError::Missing
+            | TypeError::NotSupported
+            | TypeError::AlreadyVerified
+            | TypeError::Expired
+            | TypeError::Mismatch
+            | TypeError::PersonalData
+            | TypeError::Breached
+            | TypeError::Revoked => {}
+        }
+
+        params
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +185,46 @@ pub struct  UserDomainError {
     detail: TypeError,
 }
 
+impl UserDomainError {
+    pub const CODE_PREFIX: &'static str = "user";
+
+    pub fn new(category: CategoryError, detail: TypeError) -> Self {
+        Self { category, detail }
+    }
+
+    pub fn category(&self) -> &CategoryError {
+        &self.category
+    }
+
+    pub fn detail(&self) -> &TypeError {
+        &self.detail
+    }
+
+    /// Código estable y legible por máquinas, p. ej. `user.email.too_short`.
+    pub fn code(&self) -> String {
+        format!("{}.{}.{}", Self::CODE_PREFIX, self.category.code(), self.detail.code())
+    }
+
+    pub fn params(&self) -> BTreeMap<&'static str, String> {
+        self.detail.params()
+    }
+
+    /// Mensaje legible para el usuario final en el `Locale` indicado.
+    pub fn message(&self, locale: &Locale) -> String {
+        ErrorMessages::embedded().render(self, locale)
+    }
+
+    /// Representación pensada para el frontend (React/Tauri):
+    /// `{ "code": ..., "params": { ... }, "message": ... }`.
+    pub fn to_json(&self, locale: &Locale) -> serde_json::Value {
+        serde_json::json!({
+            "code": self.code(),
+            "params": self.params(),
+            "message": self.message(locale),
+        })
+    }
+}
+
 impl From<(CategoryError, TypeError)> for UserDomainError {
     fn from((category, detail): (CategoryError, TypeError)) -> Self {
         Self { category, detail }
@@ -54,7 +233,7 @@ impl From<(CategoryError, TypeError)> for UserDomainError {
 
 impl fmt::Display for UserDomainError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error {:?} -> {:?}", self.category, self.detail)
+        write!(f, "[{}] {}", self.code(), self.message(&Locale::default()))
     }
 }
 
@@ -0,0 +1,142 @@
+use include_dir::{include_dir, Dir};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::user::domain::vo::Locale;
+use crate::user::domain::validations::UserDomainError;
+
+/// Catálogos embebidos: un archivo `<bcp47>.json` por idioma.
+/// Para añadir o corregir traducciones basta con editar/crear archivos en
+/// `validations/messages/`, sin tocar código Rust.
+static MESSAGES_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/user/domain/validations/messages");
+
+static EMBEDDED: LazyLock<ErrorMessages> = LazyLock::new(|| {
+    let mut messages = ErrorMessages::default();
+
+    for file in MESSAGES_DIR.files() {
+        if let Some(content) = file.contents_utf8() {
+            let catalog = ErrorCatalog::from_json(content)
+                .unwrap_or_else(|| panic!("Invalid error catalog {}", file.path().display()));
+            messages.add(catalog);
+        }
+    }
+
+    messages
+});
+
+/// Catálogo de mensajes de error para un idioma.
+///
+/// - `fields`: nombre legible de cada categoría (`email`, `phone`, ...).
+/// - `details`: plantilla por tipo de error (`too_short`, ...), con `{field}`
+///   y los parámetros del error como marcadores.
+/// - `codes`: plantillas específicas por código completo, que tienen prioridad.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorCatalog {
+    locale: String,
+    fields: HashMap<String, String>,
+    details: HashMap<String, String>,
+    codes: HashMap<String, String>,
+}
+
+impl ErrorCatalog {
+    pub fn from_json(content: &str) -> Option<Self> {
+        let value: JsonValue = serde_json::from_str(content).ok()?;
+
+        Some(Self {
+            locale: value.get("locale")?.as_str()?.to_string(),
+            fields: Self::section(&value, "fields"),
+            details: Self::section(&value, "details"),
+            codes: Self::section(&value, "codes"),
+        })
+    }
+
+    fn section(value: &JsonValue, name: &str) -> HashMap<String, String> {
+        value
+            .get(name)
+            .and_then(JsonValue::as_object)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|(key, text)| text.as_str().map(|t| (key.clone(), t.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Devuelve la plantilla para el error, o `None` si el catálogo no la cubre.
+    fn template(&self, error: &UserDomainError) -> Option<&String> {
+        self.codes
+            .get(&error.code())
+            .or_else(|| self.details.get(error.detail().code()))
+    }
+
+    fn field(&self, error: &UserDomainError) -> String {
+        let key = error.category().code();
+        self.fields.get(key).cloned().unwrap_or_else(|| key.to_string())
+    }
+}
+
+/// Conjunto de catálogos con resolución de idioma:
+/// etiqueta exacta → mismo idioma → `Locale::DEFAULT`.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorMessages {
+    catalogs: Vec<ErrorCatalog>,
+}
+
+impl ErrorMessages {
+    /// Catálogos compilados dentro de la librería.
+    pub fn embedded() -> &'static ErrorMessages {
+        &EMBEDDED
+    }
+
+    /// Registra (o reemplaza) el catálogo de un idioma.
+    pub fn add(&mut self, catalog: ErrorCatalog) {
+        self.catalogs.retain(|c| !c.locale.eq_ignore_ascii_case(&catalog.locale));
+        self.catalogs.push(catalog);
+    }
+
+    pub fn locales(&self) -> Vec<&str> {
+        self.catalogs.iter().map(|c| c.locale()).collect()
+    }
+
+    pub fn catalog_for(&self, locale: &Locale) -> Option<&ErrorCatalog> {
+        let tag = locale.as_str();
+
+        self.catalogs
+            .iter()
+            .find(|c| c.locale.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                self.catalogs.iter().find(|c| {
+                    c.locale
+                        .split('-')
+                        .next()
+                        .is_some_and(|lang| lang.eq_ignore_ascii_case(locale.language()))
+                })
+            })
+            .or_else(|| self.catalogs.iter().find(|c| c.locale == Locale::DEFAULT))
+    }
+
+    /// Renderiza el mensaje del error en el idioma pedido.
+    /// Si no hay plantilla disponible se devuelve el código estable.
+    pub fn render(&self, error: &UserDomainError, locale: &Locale) -> String {
+        let Some(catalog) = self.catalog_for(locale) else {
+            return error.code();
+        };
+
+        let Some(template) = catalog.template(error) else {
+            return error.code();
+        };
+
+        let mut message = template.replace("{field}", &catalog.field(error));
+        for (name, value) in error.params() {
+            message = message.replace(&format!("{{{name}}}"), &value);
+        }
+
+        message
+    }
+}
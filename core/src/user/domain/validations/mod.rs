@@ -1,5 +1,7 @@
 pub mod errors;
+pub mod messages;
 pub mod regexes;
 
 pub use errors::*;
+pub use messages::{ErrorCatalog, ErrorMessages};
 pub use regexes::*;
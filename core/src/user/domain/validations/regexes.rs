@@ -9,7 +9,7 @@ pub struct ValidationRule {
 pub static EMAIL_REGEX: LazyLock<ValidationRule> = LazyLock::new(|| ValidationRule {
     name: "EMAIL_REGEX",
     regex: Regex::new(
-        r"^[A-Za-z0-9](?:[A-Za-z0-9._-]{2,63}[A-Za-z0-9])?@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*\.[A-Za-z]{2,24}$"
+        r"^[A-Za-z0-9](?:[A-Za-z0-9._-]{0,62}[A-Za-z0-9])?@[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?(?:\.[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?)*\.[A-Za-z]{2,24}$"
     ).expect("Invalid EMAIL_REGEX"),
 });
 
@@ -20,5 +20,5 @@ pub static ROLE_NAME_REGEX: LazyLock<ValidationRule> = LazyLock::new(|| Validati
 
 pub static USERNAME_REGEX: LazyLock<ValidationRule> = LazyLock::new(|| ValidationRule {
     name: "USERNAME_REGEX",
-    regex: Regex::new(r"^(?=.{6,30}$)[a-z][a-z0-9](?:[._-]?[a-z0-9])*$").expect("Invalid USERNAME_REGEX"),
+    regex: Regex::new(r"^[a-z][a-z0-9]+(?:[._-][a-z0-9]+)*$").expect("Invalid USERNAME_REGEX"),
 });
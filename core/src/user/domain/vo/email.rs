@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
@@ -9,7 +11,8 @@ use crate::user::domain::validations::{
     EMAIL_REGEX,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Email(String);
 
 impl Email {
@@ -52,6 +55,12 @@ impl Display for Email {
     }
 }
 
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<&str> for Email {
     type Error = UserDomainError;
 
@@ -67,3 +76,17 @@ impl FromStr for Email {
         Email::new(value)
     }
 }
+
+impl TryFrom<String> for Email {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
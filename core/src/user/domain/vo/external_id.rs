@@ -2,13 +2,16 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct ExternalId(String);
 
 impl ExternalId {
@@ -45,6 +48,12 @@ impl Display for ExternalId {
     }
 }
 
+impl AsRef<str> for ExternalId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<&str> for ExternalId {
     type Error = UserDomainError;
 
@@ -60,3 +69,17 @@ impl FromStr for ExternalId {
         Self::try_from(value)
     }
 }
+
+impl TryFrom<String> for ExternalId {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<ExternalId> for String {
+    fn from(value: ExternalId) -> Self {
+        value.0
+    }
+}
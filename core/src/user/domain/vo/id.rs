@@ -3,13 +3,16 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct UserId(Uuid);
 
 impl UserId {
@@ -17,7 +20,7 @@ impl UserId {
         Self(Uuid::new_v4())
     }
 
-    pub(crate) fn from_uuid(uuid: Uuid) -> Self {
+    pub fn from_uuid(uuid: Uuid) -> Self {
         Self(uuid)
     }
 
@@ -57,3 +60,17 @@ impl FromStr for UserId {
         Self::try_from(s)
     }
 }
+
+impl TryFrom<String> for UserId {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<UserId> for String {
+    fn from(value: UserId) -> Self {
+        value.as_string()
+    }
+}
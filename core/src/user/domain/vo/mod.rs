@@ -5,7 +5,9 @@ pub mod external_id;
 pub mod gender;
 pub mod locale;
 pub mod occurred_at;
+pub mod password_strength;
 pub mod phone;
+pub mod plain_password;
 pub mod role_name;
 pub mod subscription_status;
 pub mod subscription_tier;
@@ -21,7 +23,9 @@ pub use external_id::ExternalId;
 pub use gender::Gender;
 pub use locale::Locale;
 pub use occurred_at::OccurredAt;
+pub use password_strength::PasswordStrength;
 pub use phone::Phone;
+pub use plain_password::{PasswordPolicy, PlainPassword};
 pub use role_name::RoleName;
 pub use subscription_status::SubscriptionStatus;
 pub use subscription_tier::SubscriptionTier;
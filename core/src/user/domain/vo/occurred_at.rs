@@ -3,13 +3,16 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct OccurredAt(DateTime<Utc>);
 
 impl OccurredAt {
@@ -21,8 +24,8 @@ impl OccurredAt {
         Self(dt)
     }
 
-    pub fn value(&self) -> &DateTime<Utc> {
-        &self.0
+    pub fn value(&self) -> DateTime<Utc> {
+        self.0
     }
 }
 
@@ -52,3 +55,17 @@ impl FromStr for OccurredAt {
         Self::try_from(s)
     }
 }
+
+impl TryFrom<String> for OccurredAt {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<OccurredAt> for String {
+    fn from(value: OccurredAt) -> Self {
+        value.to_string()
+    }
+}
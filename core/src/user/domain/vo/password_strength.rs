@@ -0,0 +1,178 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Contraseñas tan frecuentes que se prueban primero en cualquier ataque,
+/// ordenadas de más a menos comunes. Se comparan tras normalizar mayúsculas,
+/// quitar dígitos y símbolos al inicio y al final y deshacer sustituciones
+/// tipo «l33t».
+const COMMON_PASSWORDS: &[&str] = &[
+    "password", "qwerty", "iloveyou", "admin", "welcome", "monkey", "dragon", "letmein", "football", "baseball",
+    "master", "sunshine", "princess", "shadow", "superman", "trustno", "michael", "jennifer", "charlie", "starwars",
+    "whatever", "freedom", "secret", "login", "passw", "pass", "abc", "asdf", "zxcvbn", "hello", "batman", "access",
+    "flower", "hottie", "loveme", "mustang", "ninja", "azerty", "solo", "cashier", "vendly",
+    "contraseña", "contrasena", "clave", "hola", "amor", "teamo", "tequiero", "bienvenido", "usuario", "administrador",
+    "colombia", "mexico", "argentina", "españa", "espana", "barcelona", "madrid", "futbol", "america", "caja", "cajero",
+    "tienda", "ventas",
+];
+
+/// Filas del teclado para detectar recorridos como `qwerty` o `asdf`.
+const KEYBOARD_ROWS: &[&str] = &["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm", "azertyuiop", "qsdfghjklm", "wxcvbn"];
+
+/// Fortaleza estimada, en la misma escala 0–4 que zxcvbn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    /// Menos de 10³ intentos.
+    VeryWeak,
+    /// Menos de 10⁶.
+    Weak,
+    /// Menos de 10⁸.
+    Fair,
+    /// Menos de 10¹⁰.
+    Strong,
+    VeryStrong,
+}
+
+impl PasswordStrength {
+    /// Estima cuántos intentos necesitaría un atacante (en log10) y la
+    /// fortaleza correspondiente.
+    ///
+    /// Es una versión reducida de zxcvbn: la contraseña entera se compara con
+    /// `COMMON_PASSWORDS` y con `personal` (email, nombre de usuario, ...); el
+    /// resto se trata como fuerza bruta sobre el alfabeto usado, contando las
+    /// repeticiones (`aaaa`) y secuencias (`abcd`, `4321`, `qwerty`) como un
+    /// único símbolo.
+    pub fn estimate(raw: &str, personal: &[&str]) -> (f64, Self) {
+        let guesses = Self::guesses_log10(raw, personal);
+        (guesses, Self::from_guesses_log10(guesses))
+    }
+
+    pub fn from_guesses_log10(guesses: f64) -> Self {
+        match guesses {
+            g if g < 3.0 => PasswordStrength::VeryWeak,
+            g if g < 6.0 => PasswordStrength::Weak,
+            g if g < 8.0 => PasswordStrength::Fair,
+            g if g < 10.0 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        }
+    }
+
+    /// Puntuación 0–4.
+    pub fn score(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordStrength::VeryWeak => "very_weak",
+            PasswordStrength::Weak => "weak",
+            PasswordStrength::Fair => "fair",
+            PasswordStrength::Strong => "strong",
+            PasswordStrength::VeryStrong => "very_strong",
+        }
+    }
+
+    fn guesses_log10(raw: &str, personal: &[&str]) -> f64 {
+        let chars: Vec<char> = raw.chars().collect();
+        if chars.is_empty() {
+            return 0.0;
+        }
+
+        // Palabra conocida con adornos: cuesta su posición más los adornos
+        let trimmed = raw.trim_matches(|c: char| !c.is_alphabetic() && c != '@' && c != '$');
+        let core = normalize(trimmed);
+        let decoration = (chars.len() - trimmed.chars().count()) as f64;
+        if let Some(rank) = COMMON_PASSWORDS.iter().position(|word| *word == core) {
+            return ((rank + 2) as f64).log10() + decoration;
+        }
+        if !core.is_empty() && personal.iter().any(|item| normalize(item) == core) {
+            return 1.0 + decoration;
+        }
+
+        let per_symbol = alphabet_size(&chars).log10();
+        let lowered: Vec<char> = raw.to_lowercase().chars().collect();
+        let mut guesses = 0.0;
+        let mut i = 0;
+        while i < lowered.len() {
+            let run = pattern_len(&lowered[i..]);
+            if run >= 3 {
+                guesses += per_symbol + (run as f64).log10();
+                i += run;
+            } else {
+                guesses += per_symbol;
+                i += 1;
+            }
+        }
+        guesses
+    }
+}
+
+impl Display for PasswordStrength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Minúsculas y sustituciones tipo «l33t» deshechas.
+fn normalize(raw: &str) -> String {
+    raw.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            '4' | '@' => 'a',
+            '3' => 'e',
+            '1' | '!' => 'i',
+            '0' => 'o',
+            '$' | '5' => 's',
+            '7' => 't',
+            other => other,
+        })
+        .collect()
+}
+
+/// Tamaño del alfabeto según las clases de caracteres presentes.
+fn alphabet_size(chars: &[char]) -> f64 {
+    let mut size = 0.0;
+    if chars.iter().any(|c| c.is_ascii_lowercase()) {
+        size += 26.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_uppercase()) {
+        size += 26.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_digit()) {
+        size += 10.0;
+    }
+    if chars.iter().any(|c| c.is_ascii_punctuation() || *c == ' ') {
+        size += 33.0;
+    }
+    if chars.iter().any(|c| !c.is_ascii()) {
+        size += 100.0;
+    }
+    size
+}
+
+/// Longitud de la repetición o secuencia más larga que empieza en `chars[0]`.
+fn pattern_len(chars: &[char]) -> usize {
+    let repeat = chars.iter().take_while(|c| **c == chars[0]).count();
+
+    let step_run = |step: i32| {
+        1 + chars
+            .windows(2)
+            .take_while(|pair| pair[1] as i32 - pair[0] as i32 == step)
+            .count()
+    };
+    let sequence = step_run(1).max(step_run(-1));
+
+    let keyboard = KEYBOARD_ROWS
+        .iter()
+        .flat_map(|row| {
+            let row: Vec<char> = row.chars().collect();
+            let reversed: Vec<char> = row.iter().rev().copied().collect();
+            [row, reversed]
+        })
+        .map(|row| match row.iter().position(|c| *c == chars[0]) {
+            Some(start) => row[start..].iter().zip(chars).take_while(|(a, b)| a == b).count(),
+            None => 0,
+        })
+        .max()
+        .unwrap_or(0);
+
+    repeat.max(sequence).max(keyboard)
+}
@@ -2,13 +2,16 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult };
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Phone {
     pub country_code: u16,
     pub number: u64,
@@ -71,6 +74,47 @@ impl Phone {
         Ok(Self { country_code, number })
     }
 
+    /// Construye un teléfono desde su forma E.164 (`+573201234567`).
+    /// Los códigos de país E.164 son libres de prefijo, por lo que la longitud
+    /// del código se deduce de sus primeros dígitos.
+    pub fn from_full(value: &str) -> Result<Self, UserDomainError> {
+        let cleaned: String = value.chars().filter(|c| !c.is_whitespace() && *c != '-').collect();
+
+        if cleaned.is_empty() {
+            return Err((CategoryError::Phone, TypeError::Empty).into());
+        }
+
+        let digits = match cleaned.strip_prefix('+') {
+            Some(digits) => digits,
+            None => return Err((CategoryError::Phone, TypeError::StartsWithChar { start: "+".into() }).into()),
+        };
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err((CategoryError::Phone, TypeError::Format { format: "E164".into() }).into());
+        }
+
+        let code_len = Self::country_code_len(digits).min(digits.len());
+        let (country_code, number) = digits.split_at(code_len);
+
+        Self::new(country_code, number)
+    }
+
+    fn country_code_len(digits: &str) -> usize {
+        const TWO_DIGIT_CODES: [&str; 44] = [
+            "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46",
+            "47", "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63",
+            "64", "65", "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+        ];
+
+        if digits.starts_with('1') || digits.starts_with('7') {
+            1
+        } else if digits.len() >= 2 && TWO_DIGIT_CODES.contains(&&digits[..2]) {
+            2
+        } else {
+            3
+        }
+    }
+
     pub fn as_full(&self) -> String {
         format!("{} {}", self.country_code, self.number)
     }
@@ -107,7 +151,7 @@ impl FromStr for Phone {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let cleaned = s.trim().trim_start_matches('+');
-        let parts: Vec<&str> = cleaned.split(|c| c == ' ' || c == '-').collect();
+        let parts: Vec<&str> = cleaned.split([' ', '-']).collect();
 
         if parts.len() != 2 {
             return Err((CategoryError::Phone, TypeError::Format { format: "COUNTRY_CODE NUMBER".into() }).into());
@@ -116,3 +160,24 @@ impl FromStr for Phone {
         Self::try_from((parts[0], parts[1]))
     }
 }
+
+/// Se persiste como `+{código} {número}`: el separador conserva el reparto
+/// entre código y número, que `from_full` sólo puede deducir. Los valores
+/// antiguos en E.164 sin separador se siguen leyendo con `from_full`.
+impl TryFrom<String> for Phone {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.trim().contains([' ', '-']) {
+            value.parse()
+        } else {
+            Phone::from_full(&value)
+        }
+    }
+}
+
+impl From<Phone> for String {
+    fn from(value: Phone) -> Self {
+        value.to_string()
+    }
+}
@@ -0,0 +1,122 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use sha1::{Digest, Sha1};
+
+use crate::user::domain::validations::{
+    UserDomainError,
+    CategoryError,
+    TypeError,
+};
+use crate::user::domain::vo::PasswordStrength;
+
+/// Requisitos de una contraseña nueva.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    /// Límite superior para que Argon2 no reciba entradas arbitrariamente largas.
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Rechaza contraseñas que contengan el email, el nombre de usuario u
+    /// otros datos personales indicados.
+    pub reject_personal_data: bool,
+    pub min_strength: PasswordStrength,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 10,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            reject_personal_data: true,
+            min_strength: PasswordStrength::Fair,
+        }
+    }
+}
+
+/// Contraseña en claro que cumple una `PasswordPolicy`.
+///
+/// Sólo existe el tiempo necesario para derivar su hash: no se serializa y
+/// `Debug` no muestra el valor. Las contraseñas de inicio de sesión no pasan
+/// por aquí; sólo las nuevas.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PlainPassword {
+    value: String,
+    strength: PasswordStrength,
+}
+
+impl PlainPassword {
+    /// Valida `raw` contra `policy`. `personal` son datos del usuario (email,
+    /// nombre de usuario, ...) que la contraseña no debe contener; de un email
+    /// sólo cuenta la parte local.
+    pub fn new(raw: &str, policy: &PasswordPolicy, personal: &[&str]) -> Result<Self, UserDomainError> {
+        if raw.is_empty() {
+            return Err((CategoryError::Password, TypeError::Empty).into());
+        }
+
+        let len = raw.chars().count();
+        if len < policy.min_length {
+            return Err((CategoryError::Password, TypeError::TooShort { short: policy.min_length as u16 }).into());
+        }
+        if len > policy.max_length {
+            return Err((CategoryError::Password, TypeError::TooLong { long: policy.max_length as u32 }).into());
+        }
+
+        let classes = [
+            (policy.require_lowercase, "lowercase", raw.chars().any(char::is_lowercase)),
+            (policy.require_uppercase, "uppercase", raw.chars().any(char::is_uppercase)),
+            (policy.require_digit, "digit", raw.chars().any(|c| c.is_ascii_digit())),
+            (policy.require_symbol, "symbol", raw.chars().any(|c| !c.is_alphanumeric())),
+        ];
+        if let Some((_, class, _)) = classes.iter().find(|(required, _, present)| *required && !present) {
+            return Err((CategoryError::Password, TypeError::MissingCharacterClass { class: (*class).into() }).into());
+        }
+
+        let personal: Vec<&str> = personal
+            .iter()
+            .map(|item| item.split('@').next().unwrap_or_default().trim())
+            .filter(|item| item.chars().count() >= 3)
+            .collect();
+        let lowered = raw.to_lowercase();
+        if policy.reject_personal_data && personal.iter().any(|item| lowered.contains(&item.to_lowercase())) {
+            return Err((CategoryError::Password, TypeError::PersonalData).into());
+        }
+
+        let (_, strength) = PasswordStrength::estimate(raw, &personal);
+        if strength < policy.min_strength {
+            return Err((CategoryError::Password, TypeError::TooWeak { strength: strength.as_str().into() }).into());
+        }
+
+        Ok(Self { value: raw.to_string(), strength })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn strength(&self) -> PasswordStrength {
+        self.strength
+    }
+
+    /// SHA-1 en hexadecimal mayúsculas, el formato de las listas de
+    /// contraseñas filtradas.
+    pub fn sha1_hex(&self) -> String {
+        Sha1::digest(self.value.as_bytes()).iter().map(|byte| format!("{byte:02X}")).collect()
+    }
+}
+
+impl Debug for PlainPassword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("PlainPassword")
+            .field("value", &"<redacted>")
+            .field("strength", &self.strength)
+            .finish()
+    }
+}
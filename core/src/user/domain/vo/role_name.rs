@@ -58,6 +58,12 @@ impl Display for RoleName {
     }
 }
 
+impl AsRef<str> for RoleName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<&str> for RoleName {
     type Error = UserDomainError;
 
@@ -2,13 +2,16 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult };
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum UserStatus {
     Pending,
     Active,
@@ -75,3 +78,17 @@ impl FromStr for UserStatus {
         Self::try_from(s)
     }
 }
+
+impl TryFrom<String> for UserStatus {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<UserStatus> for String {
+    fn from(value: UserStatus) -> Self {
+        value.as_str().to_string()
+    }
+}
@@ -8,8 +8,9 @@ use crate::user::domain::validations::{
     TypeError,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum SubscriptionTier {
+    #[default]
     Free,
     Basic,
     Premium,
@@ -73,9 +74,3 @@ impl FromStr for SubscriptionTier {
         Self::try_from(s)
     }
 }
-
-impl Default for SubscriptionTier {
-    fn default() -> Self {
-        SubscriptionTier::Free
-    }
-}
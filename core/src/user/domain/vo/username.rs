@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 use std::str::FromStr;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use serde::{Deserialize, Serialize};
+
 use crate::user::domain::validations::{
     UserDomainError,
     CategoryError,
@@ -9,13 +11,14 @@ use crate::user::domain::validations::{
     USERNAME_REGEX,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Username(String);
 
 impl Username {
     /// Longitudes mínimas y máximas recomendadas
-    const MIN_USERNAME_LEN: usize = 3;
-    const MAX_USERNAME_LEN: usize = 32;
+    const MIN_USERNAME_LEN: usize = 6;
+    const MAX_USERNAME_LEN: usize = 30;
 
     pub(crate) fn new(value: &str) -> Result<Self, UserDomainError> {
         let trimmed = value.trim();
@@ -33,11 +36,13 @@ impl Username {
             return Err((CategoryError::Username, TypeError::TooLong { long: Self::MAX_USERNAME_LEN as u32 }).into());
         }
 
-        if !USERNAME_REGEX.regex.is_match(trimmed) {
+        let lowered = trimmed.to_ascii_lowercase();
+
+        if !USERNAME_REGEX.regex.is_match(&lowered) {
             return Err((CategoryError::Username, TypeError::Format { format: USERNAME_REGEX.name.into() }).into());
         }
 
-        Ok(Self(trimmed.to_ascii_lowercase()))
+        Ok(Self(lowered))
     }
 
     #[inline]
@@ -62,6 +67,12 @@ impl Display for Username {
     }
 }
 
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl TryFrom<&str> for Username {
     type Error = UserDomainError;
 
@@ -77,3 +88,17 @@ impl FromStr for Username {
         Self::try_from(s)
     }
 }
+
+impl TryFrom<String> for Username {
+    type Error = UserDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Username> for String {
+    fn from(value: Username) -> Self {
+        value.0
+    }
+}
@@ -1,45 +1,36 @@
 use async_trait::async_trait;
-use std::sync::Arc;
-use crate::user::domain::events::*; // importa todos los eventos de user/domain/events
+use std::sync::{Arc, Mutex};
 
-/// Trait genérico para publicar eventos de dominio.
-/// Esto permite que el dominio sea independiente de la infraestructura.
-#[async_trait]
-pub trait DomainEventPublisher: Send + Sync {
-    async fn publish(&self, event: DomainEvent) -> anyhow::Result<()>;
-}
+use crate::user::domain::events::UserDomainEvent;
 
-/// Enum que envuelve los eventos del dominio de `user`.
-/// Permite que un único publicador maneje múltiples tipos de eventos.
-#[derive(Debug, Clone)]
-pub enum DomainEvent {
-    UserRegistered(UserRegistered),
-    UserEmailVerified(UserEmailVerified),
-    UserPhoneVerified(UserPhoneVerified),
-    UserLoggedIn(UserLoggedIn),
-    SubscriptionRenewed(SubscriptionRenewed),
-    // Aquí puedes seguir agregando los eventos que vayas creando
-}
+pub use crate::user::domain::events::DomainEventPublisher;
 
 /// Implementación de ejemplo en memoria.
 /// Útil para tests o ambientes locales.
+#[derive(Debug, Default, Clone)]
 pub struct InMemoryDomainEventPublisher {
-    pub events: Arc<tokio::sync::Mutex<Vec<DomainEvent>>>,
+    pub events: Arc<Mutex<Vec<UserDomainEvent>>>,
 }
 
 impl InMemoryDomainEventPublisher {
     pub fn new() -> Self {
-        Self {
-            events: Arc::new(tokio::sync::Mutex::new(Vec::new())),
-        }
+        Self::default()
+    }
+
+    /// Copia de los eventos publicados hasta ahora.
+    pub fn published(&self) -> Vec<UserDomainEvent> {
+        self.events.lock().map(|events| events.clone()).unwrap_or_default()
     }
 }
 
 #[async_trait]
 impl DomainEventPublisher for InMemoryDomainEventPublisher {
-    async fn publish(&self, event: DomainEvent) -> anyhow::Result<()> {
-        let mut events = self.events.lock().await;
-        events.push(event);
+    async fn publish(&self, event: &UserDomainEvent) -> anyhow::Result<()> {
+        let mut events = self
+            .events
+            .lock()
+            .map_err(|_| anyhow::anyhow!("in-memory publisher lock poisoned"))?;
+        events.push(event.clone());
         Ok(())
     }
 }
@@ -0,0 +1,183 @@
+use async_std::task::JoinHandle;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::FutureExt;
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+
+use crate::user::domain::events::UserDomainEvent;
+use crate::user::infrastructure::messaging::{DomainEventPublisher, EventHandler, UserEventKind};
+
+/// Cómo se ejecutan los manejadores al publicar.
+///
+/// - `Sync`: `publish` espera a todos los manejadores, en orden de suscripción.
+/// - `Async`: cada manejador corre en su propia tarea y `publish` vuelve de
+///   inmediato; `wait_idle` espera a que terminen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DispatchMode {
+    #[default]
+    Sync,
+    Async,
+}
+
+/// Entrega fallida de un evento a un manejador.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub event: UserDomainEvent,
+    pub handler: String,
+    pub error: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Borra el tipo concreto del evento para guardar manejadores heterogéneos.
+#[async_trait]
+trait ErasedHandler: Send + Sync {
+    async fn handle(&self, event: &UserDomainEvent) -> Option<anyhow::Result<()>>;
+    fn name(&self) -> &str;
+}
+
+struct Typed<E, H> {
+    handler: H,
+    _event: PhantomData<fn(&E)>,
+}
+
+#[async_trait]
+impl<E, H> ErasedHandler for Typed<E, H>
+where
+    E: UserEventKind,
+    H: EventHandler<E>,
+{
+    async fn handle(&self, event: &UserDomainEvent) -> Option<anyhow::Result<()>> {
+        match E::from_event(event) {
+            Some(event) => Some(self.handler.handle(event).await),
+            None => None,
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.handler.name()
+    }
+}
+
+/// Bus de eventos en proceso.
+///
+/// Un manejador que falla o entra en pánico no impide que los demás reciban el
+/// evento: su error queda en la lista de mensajes muertos (`dead_letters`).
+///
+/// En modo `Sync`, `publish` devuelve además `Err` si algún manejador falló,
+/// de modo que `OutboxRelay` no marca el mensaje como publicado y lo
+/// reintenta. En modo `Async` los fallos sólo llegan a `dead_letters`.
+#[derive(Default, Clone)]
+pub struct InProcessEventBus {
+    mode: DispatchMode,
+    handlers: Arc<Mutex<Vec<Arc<dyn ErasedHandler>>>>,
+    dead_letters: Arc<Mutex<Vec<DeadLetter>>>,
+    running: Arc<Mutex<Vec<JoinHandle<()>>>>,
+}
+
+impl InProcessEventBus {
+    pub fn new(mode: DispatchMode) -> Self {
+        Self { mode, ..Self::default() }
+    }
+
+    pub fn mode(&self) -> DispatchMode {
+        self.mode
+    }
+
+    /// Suscribe un manejador a un tipo de evento concreto, p. ej.
+    /// `bus.subscribe::<UserRegistered, _>(SendWelcomeEmail)`.
+    /// Con `E = UserDomainEvent` recibe todos los eventos.
+    pub fn subscribe<E, H>(&self, handler: H)
+    where
+        E: UserEventKind,
+        H: EventHandler<E> + 'static,
+    {
+        let handler: Arc<dyn ErasedHandler> = Arc::new(Typed { handler, _event: PhantomData::<fn(&E)> });
+        Self::lock(&self.handlers).push(handler);
+    }
+
+    pub fn handler_count(&self) -> usize {
+        Self::lock(&self.handlers).len()
+    }
+
+    pub fn dead_letters(&self) -> Vec<DeadLetter> {
+        Self::lock(&self.dead_letters).clone()
+    }
+
+    /// Vacía la lista de mensajes muertos (p. ej. para reprocesarlos).
+    pub fn take_dead_letters(&self) -> Vec<DeadLetter> {
+        std::mem::take(&mut *Self::lock(&self.dead_letters))
+    }
+
+    /// Espera a que terminen los manejadores lanzados en modo `Async`.
+    pub async fn wait_idle(&self) {
+        loop {
+            let running = std::mem::take(&mut *Self::lock(&self.running));
+            if running.is_empty() {
+                return;
+            }
+            for task in running {
+                task.await;
+            }
+        }
+    }
+
+    /// Entrega `event` a `handler`; devuelve `false` si falló o entró en pánico.
+    async fn dispatch(handler: Arc<dyn ErasedHandler>, event: UserDomainEvent, dead_letters: Arc<Mutex<Vec<DeadLetter>>>) -> bool {
+        let error = match AssertUnwindSafe(handler.handle(&event)).catch_unwind().await {
+            Ok(Some(Err(error))) => error.to_string(),
+            Ok(_) => return true,
+            Err(panic) => format!("handler panicked: {}", Self::panic_message(panic.as_ref())),
+        };
+
+        Self::lock(&dead_letters).push(DeadLetter {
+            handler: handler.name().to_string(),
+            error,
+            failed_at: Utc::now(),
+            event,
+        });
+        false
+    }
+
+    fn panic_message(panic: &(dyn Any + Send)) -> &str {
+        panic
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown panic")
+    }
+
+    /// Un manejador que entra en pánico no debe dejar el bus inutilizable.
+    fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+        mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisher for InProcessEventBus {
+    async fn publish(&self, event: &UserDomainEvent) -> anyhow::Result<()> {
+        let handlers = Self::lock(&self.handlers).clone();
+        let mut failed = Vec::new();
+
+        for handler in handlers {
+            let name = handler.name().to_string();
+            let dispatch = Self::dispatch(handler, event.clone(), self.dead_letters.clone());
+
+            match self.mode {
+                DispatchMode::Sync => {
+                    if !dispatch.await {
+                        failed.push(name);
+                    }
+                }
+                DispatchMode::Async => Self::lock(&self.running).push(async_std::task::spawn(dispatch.map(drop))),
+            }
+        }
+
+        if !failed.is_empty() {
+            anyhow::bail!("{} failed for handler(s): {}", event.event_name(), failed.join(", "));
+        }
+        Ok(())
+    }
+}
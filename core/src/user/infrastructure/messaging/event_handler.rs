@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+
+use crate::user::domain::events::*;
+
+/// Manejador de un tipo concreto de evento (`UserRegistered`, `UserSuspended`, ...)
+/// o de todos ellos (`UserDomainEvent`).
+#[async_trait]
+pub trait EventHandler<E>: Send + Sync {
+    async fn handle(&self, event: &E) -> anyhow::Result<()>;
+
+    /// Nombre usado en la lista de mensajes muertos.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// Los cierres síncronos también sirven como manejadores.
+#[async_trait]
+impl<E, F> EventHandler<E> for F
+where
+    E: Sync,
+    F: Fn(&E) -> anyhow::Result<()> + Send + Sync,
+{
+    async fn handle(&self, event: &E) -> anyhow::Result<()> {
+        self(event)
+    }
+}
+
+/// Relaciona cada struct de evento con su variante de `UserDomainEvent`,
+/// para poder suscribir manejadores tipados.
+pub trait UserEventKind: Send + Sync + 'static {
+    fn from_event(event: &UserDomainEvent) -> Option<&Self>;
+}
+
+impl UserEventKind for UserDomainEvent {
+    fn from_event(event: &UserDomainEvent) -> Option<&Self> {
+        Some(event)
+    }
+}
+
+macro_rules! user_event_kind {
+    ($($variant:ident => $event:ty),+ $(,)?) => {
+        $(
+            impl UserEventKind for $event {
+                fn from_event(event: &UserDomainEvent) -> Option<&Self> {
+                    match event {
+                        UserDomainEvent::$variant(e) => Some(e),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+user_event_kind! {
+    Activated => UserActivated,
+    Deleted => UserDeleted,
+    EmailUpdated => UserEmailUpdated,
+    EmailVerified => UserEmailVerified,
+    ExternalIdLinked => UserExternalIdLinked,
+    ExternalIdUnlinked => UserExternalIdUnlinked,
+    LockedOut => UserLockedOut,
+    PasswordChanged => UserPasswordChanged,
+    PasswordResetRequested => UserPasswordResetRequested,
+    PhoneAssigned => UserPhoneAssigned,
+    PhoneUnassigned => UserPhoneUnassigned,
+    PhoneVerified => UserPhoneVerified,
+    Registered => UserRegistered,
+    Suspended => UserSuspended,
+    Unlocked => UserUnlocked,
+    UsernameAssigned => UserUsernameAssigned,
+}
@@ -0,0 +1,9 @@
+pub mod domain_event_publisher;
+pub mod event_bus;
+pub mod event_handler;
+pub mod outbox_relay;
+
+pub use domain_event_publisher::{DomainEventPublisher, InMemoryDomainEventPublisher};
+pub use event_bus::{DeadLetter, DispatchMode, InProcessEventBus};
+pub use event_handler::{EventHandler, UserEventKind};
+pub use outbox_relay::{OutboxRelay, RelayReport, RetryPolicy};
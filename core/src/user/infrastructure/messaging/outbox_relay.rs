@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::user::domain::repositories::{OutboxMessage, OutboxRepository, RepositoryError};
+use crate::user::infrastructure::messaging::DomainEventPublisher;
+
+/// Política de reintentos con backoff exponencial:
+/// el intento `n` fallido espera `base_delay * 2^(n-1)`, acotado por `max_delay`.
+/// Tras `max_attempts` fallos el mensaje deja de reintentarse (queda como "muerto").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        self.base_delay
+            .checked_mul(1u32 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Resultado de una pasada del relay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RelayReport {
+    pub published: usize,
+    pub failed: usize,
+    /// Mensajes que agotaron sus reintentos en esta pasada.
+    pub exhausted: usize,
+    /// Mensajes que no se intentaron porque uno anterior de su agregado falló.
+    pub skipped: usize,
+}
+
+/// Lee la bandeja de salida y entrega los eventos pendientes al `DomainEventPublisher`.
+///
+/// La entrega es "al menos una vez": si el proceso cae entre publicar y marcar,
+/// el evento se vuelve a publicar; los consumidores deben deduplicar por `event_id`.
+/// Dentro de cada agregado se respeta el orden: tras un fallo, sus eventos
+/// posteriores esperan a que el anterior se publique o agote sus reintentos.
+pub struct OutboxRelay<R: OutboxRepository> {
+    outbox: R,
+    publisher: Arc<dyn DomainEventPublisher>,
+    policy: RetryPolicy,
+    batch_size: u64,
+}
+
+impl<R: OutboxRepository> OutboxRelay<R> {
+    pub const DEFAULT_BATCH_SIZE: u64 = 100;
+
+    pub fn new(outbox: R, publisher: Arc<dyn DomainEventPublisher>) -> Self {
+        Self {
+            outbox,
+            publisher,
+            policy: RetryPolicy::default(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn outbox(&self) -> &R {
+        &self.outbox
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Procesa un lote de mensajes pendientes en este instante.
+    pub async fn relay_once(&self) -> Result<RelayReport, RepositoryError> {
+        self.relay_at(Utc::now()).await
+    }
+
+    /// Procesa un lote tomando `now` como instante actual.
+    pub async fn relay_at(&self, now: DateTime<Utc>) -> Result<RelayReport, RepositoryError> {
+        let mut report = RelayReport::default();
+        let messages = self.outbox.pending(now, self.policy.max_attempts, self.batch_size).await?;
+        let mut blocked = HashSet::new();
+
+        for message in messages {
+            if blocked.contains(&message.aggregate_id) {
+                report.skipped += 1;
+                continue;
+            }
+            match self.deliver(&message).await {
+                Ok(()) => {
+                    self.outbox.mark_published(message.event_id, now).await?;
+                    report.published += 1;
+                }
+                Err(error) => {
+                    let attempt = message.attempts + 1;
+                    let delay = chrono::Duration::from_std(self.policy.delay_for(attempt))
+                        .unwrap_or(chrono::Duration::MAX);
+                    let next_attempt_at = now.checked_add_signed(delay).unwrap_or(DateTime::<Utc>::MAX_UTC);
+
+                    self.outbox.mark_failed(message.event_id, &error, next_attempt_at).await?;
+                    blocked.insert(message.aggregate_id);
+                    report.failed += 1;
+                    if attempt >= self.policy.max_attempts {
+                        report.exhausted += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> Result<(), String> {
+        let event = message.to_event().map_err(|err| err.to_string())?;
+        self.publisher.publish(&event).await.map_err(|err| err.to_string())
+    }
+
+    /// Sondea la bandeja indefinidamente, esperando `poll_interval` cuando no
+    /// queda nada por publicar. Sólo termina si falla el acceso a la bandeja.
+    pub async fn run(&self, poll_interval: Duration) -> Result<(), RepositoryError> {
+        loop {
+            let report = self.relay_once().await?;
+            if report.published + report.failed == 0 {
+                async_std::task::sleep(poll_interval).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod messaging;
+pub mod persistence;
+pub mod services_impl;
@@ -0,0 +1,14 @@
+use sea_orm::DbErr;
+
+use crate::user::domain::repositories::RepositoryError;
+
+/// Traduce los fallos de SeaORM a `RepositoryError` conservando el `DbErr`
+/// original (recuperable con `downcast_ref::<DbErr>()` sobre `source()`).
+impl From<DbErr> for RepositoryError {
+    fn from(err: DbErr) -> Self {
+        match err {
+            DbErr::RecordNotFound(id) => RepositoryError::NotFound { entity: "record", id },
+            other => RepositoryError::infrastructure(other),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::user::domain::events::UserEventEnvelope;
+use crate::user::domain::repositories::{EventStore, RepositoryError};
+
+/// Almacén de eventos en memoria, con la misma semántica de versiones y
+/// concurrencia que `EventStoreSeaOrm`.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryEventStore {
+    streams: Arc<Mutex<HashMap<String, Vec<UserEventEnvelope>>>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn streams(&self) -> std::sync::MutexGuard<'_, HashMap<String, Vec<UserEventEnvelope>>> {
+        self.streams.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, stream_id: &str, expected_version: u64, events: &[UserEventEnvelope]) -> Result<u64, RepositoryError> {
+        let mut streams = self.streams();
+
+        let actual = streams.get(stream_id).map_or(0, |stream| stream.len() as u64);
+        if actual != expected_version {
+            return Err(RepositoryError::Concurrency { stream_id: stream_id.to_string(), expected: expected_version, actual });
+        }
+
+        // `event_id` es único en todo el almacén, como su índice en SQL.
+        let duplicate = events.iter().enumerate().find(|(i, envelope)| {
+            events[..*i].iter().any(|other| other.event_id == envelope.event_id)
+                || streams.values().flatten().any(|stored| stored.event_id == envelope.event_id)
+        });
+        if let Some((_, envelope)) = duplicate {
+            return Err(RepositoryError::Conflict { entity: "event", field: "event_id", value: envelope.event_id.to_string() });
+        }
+
+        let stream = streams.entry(stream_id.to_string()).or_default();
+
+        for (envelope, version) in events.iter().zip(expected_version + 1..) {
+            stream.push(UserEventEnvelope { aggregate_version: version, ..envelope.clone() });
+        }
+
+        Ok(stream.len() as u64)
+    }
+
+    async fn read_stream(&self, stream_id: &str, from_version: u64) -> Result<Vec<UserEventEnvelope>, RepositoryError> {
+        Ok(self
+            .streams()
+            .get(stream_id)
+            .map(|stream| stream.iter().filter(|e| e.aggregate_version >= from_version).cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn stream_version(&self, stream_id: &str) -> Result<u64, RepositoryError> {
+        Ok(self.streams().get(stream_id).map_or(0, |stream| stream.len() as u64))
+    }
+}
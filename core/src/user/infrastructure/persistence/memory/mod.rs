@@ -0,0 +1,15 @@
+pub mod event_store_memory;
+pub mod role_repository_memory;
+pub mod store;
+pub mod unit_of_work_memory;
+pub mod user_aggregate_repository_memory;
+pub mod user_query_repository_memory;
+pub mod user_repository_memory;
+
+pub use event_store_memory::InMemoryEventStore;
+pub use role_repository_memory::InMemoryRoleRepository;
+pub use store::InMemoryStore;
+pub use unit_of_work_memory::{InMemoryUnitOfWork, InMemoryUnitOfWorkFactory};
+pub use user_aggregate_repository_memory::InMemoryUserAggregateRepository;
+pub use user_query_repository_memory::InMemoryUserQueryRepository;
+pub use user_repository_memory::InMemoryUserRepository;
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::user::domain::entities::Role;
+use crate::user::domain::repositories::{RepositoryError, RoleRepository};
+use crate::user::domain::vo::RoleName;
+
+use crate::user::infrastructure::persistence::memory::InMemoryStore;
+
+/// Repositorio de roles en memoria; el nombre es único y borrar un rol
+/// elimina sus asignaciones, como en `RoleRepositorySeaOrm`.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryRoleRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryRoleRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+}
+
+#[async_trait]
+impl RoleRepository for InMemoryRoleRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Role>, RepositoryError> {
+        Ok(self.store.read(|state| state.roles.get(&id).cloned()))
+    }
+
+    async fn get_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError> {
+        Ok(self.store.read(|state| state.roles.values().find(|role| role.name == *name).cloned()))
+    }
+
+    async fn list_all(&self) -> Result<Vec<Role>, RepositoryError> {
+        let mut roles: Vec<Role> = self.store.read(|state| state.roles.values().cloned().collect());
+        roles.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        Ok(roles)
+    }
+
+    async fn save(&self, role: &Role) -> Result<(), RepositoryError> {
+        self.store.write(|state| {
+            if state.roles.values().any(|other| other.role_id != role.role_id && other.name == role.name) {
+                return Err(RepositoryError::Conflict { entity: "role", field: "name", value: role.name.as_str().to_string() });
+            }
+
+            // Como el upsert SQL, una actualización conserva `created_at`
+            let created_at = state.roles.get(&role.role_id).map_or(role.created_at, |stored| stored.created_at);
+            state.roles.insert(role.role_id, Role { created_at, ..role.clone() });
+            state.touched_roles.insert(role.role_id);
+            Ok(())
+        })
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        self.store.write(|state| {
+            if state.roles.remove(&id).is_none() {
+                return Err(RepositoryError::NotFound { entity: "role", id: id.to_string() });
+            }
+
+            state.user_roles.retain(|row| row.role_id != id);
+            state.touched_roles.insert(id);
+            Ok(())
+        })
+    }
+}
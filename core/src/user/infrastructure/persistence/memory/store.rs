@@ -0,0 +1,289 @@
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use uuid::Uuid;
+
+use crate::user::domain::entities::{
+    Role, User, UserActivityLog, UserAuthMethod, UserGdprConsent, UserMfa, UserPassword, UserProfile, UserRole,
+    UserSession, UserSnapshot, UserSubscription,
+};
+use crate::user::domain::repositories::{user_stream_id, OutboxMessage, RepositoryError};
+use crate::user::domain::vo::UserId;
+
+/// Estado compartido por los repositorios en memoria.
+///
+/// Hace las veces de base de datos: `InMemoryUserRepository`,
+/// `InMemoryRoleRepository` e `InMemoryUserAggregateRepository` creados sobre el
+/// mismo `InMemoryStore` ven los mismos datos, igual que los adaptadores SeaORM
+/// sobre la misma conexión.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryStore {
+    state: Arc<Mutex<StoreState>>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StoreState {
+    pub users: HashMap<Uuid, UserSnapshot>,
+    pub roles: HashMap<Uuid, Role>,
+    pub outbox: Vec<OutboxMessage>,
+    pub profiles: Vec<UserProfile>,
+    pub passwords: Vec<UserPassword>,
+    pub auth_methods: Vec<UserAuthMethod>,
+    pub mfa: Vec<UserMfa>,
+    pub user_roles: Vec<UserRole>,
+    pub subscriptions: Vec<UserSubscription>,
+    pub gdpr_consents: Vec<UserGdprConsent>,
+    pub sessions: Vec<UserSession>,
+    pub activity_logs: Vec<UserActivityLog>,
+    /// `row_version` de cada usuario, como la columna de `users`.
+    pub row_versions: HashMap<Uuid, u64>,
+    /// Usuarios escritos, con la versión y la versión de fila que tenían antes
+    /// de la primera escritura (`None` si no existían). `merge` sólo publica estos.
+    pub touched_users: HashMap<Uuid, Option<(u64, u64)>>,
+    /// Roles escritos o borrados, para `merge`.
+    pub touched_roles: HashSet<Uuid>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mensajes escritos en la bandeja de salida, en orden de escritura.
+    pub fn outbox(&self) -> Vec<OutboxMessage> {
+        self.read(|state| state.outbox.clone())
+    }
+
+    pub(crate) fn read<R>(&self, f: impl FnOnce(&StoreState) -> R) -> R {
+        f(&self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    /// Aplica `f` sobre una copia del estado y sólo la publica si termina bien,
+    /// de modo que un error a mitad de camino no deja escrituras parciales.
+    pub(crate) fn write<R>(&self, f: impl FnOnce(&mut StoreState) -> Result<R, RepositoryError>) -> Result<R, RepositoryError> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut draft = state.clone();
+        let result = f(&mut draft)?;
+        *state = draft;
+        Ok(result)
+    }
+
+    /// Copia independiente del estado actual, para una unidad de trabajo.
+    pub(crate) fn fork(&self) -> InMemoryStore {
+        let mut state = self.read(StoreState::clone);
+        state.touched_users.clear();
+        state.touched_roles.clear();
+        InMemoryStore { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Publica lo que `fork` escribió.
+    ///
+    /// Como la escritura versionada de SQL, cada usuario escrito en la copia
+    /// debe seguir en la versión y la versión de fila con las que se copió;
+    /// las escrituras concurrentes sobre otros usuarios no entran en conflicto.
+    /// Los roles se sobrescriben, como el upsert de `RoleRepositorySeaOrm`.
+    pub(crate) fn merge(&self, fork: &InMemoryStore) -> Result<(), RepositoryError> {
+        let forked = fork.read(StoreState::clone);
+        if forked.touched_users.is_empty() && forked.touched_roles.is_empty() {
+            return Ok(());
+        }
+
+        self.write(|state| state.apply(&forked))
+    }
+
+    /// Anota la entrega de un evento ya confirmado.
+    pub(crate) fn mark_published(&self, event_id: Uuid, published_at: DateTime<Utc>) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        match state.outbox.iter_mut().find(|message| message.event_id == event_id && message.published_at.is_none()) {
+            Some(message) => {
+                message.published_at = Some(published_at);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl StoreState {
+    /// Misma secuencia que `UserRepositorySeaOrm::save_in`: unicidad, escritura
+    /// versionada y eventos pendientes a la bandeja de salida.
+    ///
+    /// Mismas reglas de versión que `UserRepositorySeaOrm::write`: sin fila,
+    /// sólo se inserta un usuario que nunca se persistió; con fila, sólo se
+    /// reescribe si sigue en la versión con la que se cargó.
+    pub fn save_user(&mut self, user: &User) -> Result<(), RepositoryError> {
+        let snapshot = user.snapshot();
+        self.ensure_unique(&snapshot)?;
+
+        let expected = user.persisted_version();
+        let concurrency = |actual| RepositoryError::Concurrency { stream_id: user_stream_id(user.id()), expected, actual };
+
+        match self.users.get(&user.id().as_uuid()).map(|snapshot| snapshot.version) {
+            None if expected == 0 => {}
+            None => return Err(RepositoryError::NotFound { entity: "user", id: user.id().to_string() }),
+            Some(actual) if actual != expected => return Err(concurrency(actual)),
+            Some(_) => {}
+        }
+        self.touch_user(snapshot.id.as_uuid());
+        self.users.insert(snapshot.id.as_uuid(), snapshot);
+
+        for event in user.pending_events() {
+            if !self.outbox.iter().any(|message| message.event_id == event.event_id()) {
+                self.outbox.push(OutboxMessage::pending(event));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Los índices únicos también cubren a los usuarios eliminados lógicamente.
+    fn ensure_unique(&self, user: &UserSnapshot) -> Result<(), RepositoryError> {
+        let others = || self.users.values().filter(|other| other.id != user.id);
+
+        if others().any(|other| other.email == user.email) {
+            return Err(RepositoryError::Conflict { entity: "user", field: "email", value: user.email.as_str().to_string() });
+        }
+        if let Some(username) = &user.username && others().any(|other| other.username.as_ref() == Some(username)) {
+            return Err(RepositoryError::Conflict { entity: "user", field: "username", value: username.as_ref().to_string() });
+        }
+        if let Some(external_id) = &user.external_id && others().any(|other| other.external_id.as_ref() == Some(external_id)) {
+            return Err(RepositoryError::Conflict { entity: "user", field: "external_id", value: external_id.as_ref().to_string() });
+        }
+
+        Ok(())
+    }
+
+    /// Misma comprobación que `UserAggregateRepositorySeaOrm::check_row_version`.
+    pub fn check_row_version(&mut self, user: &User, expected: u64, bump: bool) -> Result<u64, RepositoryError> {
+        let id = user.id().as_uuid();
+        let actual = self.row_versions.get(&id).copied().unwrap_or(0);
+        if actual != expected {
+            return Err(RepositoryError::Concurrency { stream_id: user_stream_id(user.id()), expected, actual });
+        }
+        if !bump {
+            return Ok(expected);
+        }
+
+        self.touch_user(id);
+        self.row_versions.insert(id, expected + 1);
+        Ok(expected + 1)
+    }
+
+    /// Borrado físico con las mismas reglas `ON DELETE` que las migraciones.
+    pub fn delete_user(&mut self, id: Uuid) -> Result<(), RepositoryError> {
+        self.touch_user(id);
+        if self.users.remove(&id).is_none() {
+            return Err(RepositoryError::NotFound { entity: "user", id: id.to_string() });
+        }
+        self.row_versions.remove(&id);
+
+        self.profiles.retain(|row| row.user_id != id);
+        self.passwords.retain(|row| row.user_id != id);
+        self.auth_methods.retain(|row| row.user_id != id);
+        self.mfa.retain(|row| row.user_id != id);
+        self.user_roles.retain(|row| row.user_id != id);
+        self.subscriptions.retain(|row| row.user_id != id);
+        self.gdpr_consents.retain(|row| row.user_id != id);
+        self.sessions.retain(|row| row.user_id != id);
+        self.activity_logs.retain(|row| row.user_id != id);
+
+        for row in self.user_roles.iter_mut().filter(|row| row.granted_by == Some(id)) {
+            row.granted_by = None;
+        }
+
+        Ok(())
+    }
+
+    fn touch_user(&mut self, id: Uuid) {
+        let versions = self.versions(id);
+        self.touched_users.entry(id).or_insert(versions);
+    }
+
+    fn versions(&self, id: Uuid) -> Option<(u64, u64)> {
+        let row_version = self.row_versions.get(&id).copied().unwrap_or(0);
+        self.users.get(&id).map(|snapshot| (snapshot.version, row_version))
+    }
+
+    /// Lleva a este estado los usuarios y roles escritos en `fork`.
+    fn apply(&mut self, fork: &StoreState) -> Result<(), RepositoryError> {
+        for (&id, &expected) in &fork.touched_users {
+            let actual = self.versions(id);
+            if actual != expected {
+                // Se informa la versión que difiere; si coincide, la de fila
+                let (expected, actual) = match (expected.unwrap_or_default(), actual.unwrap_or_default()) {
+                    ((expected, _), (actual, _)) if expected != actual => (expected, actual),
+                    ((_, expected), (_, actual)) => (expected, actual),
+                };
+                return Err(RepositoryError::Concurrency { stream_id: user_stream_id(&UserId::from_uuid(id)), expected, actual });
+            }
+        }
+
+        for &id in &fork.touched_roles {
+            match fork.roles.get(&id) {
+                Some(role) => {
+                    if self.roles.values().any(|other| other.role_id != id && other.name == role.name) {
+                        return Err(RepositoryError::Conflict { entity: "role", field: "name", value: role.name.as_str().to_string() });
+                    }
+                    self.roles.insert(id, role.clone());
+                }
+                None => {
+                    self.roles.remove(&id);
+                }
+            }
+        }
+        self.user_roles.retain(|row| self.roles.contains_key(&row.role_id));
+
+        for &id in fork.touched_users.keys() {
+            match fork.users.get(&id) {
+                Some(snapshot) => {
+                    self.ensure_unique(snapshot)?;
+                    self.users.insert(id, snapshot.clone());
+                    self.row_versions.insert(id, fork.row_versions.get(&id).copied().unwrap_or(0));
+                }
+                None => {
+                    self.users.remove(&id);
+                    self.row_versions.remove(&id);
+                    for row in self.user_roles.iter_mut().filter(|row| row.granted_by == Some(id)) {
+                        row.granted_by = None;
+                    }
+                }
+            }
+
+            replace_rows(&mut self.profiles, &fork.profiles, id, |row| row.user_id);
+            replace_rows(&mut self.passwords, &fork.passwords, id, |row| row.user_id);
+            replace_rows(&mut self.auth_methods, &fork.auth_methods, id, |row| row.user_id);
+            replace_rows(&mut self.mfa, &fork.mfa, id, |row| row.user_id);
+            replace_rows(&mut self.user_roles, &fork.user_roles, id, |row| row.user_id);
+            replace_rows(&mut self.subscriptions, &fork.subscriptions, id, |row| row.user_id);
+            replace_rows(&mut self.gdpr_consents, &fork.gdpr_consents, id, |row| row.user_id);
+            replace_rows(&mut self.sessions, &fork.sessions, id, |row| row.user_id);
+            replace_rows(&mut self.activity_logs, &fork.activity_logs, id, |row| row.user_id);
+        }
+
+        // Claves foráneas de `user_roles` frente a lo escrito por otros entretanto
+        for row in self.user_roles.iter().filter(|row| fork.touched_users.contains_key(&row.user_id)) {
+            if !self.roles.contains_key(&row.role_id) {
+                return Err(RepositoryError::NotFound { entity: "role", id: row.role_id.to_string() });
+            }
+            if let Some(granted_by) = row.granted_by && !self.users.contains_key(&granted_by) {
+                return Err(RepositoryError::NotFound { entity: "user", id: granted_by.to_string() });
+            }
+        }
+
+        // Los mensajes ya presentes conservan su entrega anotada
+        for message in &fork.outbox {
+            if !self.outbox.iter().any(|stored| stored.event_id == message.event_id) {
+                self.outbox.push(message.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Sustituye las filas de `user_id` en `rows` por las de `forked`.
+fn replace_rows<T: Clone>(rows: &mut Vec<T>, forked: &[T], user_id: Uuid, owner: impl Fn(&T) -> Uuid) {
+    rows.retain(|row| owner(row) != user_id);
+    rows.extend(forked.iter().filter(|row| owner(row) == user_id).cloned());
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+use crate::user::domain::events::{DomainEventPublisher, UserDomainEvent};
+use crate::user::domain::repositories::{
+    RepositoryError, RoleRepository, UnitOfWork, UnitOfWorkFactory, UserAggregateRepository, UserRepository,
+};
+
+use crate::user::infrastructure::persistence::memory::{
+    InMemoryRoleRepository, InMemoryStore, InMemoryUserAggregateRepository, InMemoryUserRepository,
+};
+
+/// Abre unidades de trabajo sobre un `InMemoryStore`.
+#[derive(Clone)]
+pub struct InMemoryUnitOfWorkFactory {
+    store: InMemoryStore,
+    publisher: Arc<dyn DomainEventPublisher>,
+}
+
+impl InMemoryUnitOfWorkFactory {
+    pub fn new(store: InMemoryStore, publisher: Arc<dyn DomainEventPublisher>) -> Self {
+        Self { store, publisher }
+    }
+
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for InMemoryUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, RepositoryError> {
+        let fork = self.store.fork();
+
+        Ok(Box::new(InMemoryUnitOfWork {
+            users: InMemoryUserRepository::new(fork.clone()),
+            roles: InMemoryRoleRepository::new(fork.clone()),
+            aggregates: InMemoryUserAggregateRepository::new(fork),
+            target: self.store.clone(),
+            publisher: self.publisher.clone(),
+            events: Vec::new(),
+        }))
+    }
+}
+
+/// Trabaja sobre una copia del almacén y publica lo escrito en ella al
+/// confirmar; `rollback` simplemente la descarta.
+pub struct InMemoryUnitOfWork {
+    target: InMemoryStore,
+    users: InMemoryUserRepository,
+    roles: InMemoryRoleRepository,
+    aggregates: InMemoryUserAggregateRepository,
+    publisher: Arc<dyn DomainEventPublisher>,
+    events: Vec<UserDomainEvent>,
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    fn users(&self) -> &dyn UserRepository {
+        &self.users
+    }
+
+    fn roles(&self) -> &dyn RoleRepository {
+        &self.roles
+    }
+
+    fn aggregates(&self) -> &dyn UserAggregateRepository {
+        &self.aggregates
+    }
+
+    fn record_events(&mut self, events: Vec<UserDomainEvent>) {
+        self.events.extend(events);
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.target.merge(self.users.store())?;
+
+        // Igual que `UnitOfWorkSeaOrm::commit`: se marca lo entregado y, desde
+        // el primer fallo, el resto queda pendiente en la bandeja.
+        for event in &self.events {
+            if self.publisher.publish(event).await.is_err() {
+                break;
+            }
+            self.target.mark_published(event.event_id(), Utc::now());
+        }
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+use crate::user::domain::entities::User;
+use crate::user::domain::repositories::{AggregateChanges, CollectionChanges, RepositoryError, UserAggregateRepository};
+use crate::user::domain::validations::{CategoryError, TypeError};
+use crate::user::domain::vo::UserId;
+
+use crate::user::infrastructure::persistence::memory::InMemoryStore;
+use crate::user::infrastructure::persistence::orm::mappers::{merge_rows, ChildRow};
+
+/// Repositorio del agregado completo en memoria, con el mismo cálculo de
+/// diferencias y la misma carga diferida que `UserAggregateRepositorySeaOrm`.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryUserAggregateRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryUserAggregateRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    /// Filas del usuario en el mismo orden que la consulta SQL.
+    fn rows<T: ChildRow + Clone>(table: &[T], user_id: Uuid, order: impl Fn(&T) -> DateTime<Utc>) -> Vec<T> {
+        let mut rows: Vec<T> = table.iter().filter(|row| row.user_id() == user_id).cloned().collect();
+        rows.sort_by_key(|row| order(row));
+        rows
+    }
+
+    /// Sincroniza una colección. Si `complete` es falso la colección no se cargó
+    /// entera: sólo se comparan los elementos presentes y nunca se borra.
+    fn sync<T: ChildRow + Clone + PartialEq>(
+        table: &mut Vec<T>,
+        user_id: Uuid,
+        desired: &[T],
+        complete: bool,
+    ) -> Result<CollectionChanges, RepositoryError> {
+        if desired.iter().any(|child| child.user_id() != user_id) {
+            return Err(RepositoryError::Domain((CategoryError::Id, TypeError::Mismatch).into()));
+        }
+
+        let mut changes = CollectionChanges::default();
+
+        if complete {
+            let before = table.len();
+            table.retain(|row| row.user_id() != user_id || desired.iter().any(|child| child.id() == row.id()));
+            changes.deleted = before - table.len();
+        }
+
+        for child in desired {
+            match table.iter_mut().find(|row| row.id() == child.id()) {
+                Some(row) if row != child => {
+                    *row = child.clone();
+                    changes.updated += 1;
+                }
+                Some(_) => {}
+                None => {
+                    table.push(child.clone());
+                    changes.inserted += 1;
+                }
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+#[async_trait]
+impl UserAggregateRepository for InMemoryUserAggregateRepository {
+    async fn load(&self, id: &UserId, options: LoadOptions) -> Result<Option<UserAggregate>, RepositoryError> {
+        let uid = id.as_uuid();
+
+        let Some(mut aggregate) = self.store.read(|state| {
+            let snapshot = state.users.get(&uid)?.clone();
+            let mut aggregate = UserAggregate::new(User::restore(snapshot).ok()?);
+            aggregate.set_row_version(state.row_versions.get(&uid).copied().unwrap_or(0));
+
+            aggregate.profile = Self::rows(&state.profiles, uid, |row| row.created_at).into_iter().next();
+            aggregate.password = Self::rows(&state.passwords, uid, |row| row.created_at).into_iter().next();
+            aggregate.auth_methods = Self::rows(&state.auth_methods, uid, |row| row.created_at);
+            aggregate.mfa = Self::rows(&state.mfa, uid, |row| row.created_at);
+            aggregate.roles = Self::rows(&state.user_roles, uid, |row| row.granted_at);
+            aggregate.subscriptions = Self::rows(&state.subscriptions, uid, |row| row.starts_at);
+            aggregate.gdpr_consents = Self::rows(&state.gdpr_consents, uid, |row| row.created_at);
+
+            if options.sessions {
+                aggregate.sessions = Self::rows(&state.sessions, uid, |row| row.created_at);
+            }
+            if options.activity_logs {
+                aggregate.activity_logs = Self::rows(&state.activity_logs, uid, |row| row.created_at);
+            }
+            Some(aggregate)
+        }) else {
+            return Ok(None);
+        };
+
+        aggregate.set_loaded(options);
+        Ok(Some(aggregate))
+    }
+
+    async fn load_sessions(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError> {
+        let stored = self.store.read(|state| Self::rows(&state.sessions, aggregate.id(), |row| row.created_at));
+        merge_rows(stored, &mut aggregate.sessions);
+        aggregate.set_loaded(LoadOptions { sessions: true, ..aggregate.loaded() });
+        Ok(())
+    }
+
+    async fn load_activity_logs(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError> {
+        let stored = self.store.read(|state| Self::rows(&state.activity_logs, aggregate.id(), |row| row.created_at));
+        merge_rows(stored, &mut aggregate.activity_logs);
+        aggregate.set_loaded(LoadOptions { activity_logs: true, ..aggregate.loaded() });
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &UserAggregate) -> Result<AggregateChanges, RepositoryError> {
+        let uid = aggregate.id();
+        let loaded = aggregate.loaded();
+
+        self.store.write(|state| {
+            let mut changes = AggregateChanges::default();
+
+            state.save_user(&aggregate.user)?;
+
+            // Claves foráneas de `user_roles`
+            for row in &aggregate.roles {
+                if !state.roles.contains_key(&row.role_id) {
+                    return Err(RepositoryError::NotFound { entity: "role", id: row.role_id.to_string() });
+                }
+                if let Some(granted_by) = row.granted_by && !state.users.contains_key(&granted_by) {
+                    return Err(RepositoryError::NotFound { entity: "user", id: granted_by.to_string() });
+                }
+            }
+
+            changes.collections.insert("profile", Self::sync(&mut state.profiles, uid, aggregate.profile.as_slice(), true)?);
+            changes.collections.insert("password", Self::sync(&mut state.passwords, uid, aggregate.password.as_slice(), true)?);
+            changes.collections.insert("auth_methods", Self::sync(&mut state.auth_methods, uid, &aggregate.auth_methods, true)?);
+            changes.collections.insert("mfa", Self::sync(&mut state.mfa, uid, &aggregate.mfa, true)?);
+            changes.collections.insert("roles", Self::sync(&mut state.user_roles, uid, &aggregate.roles, true)?);
+            changes.collections.insert("subscriptions", Self::sync(&mut state.subscriptions, uid, &aggregate.subscriptions, true)?);
+            changes.collections.insert("gdpr_consents", Self::sync(&mut state.gdpr_consents, uid, &aggregate.gdpr_consents, true)?);
+            changes.collections.insert("sessions", Self::sync(&mut state.sessions, uid, &aggregate.sessions, loaded.sessions)?);
+            changes.collections.insert("activity_logs", Self::sync(&mut state.activity_logs, uid, &aggregate.activity_logs, loaded.activity_logs)?);
+
+            // Como `UserAggregateRepositorySeaOrm::save`
+            let bump = !aggregate.user.pending_events().is_empty() || !changes.is_empty();
+            changes.row_version = state.check_row_version(&aggregate.user, aggregate.row_version(), bump)?;
+
+            Ok(changes)
+        })
+    }
+}
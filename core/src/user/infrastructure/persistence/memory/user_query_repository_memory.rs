@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::user::domain::entities::UserSnapshot;
+use crate::user::domain::repositories::{
+    RepositoryError, SortDirection, UserCursor, UserFilter, UserPage, UserQuery, UserQueryRepository, UserSummary,
+};
+use crate::user::domain::vo::SubscriptionStatus;
+
+use crate::user::infrastructure::persistence::memory::store::StoreState;
+use crate::user::infrastructure::persistence::memory::InMemoryStore;
+
+/// Lado de consulta en memoria, con los mismos filtros, orden y cursores que
+/// `UserQueryRepositorySeaOrm`.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryUserQueryRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryUserQueryRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    fn summary(state: &StoreState, snapshot: &UserSnapshot) -> UserSummary {
+        let display_name = state
+            .profiles
+            .iter()
+            .find(|profile| profile.user_id == snapshot.id.as_uuid())
+            .and_then(|profile| profile.display_name.clone());
+
+        UserSummary {
+            id: snapshot.id.clone(),
+            email: snapshot.email.clone(),
+            username: snapshot.username.clone(),
+            display_name,
+            status: snapshot.status.clone(),
+            email_verified: snapshot.email_verified,
+            phone_verified: snapshot.phone_verified,
+            created_at: snapshot.created_at.clone(),
+        }
+    }
+
+    fn matches(state: &StoreState, filter: &UserFilter, summary: &UserSummary) -> bool {
+        let uid = summary.id.as_uuid();
+        let created_at = summary.created_at.value();
+
+        if !filter.statuses.is_empty() && !filter.statuses.contains(&summary.status) {
+            return false;
+        }
+        if filter.email_verified.is_some_and(|verified| verified != summary.email_verified) {
+            return false;
+        }
+        if filter.phone_verified.is_some_and(|verified| verified != summary.phone_verified) {
+            return false;
+        }
+        if filter.created_from.is_some_and(|from| created_at < from) || filter.created_to.is_some_and(|to| created_at >= to) {
+            return false;
+        }
+
+        if let Some(role) = &filter.role {
+            let now = Utc::now();
+            let holds = state.user_roles.iter().any(|row| {
+                row.user_id == uid
+                    && row.is_active
+                    && row.expires_at.is_none_or(|expires_at| expires_at > now)
+                    && state.roles.get(&row.role_id).is_some_and(|stored| stored.name == *role)
+            });
+            if !holds {
+                return false;
+            }
+        }
+
+        if let Some(tier) = &filter.subscription_tier {
+            let subscribed = state
+                .subscriptions
+                .iter()
+                .any(|row| row.user_id == uid && row.tier == *tier && row.status == SubscriptionStatus::Active);
+            if !subscribed {
+                return false;
+            }
+        }
+
+        if let Some(text) = filter.search_text() {
+            let contains = |value: &str| value.to_lowercase().contains(&text);
+            let found = contains(summary.email.as_str())
+                || summary.username.as_ref().is_some_and(|username| contains(username.as_ref()))
+                || summary.display_name.as_deref().is_some_and(contains);
+            if !found {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[async_trait]
+impl UserQueryRepository for InMemoryUserQueryRepository {
+    async fn search(&self, query: &UserQuery) -> Result<UserPage, RepositoryError> {
+        let position = query.position()?;
+        let size = query.page_size() as usize;
+        let sort = query.sort;
+
+        let mut rows: Vec<UserSummary> = self.store.read(|state| {
+            state
+                .users
+                .values()
+                .map(|snapshot| Self::summary(state, snapshot))
+                .filter(|summary| Self::matches(state, &query.filter, summary))
+                .collect()
+        });
+
+        rows.sort_by(|a, b| {
+            let ordering = (sort.key_of(a), a.id.as_uuid()).cmp(&(sort.key_of(b), b.id.as_uuid()));
+            match sort.direction {
+                SortDirection::Asc => ordering,
+                SortDirection::Desc => ordering.reverse(),
+            }
+        });
+
+        if let Some(position) = position {
+            let cursor = (position.key, position.id);
+            rows.retain(|row| {
+                let key = (sort.key_of(row), row.id.as_uuid());
+                match sort.direction {
+                    SortDirection::Asc => key > cursor,
+                    SortDirection::Desc => key < cursor,
+                }
+            });
+        }
+
+        let has_more = rows.len() > size;
+        rows.truncate(size);
+
+        let next_cursor = match rows.last() {
+            Some(last) if has_more => Some(UserCursor::encode(&sort, last)),
+            _ => None,
+        };
+
+        Ok(UserPage { items: rows, next_cursor })
+    }
+}
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+
+use crate::user::domain::entities::{User, UserSnapshot};
+use crate::user::domain::repositories::{RepositoryError, UserRepository};
+use crate::user::domain::vo::{Email, UserId, Username};
+
+use crate::user::infrastructure::persistence::memory::InMemoryStore;
+
+/// Repositorio de usuarios en memoria con las mismas reglas de unicidad,
+/// concurrencia y borrado lógico que `UserRepositorySeaOrm`.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryUserRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryUserRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    fn find(&self, predicate: impl Fn(&UserSnapshot) -> bool) -> Result<Option<User>, RepositoryError> {
+        self.store
+            .read(|state| state.users.values().find(|snapshot| predicate(snapshot)).cloned())
+            .map(User::restore)
+            .transpose()
+            .map_err(RepositoryError::from)
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn get_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
+        self.find(|snapshot| snapshot.id == *id)
+    }
+
+    async fn get_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError> {
+        self.find(|snapshot| snapshot.email == *email)
+    }
+
+    async fn get_by_username(&self, username: &Username) -> Result<Option<User>, RepositoryError> {
+        self.find(|snapshot| snapshot.username.as_ref() == Some(username))
+    }
+
+    async fn exists_by_email(&self, email: &Email) -> Result<bool, RepositoryError> {
+        Ok(self.store.read(|state| state.users.values().any(|snapshot| snapshot.email == *email)))
+    }
+
+    async fn exists_by_username(&self, username: &Username) -> Result<bool, RepositoryError> {
+        Ok(self.store.read(|state| state.users.values().any(|snapshot| snapshot.username.as_ref() == Some(username))))
+    }
+
+    async fn list_all(&self) -> Result<Vec<User>, RepositoryError> {
+        let mut snapshots: Vec<UserSnapshot> = self.store.read(|state| state.users.values().cloned().collect());
+        snapshots.sort_by_key(|snapshot| (snapshot.created_at.value(), snapshot.id.as_uuid()));
+
+        snapshots
+            .into_iter()
+            .map(|snapshot| User::restore(snapshot).map_err(RepositoryError::from))
+            .collect()
+    }
+
+    async fn save(&self, user: &User) -> Result<(), RepositoryError> {
+        self.store.write(|state| state.save_user(user))
+    }
+
+    async fn soft_delete(&self, id: &UserId) -> Result<(), RepositoryError> {
+        let mut user = self
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "user", id: id.to_string() })?;
+
+        user.delete()?;
+        self.save(&user).await
+    }
+
+    async fn delete(&self, id: &UserId) -> Result<(), RepositoryError> {
+        self.store.write(|state| state.delete_user(id.as_uuid()))
+    }
+}
@@ -0,0 +1,211 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Crea users, roles y asignaciones de roles.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Users::Table)
+                    .if_not_exists()
+                    .col(uuid(Users::UserId).primary_key())
+                    .col(string_null(Users::ExternalId))
+                    .col(string_null(Users::Username))
+                    .col(string(Users::Email))
+                    .col(boolean(Users::EmailVerified))
+                    .col(string_null(Users::Phone))
+                    .col(boolean(Users::PhoneVerified))
+                    .col(string(Users::Status))
+                    .col(timestamp_with_time_zone(Users::CreatedAt))
+                    .col(timestamp_with_time_zone(Users::UpdatedAt))
+                    .col(timestamp_with_time_zone_null(Users::DeletedAt))
+                    .col(big_integer(Users::Version))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_email")
+                    .table(Users::Table)
+                    .col(Users::Email)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_username")
+                    .table(Users::Table)
+                    .col(Users::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_external_id")
+                    .table(Users::Table)
+                    .col(Users::ExternalId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_status")
+                    .table(Users::Table)
+                    .col(Users::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Roles::Table)
+                    .if_not_exists()
+                    .col(uuid(Roles::RoleId).primary_key())
+                    .col(string(Roles::Name))
+                    .col(string_null(Roles::DisplayName))
+                    .col(text_null(Roles::Description))
+                    .col(json(Roles::Permissions))
+                    .col(boolean(Roles::IsSystem))
+                    .col(timestamp_with_time_zone(Roles::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_roles_name")
+                    .table(Roles::Table)
+                    .col(Roles::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserRoles::Table)
+                    .if_not_exists()
+                    .col(uuid(UserRoles::UserRoleId).primary_key())
+                    .col(uuid(UserRoles::UserId))
+                    .col(uuid(UserRoles::RoleId))
+                    .col(uuid_null(UserRoles::GrantedBy))
+                    .col(timestamp_with_time_zone(UserRoles::GrantedAt))
+                    .col(timestamp_with_time_zone_null(UserRoles::ExpiresAt))
+                    .col(boolean(UserRoles::IsActive))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_roles_user_id")
+                            .from(UserRoles::Table, UserRoles::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_roles_role_id")
+                            .from(UserRoles::Table, UserRoles::RoleId)
+                            .to(Roles::Table, Roles::RoleId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_roles_granted_by")
+                            .from(UserRoles::Table, UserRoles::GrantedBy)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_roles_user_role")
+                    .table(UserRoles::Table)
+                    .col(UserRoles::UserId)
+                    .col(UserRoles::RoleId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_roles_role_id")
+                    .table(UserRoles::Table)
+                    .col(UserRoles::RoleId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(UserRoles::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Roles::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Users::Table).to_owned()).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Users {
+    Table,
+    UserId,
+    ExternalId,
+    Username,
+    Email,
+    EmailVerified,
+    Phone,
+    PhoneVerified,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+    Version,
+}
+
+#[derive(DeriveIden)]
+pub enum Roles {
+    Table,
+    RoleId,
+    Name,
+    DisplayName,
+    Description,
+    Permissions,
+    IsSystem,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserRoles {
+    Table,
+    UserRoleId,
+    UserId,
+    RoleId,
+    GrantedBy,
+    GrantedAt,
+    ExpiresAt,
+    IsActive,
+}
@@ -0,0 +1,263 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20250101_000001_create_users_and_roles::Users;
+
+/// Crea credenciales: contraseñas, métodos de autenticación, MFA y sesiones.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserPasswords::Table)
+                    .if_not_exists()
+                    .col(uuid(UserPasswords::PasswordId).primary_key())
+                    .col(uuid(UserPasswords::UserId))
+                    .col(text(UserPasswords::PasswordHash))
+                    .col(string_null(UserPasswords::PasswordSalt))
+                    .col(string_null(UserPasswords::ResetToken))
+                    .col(timestamp_with_time_zone_null(UserPasswords::ResetTokenExpires))
+                    .col(integer(UserPasswords::FailedAttempts))
+                    .col(timestamp_with_time_zone_null(UserPasswords::LockedUntil))
+                    .col(timestamp_with_time_zone(UserPasswords::CreatedAt))
+                    .col(timestamp_with_time_zone(UserPasswords::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_passwords_user_id")
+                            .from(UserPasswords::Table, UserPasswords::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_passwords_user_id")
+                    .table(UserPasswords::Table)
+                    .col(UserPasswords::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_passwords_reset_token")
+                    .table(UserPasswords::Table)
+                    .col(UserPasswords::ResetToken)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserAuthMethods::Table)
+                    .if_not_exists()
+                    .col(uuid(UserAuthMethods::AuthMethodId).primary_key())
+                    .col(uuid(UserAuthMethods::UserId))
+                    .col(string(UserAuthMethods::AuthType))
+                    .col(string_null(UserAuthMethods::Provider))
+                    .col(string_null(UserAuthMethods::ProviderUserId))
+                    .col(boolean(UserAuthMethods::IsPrimary))
+                    .col(boolean(UserAuthMethods::IsVerified))
+                    .col(timestamp_with_time_zone(UserAuthMethods::CreatedAt))
+                    .col(timestamp_with_time_zone_null(UserAuthMethods::LastUsedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_auth_methods_user_id")
+                            .from(UserAuthMethods::Table, UserAuthMethods::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_auth_methods_user_id")
+                    .table(UserAuthMethods::Table)
+                    .col(UserAuthMethods::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_auth_methods_provider_user")
+                    .table(UserAuthMethods::Table)
+                    .col(UserAuthMethods::Provider)
+                    .col(UserAuthMethods::ProviderUserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserMfa::Table)
+                    .if_not_exists()
+                    .col(uuid(UserMfa::MfaId).primary_key())
+                    .col(uuid(UserMfa::UserId))
+                    .col(string(UserMfa::MfaType))
+                    .col(text_null(UserMfa::SecretEncrypted))
+                    .col(json_null(UserMfa::BackupCodesEncrypted))
+                    .col(integer(UserMfa::RecoveryCodesUsed))
+                    .col(boolean(UserMfa::IsEnabled))
+                    .col(boolean(UserMfa::IsVerified))
+                    .col(timestamp_with_time_zone(UserMfa::CreatedAt))
+                    .col(timestamp_with_time_zone_null(UserMfa::LastUsedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_mfa_user_id")
+                            .from(UserMfa::Table, UserMfa::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_mfa_user_type")
+                    .table(UserMfa::Table)
+                    .col(UserMfa::UserId)
+                    .col(UserMfa::MfaType)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserSessions::Table)
+                    .if_not_exists()
+                    .col(uuid(UserSessions::SessionId).primary_key())
+                    .col(uuid(UserSessions::UserId))
+                    .col(string_null(UserSessions::RefreshToken))
+                    .col(integer(UserSessions::AccessTokenVersion))
+                    .col(timestamp_with_time_zone(UserSessions::ExpiresAt))
+                    .col(string_null(UserSessions::IpAddress))
+                    .col(text_null(UserSessions::UserAgent))
+                    .col(json_null(UserSessions::DeviceInfo))
+                    .col(boolean(UserSessions::IsActive))
+                    .col(timestamp_with_time_zone(UserSessions::CreatedAt))
+                    .col(timestamp_with_time_zone_null(UserSessions::LastActivityAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_sessions_user_id")
+                            .from(UserSessions::Table, UserSessions::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_sessions_user_id")
+                    .table(UserSessions::Table)
+                    .col(UserSessions::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_sessions_refresh_token")
+                    .table(UserSessions::Table)
+                    .col(UserSessions::RefreshToken)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(UserSessions::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserMfa::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserAuthMethods::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserPasswords::Table).to_owned()).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum UserPasswords {
+    Table,
+    PasswordId,
+    UserId,
+    PasswordHash,
+    PasswordSalt,
+    ResetToken,
+    ResetTokenExpires,
+    FailedAttempts,
+    LockedUntil,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserAuthMethods {
+    Table,
+    AuthMethodId,
+    UserId,
+    AuthType,
+    Provider,
+    ProviderUserId,
+    IsPrimary,
+    IsVerified,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserMfa {
+    Table,
+    MfaId,
+    UserId,
+    MfaType,
+    SecretEncrypted,
+    BackupCodesEncrypted,
+    RecoveryCodesUsed,
+    IsEnabled,
+    IsVerified,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserSessions {
+    Table,
+    SessionId,
+    UserId,
+    RefreshToken,
+    AccessTokenVersion,
+    ExpiresAt,
+    IpAddress,
+    UserAgent,
+    DeviceInfo,
+    IsActive,
+    CreatedAt,
+    LastActivityAt,
+}
@@ -0,0 +1,241 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20250101_000001_create_users_and_roles::Users;
+
+/// Crea perfil, suscripciones, consentimientos GDPR y auditoría.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserProfiles::Table)
+                    .if_not_exists()
+                    .col(uuid(UserProfiles::ProfileId).primary_key())
+                    .col(uuid(UserProfiles::UserId))
+                    .col(string_null(UserProfiles::FirstName))
+                    .col(string_null(UserProfiles::LastName))
+                    .col(string_null(UserProfiles::DisplayName))
+                    .col(text_null(UserProfiles::AvatarUrl))
+                    .col(text_null(UserProfiles::Bio))
+                    .col(date_null(UserProfiles::BirthDate))
+                    .col(string_null(UserProfiles::Gender))
+                    .col(string(UserProfiles::Locale))
+                    .col(string(UserProfiles::Timezone))
+                    .col(timestamp_with_time_zone(UserProfiles::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_profiles_user_id")
+                            .from(UserProfiles::Table, UserProfiles::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_profiles_user_id")
+                    .table(UserProfiles::Table)
+                    .col(UserProfiles::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserSubscriptions::Table)
+                    .if_not_exists()
+                    .col(uuid(UserSubscriptions::SubscriptionId).primary_key())
+                    .col(uuid(UserSubscriptions::UserId))
+                    .col(string(UserSubscriptions::Tier))
+                    .col(string(UserSubscriptions::Status))
+                    .col(timestamp_with_time_zone(UserSubscriptions::StartsAt))
+                    .col(timestamp_with_time_zone_null(UserSubscriptions::ExpiresAt))
+                    .col(boolean(UserSubscriptions::AutoRenew))
+                    .col(string_null(UserSubscriptions::PaymentMethod))
+                    .col(timestamp_with_time_zone(UserSubscriptions::CreatedAt))
+                    .col(timestamp_with_time_zone(UserSubscriptions::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_subscriptions_user_id")
+                            .from(UserSubscriptions::Table, UserSubscriptions::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_subscriptions_user_status")
+                    .table(UserSubscriptions::Table)
+                    .col(UserSubscriptions::UserId)
+                    .col(UserSubscriptions::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserGdprConsents::Table)
+                    .if_not_exists()
+                    .col(uuid(UserGdprConsents::ConsentId).primary_key())
+                    .col(uuid(UserGdprConsents::UserId))
+                    .col(string(UserGdprConsents::ConsentType))
+                    .col(boolean(UserGdprConsents::ConsentGiven))
+                    .col(json_null(UserGdprConsents::ConsentDetails))
+                    .col(string_null(UserGdprConsents::IpAddress))
+                    .col(text_null(UserGdprConsents::UserAgent))
+                    .col(timestamp_with_time_zone_null(UserGdprConsents::ExpiresAt))
+                    .col(timestamp_with_time_zone(UserGdprConsents::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_gdpr_consents_user_id")
+                            .from(UserGdprConsents::Table, UserGdprConsents::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_gdpr_consents_user_type")
+                    .table(UserGdprConsents::Table)
+                    .col(UserGdprConsents::UserId)
+                    .col(UserGdprConsents::ConsentType)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserActivityLogs::Table)
+                    .if_not_exists()
+                    .col(uuid(UserActivityLogs::LogId).primary_key())
+                    .col(uuid(UserActivityLogs::UserId))
+                    .col(string(UserActivityLogs::ActionType))
+                    .col(json_null(UserActivityLogs::ActionDetails))
+                    .col(string_null(UserActivityLogs::IpAddress))
+                    .col(text_null(UserActivityLogs::UserAgent))
+                    .col(boolean(UserActivityLogs::Success))
+                    .col(json_null(UserActivityLogs::ErrorDetails))
+                    .col(timestamp_with_time_zone(UserActivityLogs::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_activity_logs_user_id")
+                            .from(UserActivityLogs::Table, UserActivityLogs::UserId)
+                            .to(Users::Table, Users::UserId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_activity_logs_user_created")
+                    .table(UserActivityLogs::Table)
+                    .col(UserActivityLogs::UserId)
+                    .col(UserActivityLogs::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_activity_logs_action_type")
+                    .table(UserActivityLogs::Table)
+                    .col(UserActivityLogs::ActionType)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(UserActivityLogs::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserGdprConsents::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserSubscriptions::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserProfiles::Table).to_owned()).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum UserProfiles {
+    Table,
+    ProfileId,
+    UserId,
+    FirstName,
+    LastName,
+    DisplayName,
+    AvatarUrl,
+    Bio,
+    BirthDate,
+    Gender,
+    Locale,
+    Timezone,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserSubscriptions {
+    Table,
+    SubscriptionId,
+    UserId,
+    Tier,
+    Status,
+    StartsAt,
+    ExpiresAt,
+    AutoRenew,
+    PaymentMethod,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserGdprConsents {
+    Table,
+    ConsentId,
+    UserId,
+    ConsentType,
+    ConsentGiven,
+    ConsentDetails,
+    IpAddress,
+    UserAgent,
+    ExpiresAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserActivityLogs {
+    Table,
+    LogId,
+    UserId,
+    ActionType,
+    ActionDetails,
+    IpAddress,
+    UserAgent,
+    Success,
+    ErrorDetails,
+    CreatedAt,
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Crea bandeja de salida y almacén de eventos.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserOutbox::Table)
+                    .if_not_exists()
+                    .col(uuid(UserOutbox::EventId).primary_key())
+                    .col(uuid(UserOutbox::AggregateId))
+                    .col(big_integer(UserOutbox::AggregateVersion))
+                    .col(string(UserOutbox::EventType))
+                    .col(integer(UserOutbox::SchemaVersion))
+                    .col(timestamp_with_time_zone(UserOutbox::OccurredAt))
+                    .col(json(UserOutbox::Payload))
+                    .col(integer(UserOutbox::Attempts))
+                    .col(text_null(UserOutbox::LastError))
+                    .col(timestamp_with_time_zone(UserOutbox::NextAttemptAt))
+                    .col(timestamp_with_time_zone_null(UserOutbox::PublishedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_outbox_pending")
+                    .table(UserOutbox::Table)
+                    .col(UserOutbox::PublishedAt)
+                    .col(UserOutbox::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_outbox_aggregate_id")
+                    .table(UserOutbox::Table)
+                    .col(UserOutbox::AggregateId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventStore::Table)
+                    .if_not_exists()
+                    .col(string(EventStore::StreamId))
+                    .col(big_integer(EventStore::Version))
+                    .col(uuid(EventStore::EventId))
+                    .col(uuid(EventStore::AggregateId))
+                    .col(string(EventStore::EventType))
+                    .col(integer(EventStore::SchemaVersion))
+                    .col(timestamp_with_time_zone(EventStore::OccurredAt))
+                    .col(json(EventStore::Payload))
+                    .col(timestamp_with_time_zone(EventStore::RecordedAt))
+                    .primary_key(Index::create().col(EventStore::StreamId).col(EventStore::Version))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_event_store_event_id")
+                    .table(EventStore::Table)
+                    .col(EventStore::EventId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(EventStore::Table).to_owned()).await?;
+        manager.drop_table(Table::drop().table(UserOutbox::Table).to_owned()).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum UserOutbox {
+    Table,
+    EventId,
+    AggregateId,
+    AggregateVersion,
+    EventType,
+    SchemaVersion,
+    OccurredAt,
+    Payload,
+    Attempts,
+    LastError,
+    NextAttemptAt,
+    PublishedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum EventStore {
+    Table,
+    StreamId,
+    Version,
+    EventId,
+    AggregateId,
+    EventType,
+    SchemaVersion,
+    OccurredAt,
+    Payload,
+    RecordedAt,
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20250101_000002_create_user_credentials::UserPasswords;
+
+/// Añade `last_failed_at` a `user_passwords` para la ventana de bloqueo.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPasswords::Table)
+                    .add_column(timestamp_with_time_zone_null(LastFailedAt::LastFailedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPasswords::Table)
+                    .drop_column(LastFailedAt::LastFailedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum LastFailedAt {
+    LastFailedAt,
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20250101_000002_create_user_credentials::UserPasswords;
+
+/// Añade a `user_passwords` el historial de hashes anteriores (arreglo JSON,
+/// del más reciente al más antiguo) y la fecha del último cambio.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite sólo admite una columna por `ALTER TABLE`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPasswords::Table)
+                    .add_column(json_null(PasswordHistory::PasswordHistory))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserPasswords::Table)
+                    .add_column(timestamp_with_time_zone_null(PasswordHistory::PasswordChangedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [PasswordHistory::PasswordChangedAt, PasswordHistory::PasswordHistory] {
+            manager
+                .alter_table(Table::alter().table(UserPasswords::Table).drop_column(column).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum PasswordHistory {
+    PasswordHistory,
+    PasswordChangedAt,
+}
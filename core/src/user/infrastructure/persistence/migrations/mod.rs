@@ -0,0 +1,33 @@
+use sea_orm_migration::prelude::*;
+
+pub mod m20250101_000001_create_users_and_roles;
+pub mod m20250101_000002_create_user_credentials;
+pub mod m20250101_000003_create_user_account_tables;
+pub mod m20250101_000004_create_event_tables;
+pub mod m20250101_000005_add_password_last_failed_at;
+pub mod m20250101_000006_add_password_reset_requested_at;
+pub mod m20250101_000007_add_password_history;
+pub mod m20250101_000008_add_users_row_version;
+
+/// Migraciones versionadas del contexto `User` (Postgres, MySQL y SQLite).
+///
+/// ```ignore
+/// Migrator::up(&db, None).await?;
+/// ```
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20250101_000001_create_users_and_roles::Migration),
+            Box::new(m20250101_000002_create_user_credentials::Migration),
+            Box::new(m20250101_000003_create_user_account_tables::Migration),
+            Box::new(m20250101_000004_create_event_tables::Migration),
+            Box::new(m20250101_000005_add_password_last_failed_at::Migration),
+            Box::new(m20250101_000006_add_password_reset_requested_at::Migration),
+            Box::new(m20250101_000007_add_password_history::Migration),
+            Box::new(m20250101_000008_add_users_row_version::Migration),
+        ]
+    }
+}
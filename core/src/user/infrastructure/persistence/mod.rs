@@ -0,0 +1,5 @@
+pub mod errors;
+#[cfg(any(test, feature = "testing"))]
+pub mod memory;
+pub mod migrations;
+pub mod orm;
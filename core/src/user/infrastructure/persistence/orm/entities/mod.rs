@@ -0,0 +1,13 @@
+pub mod roles;
+pub mod stored_event;
+pub mod user_activity_logs;
+pub mod user_auth_methods;
+pub mod user_gdpr_consents;
+pub mod user_mfa;
+pub mod user_outbox;
+pub mod user_passwords;
+pub mod user_profiles;
+pub mod user_roles;
+pub mod user_sessions;
+pub mod user_subscriptions;
+pub mod users;
@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `roles`. Los permisos se guardan como arreglo JSON de cadenas.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "roles")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub role_id: Uuid,
+    #[sea_orm(unique)]
+    pub name: String,
+    pub display_name: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub description: Option<String>,
+    pub permissions: Json,
+    pub is_system: bool,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::user_roles::Entity")]
+    UserRoles,
+}
+
+impl Related<super::user_roles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserRoles.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,24 @@
+use sea_orm::entity::prelude::*;
+
+/// Almacén de eventos: una fila por evento, única por `(stream_id, version)`.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "event_store")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub stream_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub version: i64,
+    #[sea_orm(unique)]
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub event_type: String,
+    pub schema_version: i32,
+    pub occurred_at: DateTimeUtc,
+    pub payload: Json,
+    pub recorded_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
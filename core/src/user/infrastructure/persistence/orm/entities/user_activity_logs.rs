@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_activity_logs`: auditoría de acciones del usuario.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_activity_logs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub log_id: Uuid,
+    pub user_id: Uuid,
+    pub action_type: String,
+    pub action_details: Option<Json>,
+    pub ip_address: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub user_agent: Option<String>,
+    pub success: bool,
+    pub error_details: Option<Json>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_auth_methods`: métodos de autenticación (contraseña, OIDC, SAML...).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_auth_methods")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub auth_method_id: Uuid,
+    pub user_id: Uuid,
+    pub auth_type: String,
+    pub provider: Option<String>,
+    pub provider_user_id: Option<String>,
+    pub is_primary: bool,
+    pub is_verified: bool,
+    pub created_at: DateTimeUtc,
+    pub last_used_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
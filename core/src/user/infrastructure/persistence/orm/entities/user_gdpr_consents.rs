@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_gdpr_consents`: registro de consentimientos (sólo anexado).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_gdpr_consents")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub consent_id: Uuid,
+    pub user_id: Uuid,
+    pub consent_type: String,
+    pub consent_given: bool,
+    pub consent_details: Option<Json>,
+    pub ip_address: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub user_agent: Option<String>,
+    pub expires_at: Option<DateTimeUtc>,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
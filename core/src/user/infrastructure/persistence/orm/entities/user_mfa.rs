@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_mfa`: factores de autenticación adicionales.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_mfa")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub mfa_id: Uuid,
+    pub user_id: Uuid,
+    pub mfa_type: String,
+    #[sea_orm(column_type = "Text")]
+    pub secret_encrypted: Option<String>,
+    pub backup_codes_encrypted: Option<Json>,
+    pub recovery_codes_used: i32,
+    pub is_enabled: bool,
+    pub is_verified: bool,
+    pub created_at: DateTimeUtc,
+    pub last_used_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
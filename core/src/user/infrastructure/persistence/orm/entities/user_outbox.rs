@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+
+/// Bandeja de salida de eventos del contexto `User`.
+/// Una fila por evento; `event_id` garantiza que reinsertar sea inofensivo.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_outbox")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event_id: Uuid,
+    pub aggregate_id: Uuid,
+    pub aggregate_version: i64,
+    pub event_type: String,
+    pub schema_version: i32,
+    pub occurred_at: DateTimeUtc,
+    pub payload: Json,
+    pub attempts: i32,
+    #[sea_orm(column_type = "Text")]
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTimeUtc,
+    pub published_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
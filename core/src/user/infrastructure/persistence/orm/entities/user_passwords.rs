@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_passwords`: credencial de contraseña vigente (una por usuario).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_passwords")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub password_id: Uuid,
+    #[sea_orm(unique)]
+    pub user_id: Uuid,
+    #[sea_orm(column_type = "Text")]
+    pub password_hash: String,
+    pub password_salt: Option<String>,
+    pub reset_token: Option<String>,
+    pub reset_token_expires: Option<DateTimeUtc>,
+    pub reset_requested_at: Option<DateTimeUtc>,
+    pub failed_attempts: i32,
+    pub last_failed_at: Option<DateTimeUtc>,
+    pub locked_until: Option<DateTimeUtc>,
+    /// Hashes anteriores como arreglo JSON de cadenas.
+    pub password_history: Option<Json>,
+    pub password_changed_at: Option<DateTimeUtc>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
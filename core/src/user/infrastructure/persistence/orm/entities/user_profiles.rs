@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_profiles`: datos de perfil (uno por usuario).
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_profiles")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub profile_id: Uuid,
+    #[sea_orm(unique)]
+    pub user_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub display_name: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub avatar_url: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub bio: Option<String>,
+    pub birth_date: Option<Date>,
+    pub gender: Option<String>,
+    pub locale: String,
+    pub timezone: String,
+    pub created_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
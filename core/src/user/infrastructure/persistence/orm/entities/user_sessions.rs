@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_sessions`: sesiones abiertas por usuario.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_sessions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    #[sea_orm(unique)]
+    pub refresh_token: Option<String>,
+    pub access_token_version: i32,
+    pub expires_at: DateTimeUtc,
+    pub ip_address: Option<String>,
+    #[sea_orm(column_type = "Text")]
+    pub user_agent: Option<String>,
+    pub device_info: Option<Json>,
+    pub is_active: bool,
+    pub created_at: DateTimeUtc,
+    pub last_activity_at: Option<DateTimeUtc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `user_subscriptions`: historial de suscripciones.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "user_subscriptions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub subscription_id: Uuid,
+    pub user_id: Uuid,
+    pub tier: String,
+    pub status: String,
+    pub starts_at: DateTimeUtc,
+    pub expires_at: Option<DateTimeUtc>,
+    pub auto_renew: bool,
+    pub payment_method: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::users::Entity",
+        from = "Column::UserId",
+        to = "super::users::Column::UserId",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::users::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
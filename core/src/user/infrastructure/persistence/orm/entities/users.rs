@@ -0,0 +1,104 @@
+use sea_orm::entity::prelude::*;
+
+/// Tabla `users`: estado actual del agregado `User`.
+/// `version` guarda la versión del agregado (número de eventos) y `row_version`
+/// cuenta los guardados de `UserAggregate`, para el control de concurrencia optimista.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "users")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: Uuid,
+    #[sea_orm(unique)]
+    pub external_id: Option<String>,
+    #[sea_orm(unique)]
+    pub username: Option<String>,
+    #[sea_orm(unique)]
+    pub email: String,
+    pub email_verified: bool,
+    pub phone: Option<String>,
+    pub phone_verified: bool,
+    pub status: String,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+    pub deleted_at: Option<DateTimeUtc>,
+    pub version: i64,
+    pub row_version: i64,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::user_roles::Entity")]
+    UserRoles,
+    #[sea_orm(has_many = "super::user_passwords::Entity")]
+    UserPasswords,
+    #[sea_orm(has_many = "super::user_auth_methods::Entity")]
+    UserAuthMethods,
+    #[sea_orm(has_many = "super::user_mfa::Entity")]
+    UserMfa,
+    #[sea_orm(has_many = "super::user_sessions::Entity")]
+    UserSessions,
+    #[sea_orm(has_many = "super::user_profiles::Entity")]
+    UserProfiles,
+    #[sea_orm(has_many = "super::user_subscriptions::Entity")]
+    UserSubscriptions,
+    #[sea_orm(has_many = "super::user_gdpr_consents::Entity")]
+    UserGdprConsents,
+    #[sea_orm(has_many = "super::user_activity_logs::Entity")]
+    UserActivityLogs,
+}
+
+impl Related<super::user_roles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserRoles.def()
+    }
+}
+
+impl Related<super::user_passwords::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserPasswords.def()
+    }
+}
+
+impl Related<super::user_auth_methods::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserAuthMethods.def()
+    }
+}
+
+impl Related<super::user_mfa::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserMfa.def()
+    }
+}
+
+impl Related<super::user_sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserSessions.def()
+    }
+}
+
+impl Related<super::user_profiles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserProfiles.def()
+    }
+}
+
+impl Related<super::user_subscriptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserSubscriptions.def()
+    }
+}
+
+impl Related<super::user_gdpr_consents::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserGdprConsents.def()
+    }
+}
+
+impl Related<super::user_activity_logs::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserActivityLogs.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
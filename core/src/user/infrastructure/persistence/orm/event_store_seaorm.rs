@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Set, SqlErr, TransactionTrait,
+};
+
+use uuid::Uuid;
+
+use crate::user::domain::events::UserEventEnvelope;
+use crate::user::domain::repositories::{EventStore, RepositoryError};
+use crate::user::domain::vo::{OccurredAt, UserId};
+
+use crate::user::infrastructure::persistence::orm::entities::stored_event;
+
+pub struct EventStoreSeaOrm {
+    pub db: DatabaseConnection,
+}
+
+impl EventStoreSeaOrm {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+
+    async fn current_version<C: ConnectionTrait>(conn: &C, stream_id: &str) -> Result<u64, DbErr> {
+        let version: Option<Option<i64>> = stored_event::Entity::find()
+            .select_only()
+            .column_as(stored_event::Column::Version.max(), "version")
+            .filter(stored_event::Column::StreamId.eq(stream_id))
+            .into_tuple()
+            .one(conn)
+            .await?;
+
+        Ok(version.flatten().unwrap_or(0).max(0) as u64)
+    }
+
+    /// Primer `event_id` del lote ya guardado o repetido dentro del lote.
+    async fn duplicate_event_id(&self, events: &[UserEventEnvelope]) -> Result<Option<Uuid>, DbErr> {
+        let ids: Vec<Uuid> = events.iter().map(|envelope| envelope.event_id).collect();
+        let stored: Vec<Uuid> = stored_event::Entity::find()
+            .select_only()
+            .column(stored_event::Column::EventId)
+            .filter(stored_event::Column::EventId.is_in(ids.clone()))
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        Ok(ids
+            .iter()
+            .enumerate()
+            .find(|(i, id)| stored.contains(id) || ids[..*i].contains(id))
+            .map(|(_, id)| *id))
+    }
+
+    fn to_envelope(model: stored_event::Model) -> UserEventEnvelope {
+        UserEventEnvelope {
+            event_id: model.event_id,
+            event_type: model.event_type,
+            aggregate_id: UserId::from_uuid(model.aggregate_id),
+            aggregate_version: model.version.max(0) as u64,
+            schema_version: u16::try_from(model.schema_version).unwrap_or(0),
+            occurred_at: OccurredAt::from_datetime(model.occurred_at),
+            payload: model.payload,
+        }
+    }
+}
+
+#[async_trait]
+impl EventStore for EventStoreSeaOrm {
+    async fn append(&self, stream_id: &str, expected_version: u64, events: &[UserEventEnvelope]) -> Result<u64, RepositoryError> {
+        let txn = self.db.begin().await?;
+
+        let actual = Self::current_version(&txn, stream_id).await?;
+        if actual != expected_version {
+            return Err(RepositoryError::Concurrency { stream_id: stream_id.to_string(), expected: expected_version, actual });
+        }
+
+        if events.is_empty() {
+            return Ok(actual);
+        }
+
+        let recorded_at = Utc::now();
+        let models = events.iter().zip(expected_version + 1..).map(|(envelope, version)| stored_event::ActiveModel {
+            stream_id: Set(stream_id.to_string()),
+            version: Set(version as i64),
+            event_id: Set(envelope.event_id),
+            aggregate_id: Set(envelope.aggregate_id.as_uuid()),
+            event_type: Set(envelope.event_type.clone()),
+            schema_version: Set(i32::from(envelope.schema_version)),
+            occurred_at: Set(envelope.occurred_at.value()),
+            payload: Set(envelope.payload.clone()),
+            recorded_at: Set(recorded_at),
+        });
+
+        // Dos escrituras simultáneas pueden pasar la comprobación anterior:
+        // la clave (stream_id, version) hace que sólo una llegue a confirmarse.
+        // La transacción se cierra antes de averiguar qué índice saltó: en
+        // Postgres ya está abortada y en SQLite retiene la única conexión.
+        if let Err(err) = stored_event::Entity::insert_many(models).exec(&txn).await {
+            txn.rollback().await?;
+            if !matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+                return Err(err.into());
+            }
+
+            let actual = Self::current_version(&self.db, stream_id).await?;
+            if actual != expected_version {
+                return Err(RepositoryError::Concurrency { stream_id: stream_id.to_string(), expected: expected_version, actual });
+            }
+            return Err(match self.duplicate_event_id(events).await? {
+                Some(event_id) => RepositoryError::Conflict { entity: "event", field: "event_id", value: event_id.to_string() },
+                None => err.into(),
+            });
+        }
+
+        txn.commit().await?;
+        Ok(expected_version + events.len() as u64)
+    }
+
+    async fn read_stream(&self, stream_id: &str, from_version: u64) -> Result<Vec<UserEventEnvelope>, RepositoryError> {
+        let models = stored_event::Entity::find()
+            .filter(stored_event::Column::StreamId.eq(stream_id))
+            .filter(stored_event::Column::Version.gte(from_version as i64))
+            .order_by_asc(stored_event::Column::Version)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(Self::to_envelope).collect())
+    }
+
+    async fn stream_version(&self, stream_id: &str) -> Result<u64, RepositoryError> {
+        Ok(Self::current_version(&self.db, stream_id).await?)
+    }
+}
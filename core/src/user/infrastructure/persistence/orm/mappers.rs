@@ -0,0 +1,445 @@
+//! Conversión entre las entidades hijas de `UserAggregate` y sus filas SeaORM.
+//!
+//! Al leer se construyen los structs directamente, sin pasar por sus
+//! constructores: una sesión ya expirada o un rol vencido siguen siendo datos
+//! válidos en la base de datos.
+
+use std::net::IpAddr;
+
+use uuid::Uuid;
+
+use crate::user::domain::entities::{
+    MfaType, UserActivityLog, UserAuthMethod, UserGdprConsent, UserMfa, UserPassword, UserProfile, UserRole,
+    UserSession, UserSubscription,
+};
+use crate::user::domain::repositories::RepositoryError;
+use crate::user::domain::vo::{
+    AuthType, ConsentType, Gender, Locale, SubscriptionStatus, SubscriptionTier, Timezone,
+};
+
+use crate::user::infrastructure::persistence::orm::entities::{
+    user_activity_logs, user_auth_methods, user_gdpr_consents, user_mfa, user_passwords, user_profiles, user_roles,
+    user_sessions, user_subscriptions,
+};
+
+/// Entidad hija persistida en una fila propia, identificada por un UUID.
+pub(crate) trait ChildRow: Sized {
+    type Model;
+
+    fn id(&self) -> Uuid;
+    fn user_id(&self) -> Uuid;
+    fn to_model(&self) -> Self::Model;
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError>;
+}
+
+/// Sustituye `current` por lo almacenado, conservando los elementos que
+/// sólo existen en memoria (añadidos antes de la carga diferida).
+pub(crate) fn merge_rows<T: ChildRow>(stored: Vec<T>, current: &mut Vec<T>) {
+    let mut pending = std::mem::take(current);
+
+    for child in stored {
+        match pending.iter().position(|p| p.id() == child.id()) {
+            Some(index) => current.push(pending.remove(index)),
+            None => current.push(child),
+        }
+    }
+    current.extend(pending);
+}
+
+impl ChildRow for UserProfile {
+    type Model = user_profiles::Model;
+
+    fn id(&self) -> Uuid {
+        self.profile_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_profiles::Model {
+            profile_id: self.profile_id,
+            user_id: self.user_id,
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            display_name: self.display_name.clone(),
+            avatar_url: self.avatar_url.clone(),
+            bio: self.bio.clone(),
+            birth_date: self.birth_date,
+            gender: self.gender.as_ref().map(|g| g.as_str().to_string()),
+            locale: self.locale.as_str(),
+            timezone: self.timezone.as_str().to_string(),
+            created_at: self.created_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            profile_id: model.profile_id,
+            user_id: model.user_id,
+            first_name: model.first_name,
+            last_name: model.last_name,
+            display_name: model.display_name,
+            avatar_url: model.avatar_url,
+            bio: model.bio,
+            birth_date: model.birth_date,
+            gender: model.gender.as_deref().map(Gender::try_from).transpose()?,
+            locale: Locale::try_from(model.locale.as_str())?,
+            timezone: Timezone::try_from(model.timezone.as_str())?,
+            created_at: model.created_at,
+        })
+    }
+}
+
+impl ChildRow for UserPassword {
+    type Model = user_passwords::Model;
+
+    fn id(&self) -> Uuid {
+        self.password_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_passwords::Model {
+            password_id: self.password_id,
+            user_id: self.user_id,
+            password_hash: self.password_hash.clone(),
+            password_salt: self.password_salt.clone(),
+            reset_token: self.reset_token.clone(),
+            reset_token_expires: self.reset_token_expires,
+            reset_requested_at: self.reset_requested_at,
+            failed_attempts: self.failed_attempts,
+            last_failed_at: self.last_failed_at,
+            locked_until: self.locked_until,
+            password_history: (!self.password_history.is_empty()).then(|| serde_json::json!(self.password_history)),
+            password_changed_at: self.password_changed_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            password_id: model.password_id,
+            user_id: model.user_id,
+            password_hash: model.password_hash,
+            password_salt: model.password_salt,
+            reset_token: model.reset_token,
+            reset_token_expires: model.reset_token_expires,
+            reset_requested_at: model.reset_requested_at,
+            failed_attempts: model.failed_attempts,
+            last_failed_at: model.last_failed_at,
+            locked_until: model.locked_until,
+            password_history: model
+                .password_history
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(RepositoryError::infrastructure)?
+                .unwrap_or_default(),
+            password_changed_at: model.password_changed_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        })
+    }
+}
+
+impl ChildRow for UserAuthMethod {
+    type Model = user_auth_methods::Model;
+
+    fn id(&self) -> Uuid {
+        self.auth_method_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_auth_methods::Model {
+            auth_method_id: self.auth_method_id,
+            user_id: self.user_id,
+            auth_type: self.auth_type.as_str().to_string(),
+            provider: self.provider.clone(),
+            provider_user_id: self.provider_user_id.clone(),
+            is_primary: self.is_primary,
+            is_verified: self.is_verified,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            auth_method_id: model.auth_method_id,
+            user_id: model.user_id,
+            auth_type: AuthType::try_from(model.auth_type.as_str())?,
+            provider: model.provider,
+            provider_user_id: model.provider_user_id,
+            is_primary: model.is_primary,
+            is_verified: model.is_verified,
+            created_at: model.created_at,
+            last_used_at: model.last_used_at,
+        })
+    }
+}
+
+impl ChildRow for UserMfa {
+    type Model = user_mfa::Model;
+
+    fn id(&self) -> Uuid {
+        self.mfa_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_mfa::Model {
+            mfa_id: self.mfa_id,
+            user_id: self.user_id,
+            mfa_type: self.mfa_type.as_str().to_string(),
+            secret_encrypted: self.secret_encrypted.clone(),
+            backup_codes_encrypted: self.backup_codes_encrypted.as_ref().map(|codes| serde_json::json!(codes)),
+            recovery_codes_used: self.recovery_codes_used,
+            is_enabled: self.is_enabled,
+            is_verified: self.is_verified,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        let backup_codes_encrypted = model
+            .backup_codes_encrypted
+            .map(serde_json::from_value::<Vec<String>>)
+            .transpose()
+            .map_err(RepositoryError::infrastructure)?;
+
+        Ok(Self {
+            mfa_id: model.mfa_id,
+            user_id: model.user_id,
+            mfa_type: MfaType::try_from(model.mfa_type.as_str())?,
+            secret_encrypted: model.secret_encrypted,
+            backup_codes_encrypted,
+            recovery_codes_used: model.recovery_codes_used,
+            is_enabled: model.is_enabled,
+            is_verified: model.is_verified,
+            created_at: model.created_at,
+            last_used_at: model.last_used_at,
+        })
+    }
+}
+
+impl ChildRow for UserSession {
+    type Model = user_sessions::Model;
+
+    fn id(&self) -> Uuid {
+        self.session_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_sessions::Model {
+            session_id: self.session_id,
+            user_id: self.user_id,
+            refresh_token: self.refresh_token.clone(),
+            access_token_version: self.access_token_version,
+            expires_at: self.expires_at,
+            ip_address: self.ip_address.clone(),
+            user_agent: self.user_agent.clone(),
+            device_info: self.device_info.clone(),
+            is_active: self.is_active,
+            created_at: self.created_at,
+            last_activity_at: self.last_activity_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            session_id: model.session_id,
+            user_id: model.user_id,
+            refresh_token: model.refresh_token,
+            access_token_version: model.access_token_version,
+            expires_at: model.expires_at,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            device_info: model.device_info,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            last_activity_at: model.last_activity_at,
+        })
+    }
+}
+
+impl ChildRow for UserRole {
+    type Model = user_roles::Model;
+
+    fn id(&self) -> Uuid {
+        self.user_role_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_roles::Model {
+            user_role_id: self.user_role_id,
+            user_id: self.user_id,
+            role_id: self.role_id,
+            granted_by: self.granted_by,
+            granted_at: self.granted_at,
+            expires_at: self.expires_at,
+            is_active: self.is_active,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            user_role_id: model.user_role_id,
+            user_id: model.user_id,
+            role_id: model.role_id,
+            granted_by: model.granted_by,
+            granted_at: model.granted_at,
+            expires_at: model.expires_at,
+            is_active: model.is_active,
+        })
+    }
+}
+
+impl ChildRow for UserSubscription {
+    type Model = user_subscriptions::Model;
+
+    fn id(&self) -> Uuid {
+        self.subscription_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_subscriptions::Model {
+            subscription_id: self.subscription_id,
+            user_id: self.user_id,
+            tier: self.tier.as_str().to_string(),
+            status: self.status.as_str().to_string(),
+            starts_at: self.starts_at,
+            expires_at: self.expires_at,
+            auto_renew: self.auto_renew,
+            payment_method: self.payment_method.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            subscription_id: model.subscription_id,
+            user_id: model.user_id,
+            tier: SubscriptionTier::try_from(model.tier.as_str())?,
+            status: SubscriptionStatus::try_from(model.status.as_str())?,
+            starts_at: model.starts_at,
+            expires_at: model.expires_at,
+            auto_renew: model.auto_renew,
+            payment_method: model.payment_method,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        })
+    }
+}
+
+impl ChildRow for UserGdprConsent {
+    type Model = user_gdpr_consents::Model;
+
+    fn id(&self) -> Uuid {
+        self.consent_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_gdpr_consents::Model {
+            consent_id: self.consent_id,
+            user_id: self.user_id,
+            consent_type: self.consent_type.as_str().to_string(),
+            consent_given: self.consent_given,
+            consent_details: self.consent_details.clone(),
+            ip_address: self.ip_address.map(|ip| ip.to_string()),
+            user_agent: self.user_agent.clone(),
+            expires_at: self.expires_at,
+            created_at: self.created_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        let ip_address = model
+            .ip_address
+            .as_deref()
+            .map(str::parse::<IpAddr>)
+            .transpose()
+            .map_err(RepositoryError::infrastructure)?;
+
+        Ok(Self {
+            consent_id: model.consent_id,
+            user_id: model.user_id,
+            consent_type: ConsentType::try_from(model.consent_type.as_str())?,
+            consent_given: model.consent_given,
+            consent_details: model.consent_details,
+            ip_address,
+            user_agent: model.user_agent,
+            expires_at: model.expires_at,
+            created_at: model.created_at,
+        })
+    }
+}
+
+impl ChildRow for UserActivityLog {
+    type Model = user_activity_logs::Model;
+
+    fn id(&self) -> Uuid {
+        self.log_id
+    }
+
+    fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn to_model(&self) -> Self::Model {
+        user_activity_logs::Model {
+            log_id: self.log_id,
+            user_id: self.user_id,
+            action_type: self.action_type.clone(),
+            action_details: self.action_details.clone(),
+            ip_address: self.ip_address.clone(),
+            user_agent: self.user_agent.clone(),
+            success: self.success,
+            error_details: self.error_details.clone(),
+            created_at: self.created_at,
+        }
+    }
+
+    fn from_model(model: Self::Model) -> Result<Self, RepositoryError> {
+        Ok(Self {
+            log_id: model.log_id,
+            user_id: model.user_id,
+            action_type: model.action_type,
+            action_details: model.action_details,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            success: model.success,
+            error_details: model.error_details,
+            created_at: model.created_at,
+        })
+    }
+}
@@ -0,0 +1,17 @@
+pub mod entities;
+pub mod event_store_seaorm;
+pub(crate) mod mappers;
+pub mod outbox_repository_seaorm;
+pub mod role_repository_seaorm;
+pub mod unit_of_work_seaorm;
+pub mod user_aggregate_repository_seaorm;
+pub mod user_query_repository_seaorm;
+pub mod user_repository_seaorm;
+
+pub use event_store_seaorm::EventStoreSeaOrm;
+pub use outbox_repository_seaorm::OutboxRepositorySeaOrm;
+pub use role_repository_seaorm::RoleRepositorySeaOrm;
+pub use unit_of_work_seaorm::{UnitOfWorkFactorySeaOrm, UnitOfWorkSeaOrm};
+pub use user_aggregate_repository_seaorm::UserAggregateRepositorySeaOrm;
+pub use user_query_repository_seaorm::UserQueryRepositorySeaOrm;
+pub use user_repository_seaorm::UserRepositorySeaOrm;
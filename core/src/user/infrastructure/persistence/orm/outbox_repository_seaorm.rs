@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Alias, Expr, OnConflict, Query};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use uuid::Uuid;
+
+use crate::user::domain::events::UserDomainEvent;
+use crate::user::domain::repositories::{OutboxMessage, OutboxRepository, RepositoryError};
+
+use crate::user::infrastructure::persistence::orm::entities::user_outbox;
+
+pub struct OutboxRepositorySeaOrm {
+    pub db: DatabaseConnection,
+}
+
+impl OutboxRepositorySeaOrm {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+
+    /// Guarda los eventos en la bandeja de salida usando la conexión recibida.
+    ///
+    /// Debe llamarse con la misma `DatabaseTransaction` que persiste el agregado:
+    /// si la transacción se revierte, los eventos tampoco quedan guardados.
+    /// Reinsertar un evento ya presente (mismo `event_id`) no tiene efecto.
+    pub async fn append<C: ConnectionTrait>(conn: &C, events: &[UserDomainEvent]) -> Result<(), RepositoryError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let models = events.iter().map(|event| Self::to_active_model(OutboxMessage::pending(event)));
+
+        user_outbox::Entity::insert_many(models)
+            .on_conflict(OnConflict::column(user_outbox::Column::EventId).do_nothing().to_owned())
+            .do_nothing()
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    fn to_active_model(message: OutboxMessage) -> user_outbox::ActiveModel {
+        user_outbox::ActiveModel {
+            event_id: Set(message.event_id),
+            aggregate_id: Set(message.aggregate_id),
+            aggregate_version: Set(message.aggregate_version as i64),
+            event_type: Set(message.event_type),
+            schema_version: Set(i32::from(message.schema_version)),
+            occurred_at: Set(message.occurred_at),
+            payload: Set(message.payload),
+            attempts: Set(message.attempts as i32),
+            last_error: Set(message.last_error),
+            next_attempt_at: Set(message.next_attempt_at),
+            published_at: Set(message.published_at),
+        }
+    }
+
+    fn to_message(model: user_outbox::Model) -> OutboxMessage {
+        OutboxMessage {
+            event_id: model.event_id,
+            aggregate_id: model.aggregate_id,
+            aggregate_version: model.aggregate_version.max(0) as u64,
+            event_type: model.event_type,
+            schema_version: u16::try_from(model.schema_version).unwrap_or(0),
+            occurred_at: model.occurred_at,
+            payload: model.payload,
+            attempts: model.attempts.max(0) as u32,
+            last_error: model.last_error,
+            next_attempt_at: model.next_attempt_at,
+            published_at: model.published_at,
+        }
+    }
+
+    /// Todos los mensajes, publicados o no (diagnóstico y tests).
+    pub async fn list_all(&self) -> Result<Vec<OutboxMessage>, RepositoryError> {
+        let models = user_outbox::Entity::find()
+            .order_by_asc(user_outbox::Column::OccurredAt)
+            .order_by_asc(user_outbox::Column::AggregateVersion)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(Self::to_message).collect())
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for OutboxRepositorySeaOrm {
+    async fn pending(&self, now: DateTime<Utc>, max_attempts: u32, limit: u64) -> Result<Vec<OutboxMessage>, RepositoryError> {
+        let earlier = Alias::new("earlier");
+        let blocked_by_earlier = Query::select()
+            .expr(Expr::val(1))
+            .from_as(user_outbox::Entity, earlier.clone())
+            .and_where(
+                Expr::col((earlier.clone(), user_outbox::Column::AggregateId))
+                    .equals((user_outbox::Entity, user_outbox::Column::AggregateId)),
+            )
+            .and_where(
+                Expr::col((earlier.clone(), user_outbox::Column::AggregateVersion))
+                    .lt(Expr::col((user_outbox::Entity, user_outbox::Column::AggregateVersion))),
+            )
+            .and_where(Expr::col((earlier.clone(), user_outbox::Column::PublishedAt)).is_null())
+            .and_where(Expr::col((earlier.clone(), user_outbox::Column::Attempts)).lt(max_attempts as i32))
+            .and_where(Expr::col((earlier, user_outbox::Column::NextAttemptAt)).gt(now))
+            .to_owned();
+
+        let models = user_outbox::Entity::find()
+            .filter(user_outbox::Column::PublishedAt.is_null())
+            .filter(user_outbox::Column::Attempts.lt(max_attempts as i32))
+            .filter(user_outbox::Column::NextAttemptAt.lte(now))
+            .filter(Expr::exists(blocked_by_earlier).not())
+            .order_by_asc(user_outbox::Column::OccurredAt)
+            .order_by_asc(user_outbox::Column::AggregateVersion)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        Ok(models.into_iter().map(Self::to_message).collect())
+    }
+
+    async fn mark_published(&self, event_id: Uuid, published_at: DateTime<Utc>) -> Result<bool, RepositoryError> {
+        let result = user_outbox::Entity::update_many()
+            .col_expr(user_outbox::Column::PublishedAt, Expr::value(published_at))
+            .filter(user_outbox::Column::EventId.eq(event_id))
+            .filter(user_outbox::Column::PublishedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn mark_failed(&self, event_id: Uuid, error: &str, next_attempt_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        user_outbox::Entity::update_many()
+            .col_expr(user_outbox::Column::Attempts, Expr::col(user_outbox::Column::Attempts).add(1))
+            .col_expr(user_outbox::Column::LastError, Expr::value(error))
+            .col_expr(user_outbox::Column::NextAttemptAt, Expr::value(next_attempt_at))
+            .filter(user_outbox::Column::EventId.eq(event_id))
+            .filter(user_outbox::Column::PublishedAt.is_null())
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,77 +1,126 @@
 use async_trait::async_trait;
-use sea_orm::{DatabaseConnection, EntityTrait, ActiveModelTrait, Set, ColumnTrait, QueryFilter};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, Set, SqlErr,
+    TransactionTrait,
+};
 use uuid::Uuid;
 
 use crate::user::domain::entities::role::Role;
 use crate::user::domain::repositories::role_repository::RoleRepository;
-use crate::user::domain::vo::validation_error::ValidationError;
+use crate::user::domain::repositories::RepositoryError;
+use crate::user::domain::vo::RoleName;
 
-// Importa las entidades generadas por SeaORM (ejemplo: sea-orm-cli generate entity)
 use crate::user::infrastructure::persistence::orm::entities::roles;
 
-pub struct RoleRepositorySeaOrm {
-    pub db: DatabaseConnection,
+pub struct RoleRepositorySeaOrm<C = DatabaseConnection> {
+    pub db: C,
 }
 
-impl RoleRepositorySeaOrm {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C> RoleRepositorySeaOrm<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 }
 
-#[async_trait]
-impl RoleRepository for RoleRepositorySeaOrm {
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<Role>, ValidationError> {
-        let role = roles::Entity::find_by_id(id)
-            .one(&self.db)
-            .await
-            .map_err(|_| ValidationError::InvalidRole)?;
-
-        Ok(role.map(|model| Role {
-            id: model.role_id,
-            name: model.name,
-            display_name: model.display_name,
-            description: model.description,
-            permissions: model.permissions.unwrap_or_default(),
-            is_system: model.is_system,
-            created_at: model.created_at,
-        }))
+impl RoleRepositorySeaOrm {
+    fn to_role(model: roles::Model) -> Result<Role, RepositoryError> {
+        let permissions: Vec<String> = serde_json::from_value(model.permissions).map_err(RepositoryError::infrastructure)?;
+
+        Ok(Role::new(
+            model.role_id,
+            &model.name,
+            model.display_name,
+            model.description,
+            permissions,
+            model.is_system,
+            Some(model.created_at),
+        )?)
     }
 
-    async fn save(&self, role: &Role) -> Result<(), ValidationError> {
-        let active_model = roles::ActiveModel {
-            role_id: Set(role.id),
-            name: Set(role.name.clone()),
+    fn to_active_model(role: &Role) -> roles::ActiveModel {
+        roles::ActiveModel {
+            role_id: Set(role.role_id),
+            name: Set(role.name.as_str().to_string()),
             display_name: Set(role.display_name.clone()),
             description: Set(role.description.clone()),
-            permissions: Set(Some(role.permissions.clone())),
+            permissions: Set(serde_json::json!(role.permissions)),
             is_system: Set(role.is_system),
             created_at: Set(role.created_at),
-        };
+        }
+    }
 
-        active_model
-            .insert(&self.db)
-            .await
-            .map_err(|_| ValidationError::InvalidRole)?;
+    fn conflict(err: DbErr, role: &Role) -> RepositoryError {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => RepositoryError::Conflict {
+                entity: "role",
+                field: "name",
+                value: role.name.as_str().to_string(),
+            },
+            _ => err.into(),
+        }
+    }
+}
 
-        Ok(())
+#[async_trait]
+impl<C> RoleRepository for RoleRepositorySeaOrm<C>
+where
+    C: ConnectionTrait + TransactionTrait + Send + Sync,
+{
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Role>, RepositoryError> {
+        roles::Entity::find_by_id(id)
+            .one(&self.db)
+            .await?
+            .map(RoleRepositorySeaOrm::to_role)
+            .transpose()
     }
 
-    async fn find_by_name(&self, name: &str) -> Result<Option<Role>, ValidationError> {
-        let role = roles::Entity::find()
-            .filter(roles::Column::Name.eq(name))
+    async fn get_by_name(&self, name: &RoleName) -> Result<Option<Role>, RepositoryError> {
+        roles::Entity::find()
+            .filter(roles::Column::Name.eq(name.as_str()))
             .one(&self.db)
+            .await?
+            .map(RoleRepositorySeaOrm::to_role)
+            .transpose()
+    }
+
+    async fn list_all(&self) -> Result<Vec<Role>, RepositoryError> {
+        roles::Entity::find()
+            .order_by_asc(roles::Column::Name)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(RoleRepositorySeaOrm::to_role)
+            .collect()
+    }
+
+    async fn save(&self, role: &Role) -> Result<(), RepositoryError> {
+        roles::Entity::insert(RoleRepositorySeaOrm::to_active_model(role))
+            .on_conflict(
+                OnConflict::column(roles::Column::RoleId)
+                    .update_columns([
+                        roles::Column::Name,
+                        roles::Column::DisplayName,
+                        roles::Column::Description,
+                        roles::Column::Permissions,
+                        roles::Column::IsSystem,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
             .await
-            .map_err(|_| ValidationError::InvalidRole)?;
-
-        Ok(role.map(|model| Role {
-            id: model.role_id,
-            name: model.name,
-            display_name: model.display_name,
-            description: model.description,
-            permissions: model.permissions.unwrap_or_default(),
-            is_system: model.is_system,
-            created_at: model.created_at,
-        }))
+            .map_err(|err| RoleRepositorySeaOrm::conflict(err, role))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let result = roles::Entity::delete_by_id(id).exec(&self.db).await?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound { entity: "role", id: id.to_string() });
+        }
+
+        Ok(())
     }
 }
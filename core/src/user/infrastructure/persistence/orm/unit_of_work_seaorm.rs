@@ -0,0 +1,180 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult, IsolationLevel,
+    QueryResult, Statement, TransactionError, TransactionTrait,
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::user::domain::events::{DomainEventPublisher, UserDomainEvent};
+use crate::user::domain::repositories::{
+    OutboxRepository, RepositoryError, RoleRepository, UnitOfWork, UnitOfWorkFactory, UserAggregateRepository,
+    UserRepository,
+};
+
+use crate::user::infrastructure::persistence::orm::{
+    OutboxRepositorySeaOrm, RoleRepositorySeaOrm, UserAggregateRepositorySeaOrm, UserRepositorySeaOrm,
+};
+
+/// Transacción compartida por los repositorios de una unidad de trabajo.
+///
+/// `DatabaseTransaction` no se puede clonar; esta envoltura deja que los tres
+/// adaptadores usen la misma. Sus propias transacciones (`begin`) pasan a ser
+/// puntos de guardado dentro de ella.
+#[derive(Clone)]
+pub(crate) struct SharedTransaction(Arc<DatabaseTransaction>);
+
+#[async_trait]
+impl ConnectionTrait for SharedTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.0.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.0.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.0.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.0.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.0.support_returning()
+    }
+}
+
+#[async_trait]
+impl TransactionTrait for SharedTransaction {
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.0.begin().await
+    }
+
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.0.begin_with_config(isolation_level, access_mode).await
+    }
+
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>> + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.0.transaction(callback).await
+    }
+
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(&'c DatabaseTransaction) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>> + Send,
+        T: Send,
+        E: std::fmt::Display + std::fmt::Debug + Send,
+    {
+        self.0.transaction_with_config(callback, isolation_level, access_mode).await
+    }
+}
+
+/// Abre unidades de trabajo sobre una `DatabaseTransaction`.
+pub struct UnitOfWorkFactorySeaOrm {
+    pub db: DatabaseConnection,
+    publisher: Arc<dyn DomainEventPublisher>,
+}
+
+impl UnitOfWorkFactorySeaOrm {
+    pub fn new(db: DatabaseConnection, publisher: Arc<dyn DomainEventPublisher>) -> Self {
+        Self { db, publisher }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for UnitOfWorkFactorySeaOrm {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, RepositoryError> {
+        let txn = SharedTransaction(Arc::new(self.db.begin().await?));
+
+        Ok(Box::new(UnitOfWorkSeaOrm {
+            users: UserRepositorySeaOrm::new(txn.clone()),
+            roles: RoleRepositorySeaOrm::new(txn.clone()),
+            aggregates: UserAggregateRepositorySeaOrm::new(txn.clone()),
+            txn,
+            outbox: OutboxRepositorySeaOrm::new(self.db.clone()),
+            publisher: self.publisher.clone(),
+            events: Vec::new(),
+        }))
+    }
+}
+
+pub struct UnitOfWorkSeaOrm {
+    txn: SharedTransaction,
+    users: UserRepositorySeaOrm<SharedTransaction>,
+    roles: RoleRepositorySeaOrm<SharedTransaction>,
+    aggregates: UserAggregateRepositorySeaOrm<SharedTransaction>,
+    outbox: OutboxRepositorySeaOrm,
+    publisher: Arc<dyn DomainEventPublisher>,
+    events: Vec<UserDomainEvent>,
+}
+
+impl UnitOfWorkSeaOrm {
+    /// Recupera la transacción para cerrarla; los repositorios ya deben
+    /// haberse soltado.
+    fn release(txn: SharedTransaction) -> Result<DatabaseTransaction, RepositoryError> {
+        Arc::try_unwrap(txn.0).map_err(|_| DbErr::Custom("transaction is still shared".into()).into())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for UnitOfWorkSeaOrm {
+    fn users(&self) -> &dyn UserRepository {
+        &self.users
+    }
+
+    fn roles(&self) -> &dyn RoleRepository {
+        &self.roles
+    }
+
+    fn aggregates(&self) -> &dyn UserAggregateRepository {
+        &self.aggregates
+    }
+
+    fn record_events(&mut self, events: Vec<UserDomainEvent>) {
+        self.events.extend(events);
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let Self { txn, users, roles, aggregates, outbox, publisher, events } = *self;
+        drop((users, roles, aggregates));
+        Self::release(txn)?.commit().await?;
+
+        // Lo entregado se marca en la bandeja para que `OutboxRelay` no lo
+        // repita; desde el primer fallo, el resto queda para el relay y en orden.
+        for event in events {
+            if publisher.publish(&event).await.is_err() || outbox.mark_published(event.event_id(), Utc::now()).await.is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        let Self { txn, users, roles, aggregates, .. } = *self;
+        drop((users, roles, aggregates));
+        Self::release(txn)?.rollback().await?;
+        Ok(())
+    }
+}
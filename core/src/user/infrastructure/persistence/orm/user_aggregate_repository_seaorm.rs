@@ -0,0 +1,271 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, IntoActiveModel, ModelTrait, QueryFilter, QueryOrder, TransactionTrait, Value,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::user::domain::aggregates::{LoadOptions, UserAggregate};
+use crate::user::domain::repositories::{
+    user_stream_id, AggregateChanges, CollectionChanges, RepositoryError, UserAggregateRepository,
+};
+use crate::user::domain::validations::{CategoryError, TypeError};
+use crate::user::domain::vo::UserId;
+
+use crate::user::infrastructure::persistence::orm::entities::{
+    user_activity_logs, user_auth_methods, user_gdpr_consents, user_mfa, user_passwords, user_profiles, user_roles,
+    user_sessions, user_subscriptions, users,
+};
+use crate::user::infrastructure::persistence::orm::mappers::{merge_rows, ChildRow};
+use crate::user::infrastructure::persistence::orm::UserRepositorySeaOrm;
+
+/// Adaptador SeaORM de `UserAggregate`.
+///
+/// Al guardar compara cada colección con lo almacenado y sólo escribe las
+/// diferencias: primero borrados, luego actualizaciones y por último inserciones,
+/// para no chocar con índices únicos al reemplazar un elemento.
+pub struct UserAggregateRepositorySeaOrm<C = DatabaseConnection> {
+    pub db: C,
+}
+
+impl<C> UserAggregateRepositorySeaOrm<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+impl UserAggregateRepositorySeaOrm {
+    async fn load_children<T, E, C>(conn: &C, user_id: Uuid, user_col: E::Column, order_col: E::Column) -> Result<Vec<T>, RepositoryError>
+    where
+        T: ChildRow<Model = E::Model>,
+        E: EntityTrait,
+        C: ConnectionTrait,
+    {
+        E::find()
+            .filter(user_col.eq(user_id))
+            .order_by_asc(order_col)
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(T::from_model)
+            .collect()
+    }
+
+    /// Sincroniza una colección. Si `complete` es falso la colección no se cargó
+    /// entera: sólo se comparan los elementos presentes y nunca se borra.
+    ///
+    /// Cada fila guardada se compara tras leerla como entidad y volver a
+    /// mapearla, para que formas equivalentes (un historial NULL o `[]`) no
+    /// cuenten como cambio.
+    async fn sync_children<T, E>(
+        txn: &DatabaseTransaction,
+        user_id: Uuid,
+        desired: &[T],
+        user_col: E::Column,
+        id_col: E::Column,
+        complete: bool,
+    ) -> Result<CollectionChanges, RepositoryError>
+    where
+        T: ChildRow<Model = E::Model> + Sync,
+        E: EntityTrait,
+        E::Model: IntoActiveModel<E::ActiveModel> + PartialEq + Send + Sync,
+        E::ActiveModel: ActiveModelTrait<Entity = E> + ActiveModelBehavior + Send,
+    {
+        if desired.iter().any(|child| child.user_id() != user_id) {
+            return Err(RepositoryError::Domain((CategoryError::Id, TypeError::Mismatch).into()));
+        }
+
+        let mut query = E::find().filter(user_col.eq(user_id));
+        if !complete {
+            query = query.filter(id_col.is_in(desired.iter().map(ChildRow::id)));
+        }
+
+        let mut stored: HashMap<Uuid, E::Model> = query
+            .all(txn)
+            .await?
+            .into_iter()
+            .filter_map(|model| Self::model_id(&model, id_col).map(|id| (id, model)))
+            .collect();
+
+        let mut inserts = Vec::new();
+        let mut updates = Vec::new();
+        for child in desired {
+            let model = child.to_model();
+            match stored.remove(&child.id()) {
+                None => inserts.push(model),
+                Some(current) => {
+                    if T::from_model(current)?.to_model() != model {
+                        updates.push(model);
+                    }
+                }
+            }
+        }
+
+        let deletes: Vec<E::Model> = if complete { stored.into_values().collect() } else { Vec::new() };
+        let changes = CollectionChanges { inserted: inserts.len(), updated: updates.len(), deleted: deletes.len() };
+
+        for model in deletes {
+            model.into_active_model().delete(txn).await?;
+        }
+        for model in updates {
+            model.into_active_model().reset_all().update(txn).await?;
+        }
+        for model in inserts {
+            E::insert(model.into_active_model().reset_all()).exec_without_returning(txn).await?;
+        }
+
+        Ok(changes)
+    }
+
+    /// Comprueba que la fila sigue en la `row_version` con la que se cargó el
+    /// agregado y, si `bump`, la pasa a la siguiente.
+    async fn check_row_version(txn: &DatabaseTransaction, aggregate: &UserAggregate, bump: bool) -> Result<u64, RepositoryError> {
+        let expected = aggregate.row_version();
+        let concurrency = |actual| RepositoryError::Concurrency { stream_id: user_stream_id(aggregate.user.id()), expected, actual };
+
+        if !bump {
+            let actual = Self::stored_row_version(txn, aggregate.id()).await?;
+            return if actual == expected { Ok(expected) } else { Err(concurrency(actual)) };
+        }
+
+        let result = users::Entity::update_many()
+            .col_expr(users::Column::RowVersion, Expr::value(expected as i64 + 1))
+            .filter(users::Column::UserId.eq(aggregate.id()))
+            .filter(users::Column::RowVersion.eq(expected as i64))
+            .exec(txn)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(concurrency(Self::stored_row_version(txn, aggregate.id()).await?));
+        }
+        Ok(expected + 1)
+    }
+
+    async fn stored_row_version(txn: &DatabaseTransaction, user_id: Uuid) -> Result<u64, RepositoryError> {
+        Ok(users::Entity::find_by_id(user_id)
+            .one(txn)
+            .await?
+            .map_or(0, |model| model.row_version.max(0) as u64))
+    }
+
+    fn model_id<M: ModelTrait>(model: &M, id_col: <M::Entity as EntityTrait>::Column) -> Option<Uuid> {
+        match model.get(id_col) {
+            Value::Uuid(Some(id)) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> UserAggregateRepository for UserAggregateRepositorySeaOrm<C>
+where
+    C: ConnectionTrait + TransactionTrait + Send + Sync,
+{
+    async fn load(&self, id: &UserId, options: LoadOptions) -> Result<Option<UserAggregate>, RepositoryError> {
+        let txn = self.db.begin().await?;
+
+        let Some(model) = users::Entity::find_by_id(id.as_uuid()).one(&txn).await? else {
+            return Ok(None);
+        };
+
+        let uid = id.as_uuid();
+        let row_version = model.row_version.max(0) as u64;
+        let mut aggregate = UserAggregate::new(UserRepositorySeaOrm::to_user(model)?);
+        aggregate.set_row_version(row_version);
+
+        aggregate.profile = UserAggregateRepositorySeaOrm::load_children::<_, user_profiles::Entity, _>(&txn, uid, user_profiles::Column::UserId, user_profiles::Column::CreatedAt)
+            .await?
+            .into_iter()
+            .next();
+        aggregate.password = UserAggregateRepositorySeaOrm::load_children::<_, user_passwords::Entity, _>(&txn, uid, user_passwords::Column::UserId, user_passwords::Column::CreatedAt)
+            .await?
+            .into_iter()
+            .next();
+        aggregate.auth_methods = UserAggregateRepositorySeaOrm::load_children::<_, user_auth_methods::Entity, _>(&txn, uid, user_auth_methods::Column::UserId, user_auth_methods::Column::CreatedAt).await?;
+        aggregate.mfa = UserAggregateRepositorySeaOrm::load_children::<_, user_mfa::Entity, _>(&txn, uid, user_mfa::Column::UserId, user_mfa::Column::CreatedAt).await?;
+        aggregate.roles = UserAggregateRepositorySeaOrm::load_children::<_, user_roles::Entity, _>(&txn, uid, user_roles::Column::UserId, user_roles::Column::GrantedAt).await?;
+        aggregate.subscriptions = UserAggregateRepositorySeaOrm::load_children::<_, user_subscriptions::Entity, _>(&txn, uid, user_subscriptions::Column::UserId, user_subscriptions::Column::StartsAt).await?;
+        aggregate.gdpr_consents = UserAggregateRepositorySeaOrm::load_children::<_, user_gdpr_consents::Entity, _>(&txn, uid, user_gdpr_consents::Column::UserId, user_gdpr_consents::Column::CreatedAt).await?;
+
+        if options.sessions {
+            aggregate.sessions = UserAggregateRepositorySeaOrm::load_children::<_, user_sessions::Entity, _>(&txn, uid, user_sessions::Column::UserId, user_sessions::Column::CreatedAt).await?;
+        }
+        if options.activity_logs {
+            aggregate.activity_logs = UserAggregateRepositorySeaOrm::load_children::<_, user_activity_logs::Entity, _>(&txn, uid, user_activity_logs::Column::UserId, user_activity_logs::Column::CreatedAt).await?;
+        }
+        aggregate.set_loaded(options);
+
+        txn.commit().await?;
+        Ok(Some(aggregate))
+    }
+
+    async fn load_sessions(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError> {
+        let stored = UserAggregateRepositorySeaOrm::load_children::<_, user_sessions::Entity, _>(&self.db, aggregate.id(), user_sessions::Column::UserId, user_sessions::Column::CreatedAt).await?;
+        merge_rows(stored, &mut aggregate.sessions);
+        aggregate.set_loaded(LoadOptions { sessions: true, ..aggregate.loaded() });
+        Ok(())
+    }
+
+    async fn load_activity_logs(&self, aggregate: &mut UserAggregate) -> Result<(), RepositoryError> {
+        let stored = UserAggregateRepositorySeaOrm::load_children::<_, user_activity_logs::Entity, _>(&self.db, aggregate.id(), user_activity_logs::Column::UserId, user_activity_logs::Column::CreatedAt).await?;
+        merge_rows(stored, &mut aggregate.activity_logs);
+        aggregate.set_loaded(LoadOptions { activity_logs: true, ..aggregate.loaded() });
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &UserAggregate) -> Result<AggregateChanges, RepositoryError> {
+        let txn = self.db.begin().await?;
+        let uid = aggregate.id();
+        let loaded = aggregate.loaded();
+        let mut changes = AggregateChanges::default();
+
+        UserRepositorySeaOrm::save_in(&txn, &aggregate.user).await?;
+
+        changes.collections.insert(
+            "profile",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_profiles::Entity>(&txn, uid, aggregate.profile.as_slice(), user_profiles::Column::UserId, user_profiles::Column::ProfileId, true).await?,
+        );
+        changes.collections.insert(
+            "password",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_passwords::Entity>(&txn, uid, aggregate.password.as_slice(), user_passwords::Column::UserId, user_passwords::Column::PasswordId, true).await?,
+        );
+        changes.collections.insert(
+            "auth_methods",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_auth_methods::Entity>(&txn, uid, &aggregate.auth_methods, user_auth_methods::Column::UserId, user_auth_methods::Column::AuthMethodId, true).await?,
+        );
+        changes.collections.insert(
+            "mfa",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_mfa::Entity>(&txn, uid, &aggregate.mfa, user_mfa::Column::UserId, user_mfa::Column::MfaId, true).await?,
+        );
+        changes.collections.insert(
+            "roles",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_roles::Entity>(&txn, uid, &aggregate.roles, user_roles::Column::UserId, user_roles::Column::UserRoleId, true).await?,
+        );
+        changes.collections.insert(
+            "subscriptions",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_subscriptions::Entity>(&txn, uid, &aggregate.subscriptions, user_subscriptions::Column::UserId, user_subscriptions::Column::SubscriptionId, true).await?,
+        );
+        changes.collections.insert(
+            "gdpr_consents",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_gdpr_consents::Entity>(&txn, uid, &aggregate.gdpr_consents, user_gdpr_consents::Column::UserId, user_gdpr_consents::Column::ConsentId, true).await?,
+        );
+        changes.collections.insert(
+            "sessions",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_sessions::Entity>(&txn, uid, &aggregate.sessions, user_sessions::Column::UserId, user_sessions::Column::SessionId, loaded.sessions).await?,
+        );
+        changes.collections.insert(
+            "activity_logs",
+            UserAggregateRepositorySeaOrm::sync_children::<_, user_activity_logs::Entity>(&txn, uid, &aggregate.activity_logs, user_activity_logs::Column::UserId, user_activity_logs::Column::LogId, loaded.activity_logs).await?,
+        );
+
+        // También sin eventos o sin diferencias: dos intentos fallidos
+        // concurrentes escriben el mismo contador y el segundo no cambiaría nada.
+        let bump = !aggregate.user.pending_events().is_empty() || !changes.is_empty();
+        changes.row_version = UserAggregateRepositorySeaOrm::check_row_version(&txn, aggregate, bump).await?;
+
+        txn.commit().await?;
+        Ok(changes)
+    }
+}
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, Func, LikeExpr, Query, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Value};
+use std::collections::HashMap;
+
+use crate::user::domain::repositories::{
+    CursorPosition, RepositoryError, SortDirection, SortKey, UserCursor, UserFilter, UserPage, UserQuery,
+    UserQueryRepository, UserSort, UserSortField, UserSummary,
+};
+use crate::user::domain::vo::SubscriptionStatus;
+
+use crate::user::infrastructure::persistence::orm::entities::{roles, user_profiles, user_roles, user_subscriptions, users};
+use crate::user::infrastructure::persistence::orm::UserRepositorySeaOrm;
+
+/// Adaptador SeaORM del lado de consulta.
+///
+/// Pagina por clave (`WHERE (orden, id) > cursor`) en lugar de `OFFSET`, de modo
+/// que las inserciones entre páginas no desplazan los resultados.
+pub struct UserQueryRepositorySeaOrm {
+    pub db: DatabaseConnection,
+}
+
+impl UserQueryRepositorySeaOrm {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// `LIKE` sin distinguir mayúsculas, escapando los comodines del usuario.
+    fn contains(column: impl Into<SimpleExpr>, text: &str) -> SimpleExpr {
+        let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        Expr::expr(Func::lower(column.into())).like(LikeExpr::new(format!("%{escaped}%")).escape('\\'))
+    }
+
+    fn filter_condition(filter: &UserFilter) -> Condition {
+        let mut condition = Condition::all();
+
+        if !filter.statuses.is_empty() {
+            condition = condition.add(users::Column::Status.is_in(filter.statuses.iter().map(|s| s.as_str().to_string())));
+        }
+        if let Some(verified) = filter.email_verified {
+            condition = condition.add(users::Column::EmailVerified.eq(verified));
+        }
+        if let Some(verified) = filter.phone_verified {
+            condition = condition.add(users::Column::PhoneVerified.eq(verified));
+        }
+        if let Some(from) = filter.created_from {
+            condition = condition.add(users::Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = filter.created_to {
+            condition = condition.add(users::Column::CreatedAt.lt(to));
+        }
+
+        if let Some(role) = &filter.role {
+            let holders = Query::select()
+                .column((user_roles::Entity, user_roles::Column::UserId))
+                .from(user_roles::Entity)
+                .inner_join(
+                    roles::Entity,
+                    Expr::col((roles::Entity, roles::Column::RoleId)).equals((user_roles::Entity, user_roles::Column::RoleId)),
+                )
+                .and_where(Expr::col((roles::Entity, roles::Column::Name)).eq(role.as_str()))
+                .and_where(Expr::col((user_roles::Entity, user_roles::Column::IsActive)).eq(true))
+                .cond_where(
+                    Condition::any()
+                        .add(Expr::col((user_roles::Entity, user_roles::Column::ExpiresAt)).is_null())
+                        .add(Expr::col((user_roles::Entity, user_roles::Column::ExpiresAt)).gt(Utc::now())),
+                )
+                .to_owned();
+            condition = condition.add(users::Column::UserId.in_subquery(holders));
+        }
+
+        if let Some(tier) = &filter.subscription_tier {
+            let subscribers = Query::select()
+                .column(user_subscriptions::Column::UserId)
+                .from(user_subscriptions::Entity)
+                .and_where(user_subscriptions::Column::Tier.eq(tier.as_str()))
+                .and_where(user_subscriptions::Column::Status.eq(SubscriptionStatus::Active.as_str()))
+                .to_owned();
+            condition = condition.add(users::Column::UserId.in_subquery(subscribers));
+        }
+
+        if let Some(text) = filter.search_text() {
+            let by_display_name = Query::select()
+                .column(user_profiles::Column::UserId)
+                .from(user_profiles::Entity)
+                .and_where(Self::contains(Expr::col(user_profiles::Column::DisplayName), &text))
+                .to_owned();
+
+            condition = condition.add(
+                Condition::any()
+                    .add(Self::contains(Expr::col((users::Entity, users::Column::Email)), &text))
+                    .add(Self::contains(Expr::col((users::Entity, users::Column::Username)), &text))
+                    .add(users::Column::UserId.in_subquery(by_display_name)),
+            );
+        }
+
+        condition
+    }
+
+    fn sort_column(field: UserSortField) -> users::Column {
+        match field {
+            UserSortField::CreatedAt => users::Column::CreatedAt,
+            UserSortField::Email => users::Column::Email,
+        }
+    }
+
+    /// Filas estrictamente posteriores al cursor en el orden pedido.
+    fn after_condition(sort: &UserSort, position: CursorPosition) -> Condition {
+        let column = Self::sort_column(sort.field);
+        let key: Value = match position.key {
+            SortKey::CreatedAt(at) => at.into(),
+            SortKey::Email(email) => email.into(),
+        };
+
+        let (past_key, past_id) = match sort.direction {
+            SortDirection::Asc => (Expr::col(column).gt(key.clone()), users::Column::UserId.gt(position.id)),
+            SortDirection::Desc => (Expr::col(column).lt(key.clone()), users::Column::UserId.lt(position.id)),
+        };
+
+        Condition::any()
+            .add(past_key)
+            .add(Condition::all().add(Expr::col(column).eq(key)).add(past_id))
+    }
+}
+
+#[async_trait]
+impl UserQueryRepository for UserQueryRepositorySeaOrm {
+    async fn search(&self, query: &UserQuery) -> Result<UserPage, RepositoryError> {
+        let position = query.position()?;
+        let size = query.page_size();
+
+        let column = Self::sort_column(query.sort.field);
+        let order = match query.sort.direction {
+            SortDirection::Asc => sea_orm::Order::Asc,
+            SortDirection::Desc => sea_orm::Order::Desc,
+        };
+
+        let mut select = users::Entity::find().filter(Self::filter_condition(&query.filter));
+        if let Some(position) = position {
+            select = select.filter(Self::after_condition(&query.sort, position));
+        }
+
+        // Una fila de más indica si hay página siguiente
+        let mut rows = select
+            .order_by(column, order.clone())
+            .order_by(users::Column::UserId, order)
+            .limit(size + 1)
+            .all(&self.db)
+            .await?;
+
+        let has_more = rows.len() as u64 > size;
+        rows.truncate(size as usize);
+
+        let ids: Vec<_> = rows.iter().map(|row| row.user_id).collect();
+        let display_names: HashMap<_, _> = user_profiles::Entity::find()
+            .filter(user_profiles::Column::UserId.is_in(ids))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .filter_map(|profile| profile.display_name.map(|name| (profile.user_id, name)))
+            .collect();
+
+        let items = rows
+            .into_iter()
+            .map(|row| {
+                let display_name = display_names.get(&row.user_id).cloned();
+                let user = UserRepositorySeaOrm::to_user(row)?;
+
+                Ok(UserSummary {
+                    id: user.id().clone(),
+                    email: user.email().clone(),
+                    username: user.username().cloned(),
+                    display_name,
+                    status: user.status().clone(),
+                    email_verified: user.email_verified(),
+                    phone_verified: user.phone_verified(),
+                    created_at: user.created_at().clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        let next_cursor = match items.last() {
+            Some(last) if has_more => Some(UserCursor::encode(&query.sort, last)),
+            _ => None,
+        };
+
+        Ok(UserPage { items, next_cursor })
+    }
+}
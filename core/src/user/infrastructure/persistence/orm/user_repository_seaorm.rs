@@ -1,77 +1,245 @@
 use async_trait::async_trait;
-use sea_orm::{DatabaseConnection, EntityTrait, ActiveModelTrait, Set, ColumnTrait, QueryFilter};
-use uuid::Uuid;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, EntityTrait, PaginatorTrait, QueryFilter,
+    NotSet, QueryOrder, Set, SqlErr, TransactionTrait,
+};
 
-use crate::user::domain::entities::role::Role;
-use crate::user::domain::repositories::role_repository::RoleRepository;
-use crate::user::domain::vo::validation_error::ValidationError;
+use crate::user::domain::entities::{User, UserSnapshot};
+use crate::user::domain::repositories::user_repository::UserRepository;
+use crate::user::domain::repositories::{user_stream_id, RepositoryError};
+use crate::user::domain::vo::{Email, ExternalId, OccurredAt, Phone, UserId, UserStatus, Username};
 
-// Importa las entidades generadas por SeaORM (ejemplo: sea-orm-cli generate entity)
-use crate::user::infrastructure::persistence::orm::entities::roles;
+use crate::user::infrastructure::persistence::orm::entities::users;
+use crate::user::infrastructure::persistence::orm::OutboxRepositorySeaOrm;
 
-pub struct RoleRepositorySeaOrm {
-    pub db: DatabaseConnection,
+/// Adaptador SeaORM del agregado `User`.
+///
+/// `save` escribe la fila y los eventos pendientes en la bandeja de salida
+/// (`user_outbox`) dentro de la misma transacción.
+pub struct UserRepositorySeaOrm<C = DatabaseConnection> {
+    pub db: C,
 }
 
-impl RoleRepositorySeaOrm {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C> UserRepositorySeaOrm<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 }
 
-#[async_trait]
-impl RoleRepository for RoleRepositorySeaOrm {
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<Role>, ValidationError> {
-        let role = roles::Entity::find_by_id(id)
-            .one(&self.db)
-            .await
-            .map_err(|_| ValidationError::InvalidRole)?;
-
-        Ok(role.map(|model| Role {
-            id: model.role_id,
-            name: model.name,
-            display_name: model.display_name,
-            description: model.description,
-            permissions: model.permissions.unwrap_or_default(),
-            is_system: model.is_system,
-            created_at: model.created_at,
-        }))
-    }
-
-    async fn save(&self, role: &Role) -> Result<(), ValidationError> {
-        let active_model = roles::ActiveModel {
-            role_id: Set(role.id),
-            name: Set(role.name.clone()),
-            display_name: Set(role.display_name.clone()),
-            description: Set(role.description.clone()),
-            permissions: Set(Some(role.permissions.clone())),
-            is_system: Set(role.is_system),
-            created_at: Set(role.created_at),
+impl UserRepositorySeaOrm {
+    pub(crate) fn to_user(model: users::Model) -> Result<User, RepositoryError> {
+        let snapshot = UserSnapshot {
+            id: UserId::from_uuid(model.user_id),
+            external_id: model.external_id.map(ExternalId::try_from).transpose()?,
+            username: model.username.map(Username::try_from).transpose()?,
+            email: Email::try_from(model.email)?,
+            email_verified: model.email_verified,
+            phone: model.phone.map(Phone::try_from).transpose()?,
+            phone_verified: model.phone_verified,
+            status: UserStatus::try_from(model.status.as_str())?,
+            created_at: OccurredAt::from_datetime(model.created_at),
+            updated_at: OccurredAt::from_datetime(model.updated_at),
+            deleted_at: model.deleted_at.map(OccurredAt::from_datetime),
+            version: model.version.max(0) as u64,
         };
 
-        active_model
-            .insert(&self.db)
-            .await
-            .map_err(|_| ValidationError::InvalidRole)?;
+        Ok(User::restore(snapshot)?)
+    }
+
+    fn to_active_model(snapshot: UserSnapshot) -> users::ActiveModel {
+        users::ActiveModel {
+            user_id: Set(snapshot.id.as_uuid()),
+            external_id: Set(snapshot.external_id.map(|v| v.as_ref().to_string())),
+            username: Set(snapshot.username.map(|v| v.as_ref().to_string())),
+            email: Set(snapshot.email.as_str().to_string()),
+            email_verified: Set(snapshot.email_verified),
+            phone: Set(snapshot.phone.map(String::from)),
+            phone_verified: Set(snapshot.phone_verified),
+            status: Set(snapshot.status.as_str().to_string()),
+            created_at: Set(snapshot.created_at.value()),
+            updated_at: Set(snapshot.updated_at.value()),
+            deleted_at: Set(snapshot.deleted_at.map(|v| v.value())),
+            version: Set(snapshot.version as i64),
+            // La lleva `UserAggregateRepositorySeaOrm`; un alta empieza en 0
+            row_version: NotSet,
+        }
+    }
+
+    async fn find_one<C: ConnectionTrait>(conn: &C, filter: impl sea_orm::sea_query::IntoCondition + Send) -> Result<Option<User>, RepositoryError> {
+        users::Entity::find()
+            .filter(filter)
+            .one(conn)
+            .await?
+            .map(Self::to_user)
+            .transpose()
+    }
+
+    /// Rechaza valores únicos ya usados por otro usuario.
+    async fn ensure_unique(txn: &DatabaseTransaction, user: &User) -> Result<(), RepositoryError> {
+        let others = users::Column::UserId.ne(user.id().as_uuid());
+
+        let mut checks = vec![("email", users::Column::Email, user.email().as_str().to_string())];
+        if let Some(username) = user.username() {
+            checks.push(("username", users::Column::Username, username.as_ref().to_string()));
+        }
+        if let Some(external_id) = user.external_id() {
+            checks.push(("external_id", users::Column::ExternalId, external_id.as_ref().to_string()));
+        }
+
+        for (field, column, value) in checks {
+            let taken = users::Entity::find()
+                .filter(column.eq(value.as_str()))
+                .filter(others.clone())
+                .count(txn)
+                .await?
+                > 0;
+
+            if taken {
+                return Err(RepositoryError::Conflict { entity: "user", field, value });
+            }
+        }
 
         Ok(())
     }
 
-    async fn find_by_name(&self, name: &str) -> Result<Option<Role>, ValidationError> {
-        let role = roles::Entity::find()
-            .filter(roles::Column::Name.eq(name))
-            .one(&self.db)
-            .await
-            .map_err(|_| ValidationError::InvalidRole)?;
-
-        Ok(role.map(|model| Role {
-            id: model.role_id,
-            name: model.name,
-            display_name: model.display_name,
-            description: model.description,
-            permissions: model.permissions.unwrap_or_default(),
-            is_system: model.is_system,
-            created_at: model.created_at,
-        }))
+    async fn stored_version<C: ConnectionTrait>(conn: &C, id: &UserId) -> Result<Option<u64>, RepositoryError> {
+        Ok(users::Entity::find_by_id(id.as_uuid())
+            .one(conn)
+            .await?
+            .map(|model| model.version.max(0) as u64))
+    }
+
+    /// Inserta si la fila no existe y, si existe, la actualiza sólo cuando
+    /// sigue en la versión con la que se cargó el usuario.
+    ///
+    /// Que exista la fila decide entre insertar y actualizar; un alta
+    /// (`persisted_version() == 0`) sobre una fila existente es un conflicto.
+    async fn write(txn: &DatabaseTransaction, user: &User) -> Result<(), RepositoryError> {
+        let expected = user.persisted_version();
+        let concurrency = |actual| RepositoryError::Concurrency { stream_id: user_stream_id(user.id()), expected, actual };
+
+        match Self::stored_version(txn, user.id()).await? {
+            None if expected == 0 => Self::insert(txn, user).await,
+            None => Err(RepositoryError::NotFound { entity: "user", id: user.id().to_string() }),
+            Some(actual) if actual != expected => Err(concurrency(actual)),
+            Some(_) => {
+                let result = users::Entity::update_many()
+                    .set(Self::to_active_model(user.snapshot()))
+                    .filter(users::Column::UserId.eq(user.id().as_uuid()))
+                    .filter(users::Column::Version.eq(expected as i64))
+                    .exec(txn)
+                    .await?;
+
+                if result.rows_affected == 0 {
+                    return Err(match Self::stored_version(txn, user.id()).await? {
+                        Some(actual) => concurrency(actual),
+                        None => RepositoryError::NotFound { entity: "user", id: user.id().to_string() },
+                    });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserta dentro de un savepoint: si otra escritura se adelantó, se
+    /// vuelve a él antes de leer qué pasó (en Postgres la transacción
+    /// abortada ya no admite consultas).
+    async fn insert(txn: &DatabaseTransaction, user: &User) -> Result<(), RepositoryError> {
+        let savepoint = txn.begin().await?;
+        let Err(err) = users::Entity::insert(Self::to_active_model(user.snapshot())).exec_without_returning(&savepoint).await else {
+            savepoint.commit().await?;
+            return Ok(());
+        };
+        savepoint.rollback().await?;
+
+        if !matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+            return Err(err.into());
+        }
+        if let Some(actual) = Self::stored_version(txn, user.id()).await? {
+            return Err(RepositoryError::Concurrency { stream_id: user_stream_id(user.id()), expected: 0, actual });
+        }
+        // Email, username o external_id ocupados por otro usuario entre la
+        // comprobación y el insert.
+        Self::ensure_unique(txn, user).await?;
+        Err(err.into())
+    }
+
+    /// Guarda la raíz y sus eventos pendientes dentro de una transacción abierta.
+    pub(crate) async fn save_in(txn: &DatabaseTransaction, user: &User) -> Result<(), RepositoryError> {
+        Self::ensure_unique(txn, user).await?;
+        Self::write(txn, user).await?;
+        OutboxRepositorySeaOrm::append(txn, user.pending_events()).await
+    }
+}
+
+#[async_trait]
+impl<C> UserRepository for UserRepositorySeaOrm<C>
+where
+    C: ConnectionTrait + TransactionTrait + Send + Sync,
+{
+    async fn get_by_id(&self, id: &UserId) -> Result<Option<User>, RepositoryError> {
+        UserRepositorySeaOrm::find_one(&self.db, users::Column::UserId.eq(id.as_uuid())).await
+    }
+
+    async fn get_by_email(&self, email: &Email) -> Result<Option<User>, RepositoryError> {
+        UserRepositorySeaOrm::find_one(&self.db, users::Column::Email.eq(email.as_str())).await
+    }
+
+    async fn get_by_username(&self, username: &Username) -> Result<Option<User>, RepositoryError> {
+        UserRepositorySeaOrm::find_one(&self.db, users::Column::Username.eq(username.as_ref())).await
+    }
+
+    async fn exists_by_email(&self, email: &Email) -> Result<bool, RepositoryError> {
+        let count = users::Entity::find()
+            .filter(users::Column::Email.eq(email.as_str()))
+            .count(&self.db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn exists_by_username(&self, username: &Username) -> Result<bool, RepositoryError> {
+        let count = users::Entity::find()
+            .filter(users::Column::Username.eq(username.as_ref()))
+            .count(&self.db)
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn list_all(&self) -> Result<Vec<User>, RepositoryError> {
+        users::Entity::find()
+            .order_by_asc(users::Column::CreatedAt)
+            .order_by_asc(users::Column::UserId)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(UserRepositorySeaOrm::to_user)
+            .collect()
+    }
+
+    async fn save(&self, user: &User) -> Result<(), RepositoryError> {
+        let txn = self.db.begin().await?;
+        UserRepositorySeaOrm::save_in(&txn, user).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn soft_delete(&self, id: &UserId) -> Result<(), RepositoryError> {
+        let mut user = self
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound { entity: "user", id: id.to_string() })?;
+
+        user.delete()?;
+        self.save(&user).await
+    }
+
+    async fn delete(&self, id: &UserId) -> Result<(), RepositoryError> {
+        let result = users::Entity::delete_by_id(id.as_uuid()).exec(&self.db).await?;
+
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound { entity: "user", id: id.to_string() });
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,87 @@
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::user::domain::services::{AccessTokenClaims, AccessTokenCodec};
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Claims tal como viajan en el JWT: los del dominio más el emisor.
+#[derive(Debug, Serialize, Deserialize)]
+struct JwtClaims {
+    #[serde(flatten)]
+    claims: AccessTokenClaims,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+}
+
+/// Tokens de acceso JWT firmados con claves locales: HS256 con un secreto
+/// compartido o EdDSA (Ed25519) con un par de claves PEM.
+///
+/// Sólo acepta el algoritmo con el que se construyó. Las fechas no se
+/// validan aquí, sino en `SessionService` con el `Clock` del servicio.
+#[derive(Clone)]
+pub struct JwtAccessTokenCodec {
+    algorithm: Algorithm,
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    issuer: Option<String>,
+}
+
+impl JwtAccessTokenCodec {
+    /// Longitud mínima del secreto HS256: la salida de SHA-256.
+    pub const MIN_SECRET_LEN: usize = 32;
+
+    pub fn hs256(secret: &[u8]) -> Result<Self, UserDomainError> {
+        if secret.len() < Self::MIN_SECRET_LEN {
+            return Err((CategoryError::Session, TypeError::TooShort { short: Self::MIN_SECRET_LEN as u16 }).into());
+        }
+        Ok(Self {
+            algorithm: Algorithm::HS256,
+            encoding: EncodingKey::from_secret(secret),
+            decoding: DecodingKey::from_secret(secret),
+            issuer: None,
+        })
+    }
+
+    /// Par de claves Ed25519: privada en PKCS#8 y pública en SPKI, ambas PEM.
+    pub fn ed25519(private_pem: &[u8], public_pem: &[u8]) -> Result<Self, UserDomainError> {
+        let invalid_key = |_| UserDomainError::from((CategoryError::Session, TypeError::Format { format: "ed25519_pem".into() }));
+        Ok(Self {
+            algorithm: Algorithm::EdDSA,
+            encoding: EncodingKey::from_ed_pem(private_pem).map_err(invalid_key)?,
+            decoding: DecodingKey::from_ed_pem(public_pem).map_err(invalid_key)?,
+            issuer: None,
+        })
+    }
+
+    /// Añade `iss` a los tokens emitidos y lo exige al verificarlos.
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    fn validation(&self) -> Validation {
+        let mut validation = Validation::new(self.algorithm);
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation.required_spec_claims.clear();
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+            validation.set_required_spec_claims(&["iss"]);
+        }
+        validation
+    }
+}
+
+impl AccessTokenCodec for JwtAccessTokenCodec {
+    fn encode(&self, claims: &AccessTokenClaims) -> Result<String, UserDomainError> {
+        let claims = JwtClaims { claims: claims.clone(), iss: self.issuer.clone() };
+        jsonwebtoken::encode(&Header::new(self.algorithm), &claims, &self.encoding)
+            .map_err(|_| (CategoryError::Session, TypeError::InvalidState { state: "token_signing_failed".into() }).into())
+    }
+
+    fn decode(&self, token: &str) -> Result<AccessTokenClaims, UserDomainError> {
+        jsonwebtoken::decode::<JwtClaims>(token, &self.decoding, &self.validation())
+            .map(|data| data.claims.claims)
+            .map_err(|_| (CategoryError::Session, TypeError::Format { format: "jwt".into() }).into())
+    }
+}
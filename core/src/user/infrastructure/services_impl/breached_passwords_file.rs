@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::user::domain::services::BreachedPasswordSource;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Lista de ejemplo incluida en el binario.
+const BUNDLED: &str = include_str!("data/breached_passwords.txt");
+
+/// Contraseñas filtradas leídas de un fichero local, sin red.
+///
+/// Cada línea es un SHA-1 en hexadecimal, opcionalmente seguido de
+/// `:APARICIONES` (el formato de los volcados de Pwned Passwords). Las líneas
+/// vacías y las que empiezan por `#` se ignoran. Se indexa por prefijo para
+/// responder a `range` igual que lo haría el servicio remoto.
+#[derive(Debug, Clone, Default)]
+pub struct PrefixFileBreachedPasswords {
+    ranges: HashMap<String, Vec<(String, u32)>>,
+}
+
+impl PrefixFileBreachedPasswords {
+    pub fn parse(contents: &str) -> Result<Self, UserDomainError> {
+        let mut ranges: HashMap<String, Vec<(String, u32)>> = HashMap::new();
+
+        for line in contents.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (hash, count) = line.split_once(':').unwrap_or((line, "1"));
+            let count = count.trim().parse::<u32>().map_err(|_| Self::format_error())?;
+            let hash = hash.trim().to_ascii_uppercase();
+            if hash.len() != 40 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(Self::format_error());
+            }
+
+            let (prefix, suffix) = hash.split_at(5);
+            ranges.entry(prefix.to_string()).or_default().push((suffix.to_string(), count));
+        }
+
+        Ok(Self { ranges })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, UserDomainError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|_| UserDomainError::from((CategoryError::Password, TypeError::InvalidState { state: "breached_list_unreadable".into() })))?;
+        Self::parse(&contents)
+    }
+
+    /// Lista reducida de contraseñas muy comunes que viaja con el crate.
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED).expect("la lista incluida es válida")
+    }
+
+    /// Número de hashes cargados.
+    pub fn len(&self) -> usize {
+        self.ranges.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    fn format_error() -> UserDomainError {
+        (CategoryError::Password, TypeError::Format { format: "sha1".into() }).into()
+    }
+}
+
+impl BreachedPasswordSource for PrefixFileBreachedPasswords {
+    fn range(&self, prefix: &str) -> Result<Vec<(String, u32)>, UserDomainError> {
+        Ok(self.ranges.get(&prefix.to_ascii_uppercase()).cloned().unwrap_or_default())
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::user::domain::services::Clock;
+
+/// Reloj que sólo avanza cuando se le pide, para probar caducidades sin
+/// esperar. Los clones comparten la hora.
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl ManualClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self { now: Arc::new(Mutex::new(start)) }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.guard() = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.guard();
+        *now += by;
+    }
+
+    fn guard(&self) -> MutexGuard<'_, DateTime<Utc>> {
+        self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.guard()
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::user::domain::services::LoginThrottle;
+
+#[derive(Debug, Default)]
+struct IpState {
+    failures: VecDeque<DateTime<Utc>>,
+    blocked_until: Option<DateTime<Utc>>,
+}
+
+/// Contadores por IP en memoria del proceso.
+///
+/// Suficiente para una instalación de escritorio o un único servidor; con
+/// varias instancias hace falta un adaptador compartido.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryLoginThrottle {
+    ips: Arc<Mutex<HashMap<IpAddr, IpState>>>,
+}
+
+impl InMemoryLoginThrottle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn ips(&self) -> MutexGuard<'_, HashMap<IpAddr, IpState>> {
+        self.ips.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl LoginThrottle for InMemoryLoginThrottle {
+    fn record_failure(&self, ip: IpAddr, at: DateTime<Utc>, since: DateTime<Utc>) -> u32 {
+        let mut ips = self.ips();
+        let state = ips.entry(ip).or_default();
+
+        while state.failures.front().is_some_and(|failed| *failed < since) {
+            state.failures.pop_front();
+        }
+        state.failures.push_back(at);
+        u32::try_from(state.failures.len()).unwrap_or(u32::MAX)
+    }
+
+    fn block(&self, ip: IpAddr, until: DateTime<Utc>) {
+        self.ips().entry(ip).or_default().blocked_until = Some(until);
+    }
+
+    fn blocked_until(&self, ip: IpAddr) -> Option<DateTime<Utc>> {
+        self.ips().get(&ip).and_then(|state| state.blocked_until)
+    }
+
+    fn clear(&self, ip: IpAddr) {
+        self.ips().remove(&ip);
+    }
+}
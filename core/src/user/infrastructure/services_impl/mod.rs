@@ -0,0 +1,21 @@
+pub mod access_token_jwt;
+pub mod breached_passwords_file;
+#[cfg(any(test, feature = "testing"))]
+pub mod clock_manual;
+pub mod login_throttle_memory;
+pub mod password_hasher_argon2;
+pub mod password_hasher_bcrypt;
+pub mod password_hasher_delegating;
+#[cfg(any(test, feature = "testing"))]
+pub mod password_reset_notifier_memory;
+
+pub use access_token_jwt::JwtAccessTokenCodec;
+pub use breached_passwords_file::PrefixFileBreachedPasswords;
+#[cfg(any(test, feature = "testing"))]
+pub use clock_manual::ManualClock;
+pub use login_throttle_memory::InMemoryLoginThrottle;
+pub use password_hasher_argon2::{Argon2Config, Argon2PasswordHasher};
+pub use password_hasher_bcrypt::BcryptPasswordHasher;
+pub use password_hasher_delegating::DelegatingPasswordHasher;
+#[cfg(any(test, feature = "testing"))]
+pub use password_reset_notifier_memory::{InMemoryPasswordResetNotifier, SentResetToken};
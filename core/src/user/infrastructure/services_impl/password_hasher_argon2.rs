@@ -0,0 +1,121 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand_core::OsRng;
+use std::fmt;
+
+use crate::user::domain::services::PasswordHasher;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Parámetros de Argon2id. Los valores por defecto son los recomendados por
+/// OWASP (19 MiB, 2 iteraciones, 1 hilo).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Argon2Config {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    /// Secreto del servidor (pepper). No queda en el hash: cambiarlo invalida
+    /// todas las contraseñas existentes.
+    pub pepper: Option<Vec<u8>>,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+            pepper: None,
+        }
+    }
+}
+
+impl fmt::Debug for Argon2Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Argon2Config")
+            .field("memory_kib", &self.memory_kib)
+            .field("iterations", &self.iterations)
+            .field("parallelism", &self.parallelism)
+            .field("pepper", &self.pepper.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// Adaptador Argon2id.
+///
+/// Verifica con los parámetros guardados en cada hash, así que los hashes
+/// antiguos siguen siendo válidos; `needs_rehash` señala los que no usan los
+/// parámetros actuales.
+#[derive(Debug, Clone)]
+pub struct Argon2PasswordHasher {
+    config: Argon2Config,
+    params: Params,
+}
+
+impl Argon2PasswordHasher {
+    pub fn new(config: Argon2Config) -> Result<Self, UserDomainError> {
+        let params = Params::new(config.memory_kib, config.iterations, config.parallelism, None)
+            .map_err(|_| UserDomainError::from((CategoryError::Password, TypeError::InvalidState { state: "argon2_params".into() })))?;
+
+        Ok(Self { config, params })
+    }
+
+    pub fn config(&self) -> &Argon2Config {
+        &self.config
+    }
+
+    fn argon2(&self) -> Result<Argon2<'_>, UserDomainError> {
+        match &self.config.pepper {
+            Some(pepper) => Argon2::new_with_secret(pepper, Algorithm::Argon2id, Version::V0x13, self.params.clone())
+                .map_err(|_| (CategoryError::Password, TypeError::InvalidState { state: "argon2_pepper".into() }).into()),
+            None => Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())),
+        }
+    }
+
+    fn parse(hash: &str) -> Result<PasswordHash<'_>, UserDomainError> {
+        PasswordHash::new(hash).map_err(|_| (CategoryError::Password, TypeError::Format { format: "PHC".into() }).into())
+    }
+}
+
+impl Default for Argon2PasswordHasher {
+    fn default() -> Self {
+        Self::new(Argon2Config::default()).expect("parámetros por defecto válidos")
+    }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, UserDomainError> {
+        let salt = SaltString::generate(&mut OsRng);
+
+        self.argon2()?
+            .hash_password(password.as_bytes(), &salt)
+            .map(|hash| hash.to_string())
+            .map_err(|_| (CategoryError::Password, TypeError::InvalidState { state: "hash_failed".into() }).into())
+    }
+
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError> {
+        let parsed = Self::parse(hash)?;
+        Ok(self.argon2()?.verify_password(password.as_bytes(), &parsed).is_ok())
+    }
+
+    fn needs_rehash(&self, hash: &str) -> bool {
+        let Ok(parsed) = Self::parse(hash) else {
+            return true;
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident() || parsed.version != Some(Version::V0x13.into()) {
+            return true;
+        }
+
+        match Params::try_from(&parsed) {
+            Ok(stored) => {
+                stored.m_cost() != self.params.m_cost()
+                    || stored.t_cost() != self.params.t_cost()
+                    || stored.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+
+    fn recognizes(&self, hash: &str) -> bool {
+        hash.starts_with("$argon2")
+    }
+}
@@ -0,0 +1,54 @@
+use bcrypt::HashParts;
+
+use crate::user::domain::services::PasswordHasher;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Adaptador bcrypt, pensado para verificar hashes heredados de otros
+/// sistemas mientras se migran a Argon2id (ver `DelegatingPasswordHasher`).
+///
+/// bcrypt sólo usa los primeros 72 bytes de la contraseña.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BcryptPasswordHasher {
+    cost: u32,
+}
+
+impl BcryptPasswordHasher {
+    pub const MIN_COST: u32 = 4;
+    pub const MAX_COST: u32 = 31;
+
+    pub fn new(cost: u32) -> Result<Self, UserDomainError> {
+        if !(Self::MIN_COST..=Self::MAX_COST).contains(&cost) {
+            return Err((CategoryError::Password, TypeError::InvalidState { state: "bcrypt_cost".into() }).into());
+        }
+        Ok(Self { cost })
+    }
+
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+}
+
+impl Default for BcryptPasswordHasher {
+    fn default() -> Self {
+        Self { cost: bcrypt::DEFAULT_COST }
+    }
+}
+
+impl PasswordHasher for BcryptPasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, UserDomainError> {
+        bcrypt::hash(password, self.cost)
+            .map_err(|_| (CategoryError::Password, TypeError::InvalidState { state: "hash_failed".into() }).into())
+    }
+
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError> {
+        bcrypt::verify(password, hash).map_err(|_| (CategoryError::Password, TypeError::Format { format: "bcrypt".into() }).into())
+    }
+
+    fn needs_rehash(&self, hash: &str) -> bool {
+        hash.parse::<HashParts>().map_or(true, |parts| parts.get_cost() < self.cost)
+    }
+
+    fn recognizes(&self, hash: &str) -> bool {
+        ["$2a$", "$2b$", "$2x$", "$2y$"].iter().any(|prefix| hash.starts_with(prefix))
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::user::domain::services::PasswordHasher;
+use crate::user::domain::validations::{CategoryError, TypeError, UserDomainError};
+
+/// Combina el algoritmo actual con los heredados durante una migración.
+///
+/// Los hashes nuevos siempre se generan con `current`; para verificar se usa
+/// el primer adaptador que reconoce el hash. Cualquier hash que no sea de
+/// `current` con sus parámetros actuales necesita recalcularse.
+#[derive(Clone)]
+pub struct DelegatingPasswordHasher {
+    current: Arc<dyn PasswordHasher>,
+    legacy: Vec<Arc<dyn PasswordHasher>>,
+}
+
+impl DelegatingPasswordHasher {
+    pub fn new(current: Arc<dyn PasswordHasher>) -> Self {
+        Self { current, legacy: Vec::new() }
+    }
+
+    pub fn with_legacy(mut self, hasher: Arc<dyn PasswordHasher>) -> Self {
+        self.legacy.push(hasher);
+        self
+    }
+
+    fn for_hash(&self, hash: &str) -> Option<&Arc<dyn PasswordHasher>> {
+        std::iter::once(&self.current).chain(&self.legacy).find(|hasher| hasher.recognizes(hash))
+    }
+}
+
+impl PasswordHasher for DelegatingPasswordHasher {
+    fn hash(&self, password: &str) -> Result<String, UserDomainError> {
+        self.current.hash(password)
+    }
+
+    fn verify(&self, password: &str, hash: &str) -> Result<bool, UserDomainError> {
+        match self.for_hash(hash) {
+            Some(hasher) => hasher.verify(password, hash),
+            None => Err((CategoryError::Password, TypeError::NotSupported).into()),
+        }
+    }
+
+    fn needs_rehash(&self, hash: &str) -> bool {
+        !self.current.recognizes(hash) || self.current.needs_rehash(hash)
+    }
+
+    fn recognizes(&self, hash: &str) -> bool {
+        self.for_hash(hash).is_some()
+    }
+}
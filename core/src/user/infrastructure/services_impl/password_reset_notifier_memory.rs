@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::user::domain::services::PasswordResetNotifier;
+use crate::user::domain::vo::Email;
+
+/// Token entregado por `InMemoryPasswordResetNotifier`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentResetToken {
+    pub email: Email,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Buzón en memoria que guarda los tokens en vez de enviarlos. Los clones
+/// comparten el buzón.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryPasswordResetNotifier {
+    sent: Arc<Mutex<Vec<SentResetToken>>>,
+}
+
+impl InMemoryPasswordResetNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copia de los tokens entregados hasta ahora, en orden.
+    pub fn sent(&self) -> Vec<SentResetToken> {
+        self.guard().clone()
+    }
+
+    fn guard(&self) -> MutexGuard<'_, Vec<SentResetToken>> {
+        self.sent.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl PasswordResetNotifier for InMemoryPasswordResetNotifier {
+    async fn send_reset_token(&self, email: &Email, token: &str, expires_at: DateTime<Utc>) -> anyhow::Result<()> {
+        self.guard().push(SentResetToken { email: email.clone(), token: token.to_string(), expires_at });
+        Ok(())
+    }
+}